@@ -0,0 +1,46 @@
+#![no_std]
+
+use core::alloc::{GlobalAlloc, Layout};
+use core::panic::PanicInfo;
+use sp1_poseidon2_bn256::{
+    fields::bn256::FpBN256,
+    merkle_tree::merkle_tree_fp::MerkleTree,
+    poseidon2::{
+        poseidon2::Poseidon2,
+        poseidon2_instance_bn256::{POSEIDON2_BN256_PARAMS, POSEIDON2_BN256_T4_PARAMS},
+    },
+};
+
+struct NoAlloc;
+
+unsafe impl GlobalAlloc for NoAlloc {
+    unsafe fn alloc(&self, _layout: Layout) -> *mut u8 {
+        core::ptr::null_mut()
+    }
+
+    unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {}
+}
+
+#[global_allocator]
+static ALLOCATOR: NoAlloc = NoAlloc;
+
+#[panic_handler]
+fn panic(_info: &PanicInfo) -> ! {
+    loop {
+        core::hint::spin_loop();
+    }
+}
+
+#[unsafe(no_mangle)]
+pub fn ensure_no_std() -> u64 {
+    let poseidon2 = Poseidon2::new(&POSEIDON2_BN256_PARAMS);
+    let mut state = [FpBN256::ZERO; 3];
+    poseidon2.permute_in_place(&mut state);
+
+    let poseidon2_t4 = Poseidon2::new(&POSEIDON2_BN256_T4_PARAMS);
+    let leaves = [state[0], state[1]];
+    let root = MerkleTree::new(poseidon2).accumulate(&leaves);
+    let hash = poseidon2_t4.hash(&[root]);
+
+    hash.retrieve().as_words()[0] as u64
+}
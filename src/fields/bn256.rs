@@ -1,6 +1,8 @@
 // Scalar field of BN254 curve using `crypto-bigint`
 // Compatible with both 32-bit (e.g., SP1 zkVM) and 64-bit targets.
 
+use super::op_count;
+use alloc::vec::Vec;
 use crypto_bigint::{Encoding, Uint, impl_modulus, modular::constant_mod::Residue};
 
 // --- 256 bit field size for platform word width ---
@@ -24,24 +26,43 @@ impl_modulus!(
 
 pub type FpBN256 = Residue<ModulusBN254, LIMBSIZE>;
 
-/// Trait providing in-place modular operations
+/// Trait providing in-place modular operations, counted by
+/// [`op_count`](super::op_count) with the `op-count` feature.
 pub trait ModMathInPlace {
     fn square_in_place(&mut self) -> &mut Self;
     fn double_in_place(&mut self) -> &mut Self;
+    fn mul_in_place(&mut self, rhs: &Self) -> &mut Self;
+    fn add_in_place(&mut self, rhs: &Self) -> &mut Self;
 }
 
 impl ModMathInPlace for FpBN256 {
     #[inline]
     fn square_in_place(&mut self) -> &mut Self {
+        op_count::record_square();
         *self *= *self;
         self
     }
 
     #[inline]
     fn double_in_place(&mut self) -> &mut Self {
+        op_count::record_double();
         *self += *self;
         self
     }
+
+    #[inline]
+    fn mul_in_place(&mut self, rhs: &Self) -> &mut Self {
+        op_count::record_mul();
+        *self *= *rhs;
+        self
+    }
+
+    #[inline]
+    fn add_in_place(&mut self, rhs: &Self) -> &mut Self {
+        op_count::record_add();
+        *self += *rhs;
+        self
+    }
 }
 
 /// Converts a big-endian byte slice into a `Vec<FpBN256>` by modular reduction.
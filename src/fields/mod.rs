@@ -1,4 +1,7 @@
 #[allow(clippy::too_many_arguments)]
-#[allow(clippy::derive_hash_xor_eq)]
+#[allow(clippy::derived_hash_with_manual_eq)]
 pub mod bn256;
+pub mod op_count;
+#[cfg(feature = "serde")]
+pub mod serde_hex;
 pub mod utils;
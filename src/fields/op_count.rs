@@ -0,0 +1,307 @@
+//! Counts of the `FpBN256` operations performed by the permutation, the
+//! sponges and everything built on them, and a cost model turning them into
+//! estimated cycles.
+//!
+//! Operations are counted through [`ModMathInPlace`](super::bn256::ModMathInPlace),
+//! and only with the `op-count` feature; without it the counters are no-ops
+//! and [`snapshot`] is always zero. The counters are per thread with `std`,
+//! and global otherwise. With per-thread counters, operations are
+//! counted on the thread performing them; `Poseidon2::permute_batch` credits
+//! the operations of its rayon workers to the calling thread, other code
+//! spreading work over threads has to do the same to be measured.
+
+use core::ops::{Add, Sub};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OpCounts {
+    pub mul: u64,
+    pub square: u64,
+    pub add: u64,
+    pub double: u64,
+}
+
+impl OpCounts {
+    pub const ZERO: OpCounts = OpCounts {
+        mul: 0,
+        square: 0,
+        add: 0,
+        double: 0,
+    };
+
+    /// Estimated cycles under `model`.
+    pub fn cost(&self, model: &CostModel) -> u64 {
+        self.mul * model.mul
+            + self.square * model.square
+            + self.add * model.add
+            + self.double * model.double
+    }
+}
+
+impl Add for OpCounts {
+    type Output = OpCounts;
+
+    fn add(self, rhs: OpCounts) -> OpCounts {
+        OpCounts {
+            mul: self.mul + rhs.mul,
+            square: self.square + rhs.square,
+            add: self.add + rhs.add,
+            double: self.double + rhs.double,
+        }
+    }
+}
+
+impl Sub for OpCounts {
+    type Output = OpCounts;
+
+    fn sub(self, rhs: OpCounts) -> OpCounts {
+        OpCounts {
+            mul: self.mul - rhs.mul,
+            square: self.square - rhs.square,
+            add: self.add - rhs.add,
+            double: self.double - rhs.double,
+        }
+    }
+}
+
+/// Cycles per operation.
+///
+/// No weights are shipped for SP1, as they depend on the zkVM version and on
+/// whether the `crypto-bigint` precompile patch is in use. Calibrate them by
+/// running [`measure`] in the guest next to SP1's cycle tracker.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CostModel {
+    pub mul: u64,
+    pub square: u64,
+    pub add: u64,
+    pub double: u64,
+}
+
+impl CostModel {
+    /// Every operation costs one cycle, i.e. the total operation count.
+    pub const UNIT: CostModel = CostModel {
+        mul: 1,
+        square: 1,
+        add: 1,
+        double: 1,
+    };
+}
+
+/// Current values of the counters.
+pub fn snapshot() -> OpCounts {
+    counters::get()
+}
+
+/// Sets the counters of the current thread (or the global ones), e.g. to
+/// credit operations performed on other threads.
+#[allow(dead_code)]
+pub(crate) fn set(counts: OpCounts) {
+    counters::set(counts);
+}
+
+/// Runs `f` and returns its result with the operations it performed.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, OpCounts) {
+    let before = snapshot();
+    let res = f();
+    (res, snapshot() - before)
+}
+
+#[derive(Clone, Copy)]
+enum Op {
+    Mul,
+    Square,
+    Add,
+    Double,
+}
+
+#[cfg(all(feature = "op-count", any(feature = "std", test)))]
+mod counters {
+    use super::{Op, OpCounts};
+    use core::cell::Cell;
+
+    std::thread_local! {
+        static COUNTS: Cell<OpCounts> = const { Cell::new(OpCounts::ZERO) };
+    }
+
+    pub(super) fn get() -> OpCounts {
+        COUNTS.with(Cell::get)
+    }
+
+    pub(super) fn set(counts: OpCounts) {
+        COUNTS.with(|c| c.set(counts));
+    }
+
+    pub(super) fn bump(op: Op) {
+        COUNTS.with(|counts| {
+            let mut c = counts.get();
+            match op {
+                Op::Mul => c.mul += 1,
+                Op::Square => c.square += 1,
+                Op::Add => c.add += 1,
+                Op::Double => c.double += 1,
+            }
+            counts.set(c);
+        });
+    }
+}
+
+// The 32-bit zkVM targets have neither `AtomicU64` nor compare-and-swap, so
+// every counter is kept in two `AtomicU32` halves updated with plain loads and
+// stores. This is meant for the single-threaded guest: concurrent updates from
+// several threads may be lost.
+#[cfg(all(feature = "op-count", not(any(feature = "std", test))))]
+mod counters {
+    use super::{Op, OpCounts};
+    use core::sync::atomic::{AtomicU32, Ordering};
+
+    struct Counter {
+        lo: AtomicU32,
+        hi: AtomicU32,
+    }
+
+    impl Counter {
+        const fn new() -> Self {
+            Counter {
+                lo: AtomicU32::new(0),
+                hi: AtomicU32::new(0),
+            }
+        }
+
+        fn get(&self) -> u64 {
+            ((self.hi.load(Ordering::Relaxed) as u64) << 32)
+                | self.lo.load(Ordering::Relaxed) as u64
+        }
+
+        fn set(&self, value: u64) {
+            self.lo.store(value as u32, Ordering::Relaxed);
+            self.hi.store((value >> 32) as u32, Ordering::Relaxed);
+        }
+    }
+
+    static MUL: Counter = Counter::new();
+    static SQUARE: Counter = Counter::new();
+    static ADD: Counter = Counter::new();
+    static DOUBLE: Counter = Counter::new();
+
+    pub(super) fn get() -> OpCounts {
+        OpCounts {
+            mul: MUL.get(),
+            square: SQUARE.get(),
+            add: ADD.get(),
+            double: DOUBLE.get(),
+        }
+    }
+
+    pub(super) fn set(counts: OpCounts) {
+        MUL.set(counts.mul);
+        SQUARE.set(counts.square);
+        ADD.set(counts.add);
+        DOUBLE.set(counts.double);
+    }
+
+    pub(super) fn bump(op: Op) {
+        let counter = match op {
+            Op::Mul => &MUL,
+            Op::Square => &SQUARE,
+            Op::Add => &ADD,
+            Op::Double => &DOUBLE,
+        };
+        counter.set(counter.get() + 1);
+    }
+}
+
+#[cfg(not(feature = "op-count"))]
+mod counters {
+    use super::{Op, OpCounts};
+
+    #[inline(always)]
+    pub(super) fn get() -> OpCounts {
+        OpCounts::ZERO
+    }
+
+    #[inline(always)]
+    pub(super) fn set(_counts: OpCounts) {}
+
+    #[inline(always)]
+    pub(super) fn bump(_op: Op) {}
+}
+
+#[inline(always)]
+pub(crate) fn record_mul() {
+    counters::bump(Op::Mul);
+}
+
+#[inline(always)]
+pub(crate) fn record_square() {
+    counters::bump(Op::Square);
+}
+
+#[inline(always)]
+pub(crate) fn record_add() {
+    counters::bump(Op::Add);
+}
+
+#[inline(always)]
+pub(crate) fn record_double() {
+    counters::bump(Op::Double);
+}
+
+#[cfg(test)]
+mod op_count_tests {
+    use super::*;
+    use crate::fields::bn256::FpBN256;
+    use crate::poseidon2::{
+        poseidon2::Poseidon2, poseidon2_instance_bn256::POSEIDON2_BN256_PARAMS,
+    };
+
+    #[test]
+    fn cost() {
+        let counts = OpCounts {
+            mul: 80,
+            square: 160,
+            add: 407,
+            double: 56,
+        };
+        assert_eq!(counts.cost(&CostModel::UNIT), 703);
+        let model = CostModel {
+            mul: 10,
+            square: 8,
+            add: 2,
+            double: 1,
+        };
+        assert_eq!(counts.cost(&model), 800 + 1280 + 814 + 56);
+    }
+
+    #[test]
+    fn permutation_counts() {
+        let poseidon2 = Poseidon2::new(&POSEIDON2_BN256_PARAMS);
+        let (_, counts) = measure(|| poseidon2.permutation(&[FpBN256::ONE; 3]));
+        if cfg!(feature = "op-count") {
+            // t = 3, d = 5, R_F = 8, R_P = 56: one mul and two squarings per
+            // sbox, five additions per linear layer plus one doubling in the
+            // internal one, and 3 + 55 partial round constants
+            let expected = OpCounts {
+                mul: 80,
+                square: 160,
+                add: 9 * 5 + 8 * 3 + 3 + 55 + 56 * 5,
+                double: 56,
+            };
+            assert_eq!(counts, expected);
+        } else {
+            assert_eq!(counts, OpCounts::ZERO);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "op-count", feature = "parallel"))]
+    fn parallel_batch_counts() {
+        let poseidon2 = Poseidon2::new(&POSEIDON2_BN256_PARAMS);
+        let (_, single) = measure(|| poseidon2.permutation(&[FpBN256::ONE; 3]));
+        // large enough to be spread over the thread pool
+        let mut states = [[FpBN256::ONE; 3]; 150];
+        let (_, counts) = measure(|| poseidon2.permute_batch(&mut states));
+        assert_eq!(counts.mul, 150 * single.mul);
+        assert_eq!(counts.square, 150 * single.square);
+        assert_eq!(counts.add, 150 * single.add);
+        assert_eq!(counts.double, 150 * single.double);
+    }
+}
@@ -0,0 +1,85 @@
+//! Serde helpers encoding field elements as `0x`-prefixed hex strings, for
+//! use with `#[serde(with = "...")]`. Values `>= p` are rejected rather than
+//! reduced, see [`try_from_hex`].
+
+use super::bn256::FpBN256;
+use super::utils::{to_hex, try_from_hex};
+use alloc::{string::String, vec::Vec};
+use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+pub fn serialize<S: Serializer>(el: &FpBN256, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&to_hex(el))
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<FpBN256, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    try_from_hex(&s).ok_or_else(|| D::Error::custom("invalid or non-canonical hex field element"))
+}
+
+/// The same encoding for sequences of field elements.
+pub mod vec {
+    use super::*;
+    use serde::ser::SerializeSeq;
+
+    pub fn serialize<S: Serializer>(els: &[FpBN256], serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(els.len()))?;
+        for el in els {
+            seq.serialize_element(&to_hex(el))?;
+        }
+        seq.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<FpBN256>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|s| {
+                try_from_hex(s)
+                    .ok_or_else(|| D::Error::custom("invalid or non-canonical hex field element"))
+            })
+            .collect()
+    }
+}
+
+/// The same encoding for matrices, as a sequence of rows.
+pub mod matrix {
+    use super::*;
+    use serde::ser::SerializeSeq;
+
+    struct Row<'a>(&'a [FpBN256]);
+
+    impl serde::Serialize for Row<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::vec::serialize(self.0, serializer)
+        }
+    }
+
+    pub fn serialize<S: Serializer>(
+        rows: &[Vec<FpBN256>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(rows.len()))?;
+        for row in rows {
+            seq.serialize_element(&Row(row))?;
+        }
+        seq.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Vec<FpBN256>>, D::Error> {
+        Vec::<Vec<String>>::deserialize(deserializer)?
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|s| {
+                        try_from_hex(s).ok_or_else(|| {
+                            D::Error::custom("invalid or non-canonical hex field element")
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
@@ -1,22 +1,99 @@
-use crate::fields::bn256::{FpBN256, U256Field};
+use crate::fields::bn256::{FpBN256, ModulusBN254, U256Field};
+use alloc::string::String;
+use core::fmt::Write;
+use crypto_bigint::{Encoding, Word, modular::constant_mod::ResidueParams};
 
 /// Converts a hex string into FpBN256
-/// Interpret as a big-endian number, reducing as needed
-/// FIXME: vet this is actually correct!
-/// TODO: Can this be made a const fn? Avoid lazy_static for performance
-pub fn from_hex(s: &str) -> FpBN256 {
-    let s = s.strip_prefix("0x").unwrap_or(s);
-    let bytes = hex::decode(s).expect("invalid hex");
+/// Interpret as a big-endian number, reducing as needed. The `0x` prefix is
+/// optional.
+///
+/// This is a `const fn`: constant tables built with it are evaluated at
+/// compile time and stored in Montgomery form.
+pub const fn from_hex(s: &str) -> FpBN256 {
+    let bytes = s.as_bytes();
+    let mut i = if bytes.len() >= 2 && bytes[0] == b'0' && (bytes[1] == b'x' || bytes[1] == b'X') {
+        2
+    } else {
+        0
+    };
 
-    let mut res = FpBN256::new(&U256Field::ZERO);
-    let radix = FpBN256::new(&U256Field::from_u64(256));
+    // digits beyond the last 64 are folded in one at a time
+    let mut res = FpBN256::ZERO;
+    let long = bytes.len() - i > 64;
+    let sixteen = FpBN256::new(&U256Field::from_u8(16));
+    while bytes.len() - i > 64 {
+        let digit = FpBN256::new(&U256Field::from_u8(hex_digit(bytes[i])));
+        res = res.mul(&sixteen).add(&digit);
+        i += 1;
+    }
+
+    // the last (at most) 64 digits fit into a U256Field; they are packed
+    // into words directly, which keeps large constant tables cheap to
+    // evaluate at compile time
+    const DIGITS_PER_WORD: usize = 2 * core::mem::size_of::<Word>();
+    let mut words = [0 as Word; U256Field::LIMBS];
+    let mut k = 0; // digit position, from the least significant one
+    let mut j = bytes.len();
+    while j > i {
+        j -= 1;
+        words[k / DIGITS_PER_WORD] |= (hex_digit(bytes[j]) as Word) << (4 * (k % DIGITS_PER_WORD));
+        k += 1;
+    }
+    let low = U256Field::from_words(words);
+
+    if long {
+        // res * 2^256 + low
+        let two_256 = FpBN256::new(&U256Field::MAX).add(&FpBN256::ONE);
+        res = res.mul(&two_256);
+    }
+    res.add(&FpBN256::new(&low))
+}
+
+/// Like [`from_hex`], but returns `None` instead of panicking for an empty or
+/// non-hex string, and instead of reducing for a value `>= p`, so that every
+/// element has a single accepted encoding (up to leading zeros and case).
+pub fn try_from_hex(s: &str) -> Option<FpBN256> {
+    let digits = s
+        .strip_prefix("0x")
+        .or_else(|| s.strip_prefix("0X"))
+        .unwrap_or(s);
+    if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let significant = digits.trim_start_matches('0');
+    if significant.len() > 64 || from_hex_unreduced(significant) >= ModulusBN254::MODULUS {
+        return None;
+    }
+    Some(from_hex(s))
+}
 
-    for &byte in bytes.iter() {
-        res *= &radix;
-        res += FpBN256::new(&U256Field::from_u64(byte as u64));
+// At most 64 hex digits as an integer
+fn from_hex_unreduced(digits: &str) -> U256Field {
+    let mut bytes = [0u8; 32];
+    for (k, c) in digits.bytes().rev().enumerate() {
+        bytes[31 - k / 2] |= hex_digit(c) << (4 * (k % 2));
     }
+    U256Field::from_be_bytes(bytes)
+}
 
-    res
+/// Canonical `0x`-prefixed, zero-padded lowercase hex encoding, as accepted
+/// by [`from_hex`].
+pub fn to_hex(el: &FpBN256) -> String {
+    let mut s = String::with_capacity(66);
+    s.push_str("0x");
+    for byte in el.retrieve().to_be_bytes() {
+        let _ = write!(s, "{:02x}", byte);
+    }
+    s
+}
+
+const fn hex_digit(c: u8) -> u8 {
+    match c {
+        b'0'..=b'9' => c - b'0',
+        b'a'..=b'f' => c - b'a' + 10,
+        b'A'..=b'F' => c - b'A' + 10,
+        _ => panic!("invalid hex"),
+    }
 }
 
 #[cfg(feature = "std")]
@@ -40,3 +117,71 @@ pub fn random_scalar_without_0() -> FpBN256 {
         }
     }
 }
+
+#[cfg(test)]
+mod fields_utils_tests {
+    use super::*;
+    use alloc::format;
+
+    // byte-wise Horner evaluation, as `from_hex` used to compute it
+    fn from_hex_reference(s: &str) -> FpBN256 {
+        let radix = FpBN256::new(&U256Field::from_u64(16));
+        s.trim_start_matches("0x")
+            .chars()
+            .fold(FpBN256::ZERO, |acc, c| {
+                let digit = c.to_digit(16).unwrap() as u64;
+                acc * radix + FpBN256::new(&U256Field::from_u64(digit))
+            })
+    }
+
+    #[test]
+    fn from_hex_const() {
+        const FORTY_TWO: FpBN256 = from_hex("0x2a");
+        assert_eq!(FORTY_TWO, FpBN256::new(&U256Field::from_u64(42)));
+
+        for s in [
+            "0x0",
+            "abc",
+            "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "0x30644E72E131A029B85045B68181585D2833E84879B9709143E1F593F0000001",
+            "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "0x1ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "0x123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789",
+        ] {
+            assert_eq!(from_hex(s), from_hex_reference(s), "{s}");
+        }
+        assert_eq!(
+            from_hex("0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001"),
+            FpBN256::ZERO
+        );
+    }
+
+    #[test]
+    fn hex_roundtrip() {
+        let el = from_hex("0x2a");
+        assert_eq!(
+            to_hex(&el),
+            "0x000000000000000000000000000000000000000000000000000000000000002a"
+        );
+        assert_eq!(try_from_hex(&to_hex(&el)), Some(el));
+        assert_eq!(try_from_hex("2A"), Some(el));
+        assert_eq!(try_from_hex("0x"), None);
+        assert_eq!(try_from_hex("0x2g"), None);
+        assert_eq!(try_from_hex("0x0"), Some(FpBN256::ZERO));
+        assert_eq!(try_from_hex(&format!("0x{}2a", "0".repeat(70))), Some(el));
+    }
+
+    #[test]
+    fn try_from_hex_rejects_non_canonical() {
+        // p - 1, p and p + 1
+        let p_minus_1 = "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000";
+        let p = "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+        let p_plus_1 = "0x30644E72E131A029B85045B68181585D2833E84879B9709143E1F593F0000002";
+        assert_eq!(try_from_hex(p_minus_1), Some(-FpBN256::ONE));
+        assert_eq!(try_from_hex(p), None);
+        assert_eq!(try_from_hex(p_plus_1), None);
+        assert_eq!(try_from_hex(&format!("0x1{}", "0".repeat(64))), None);
+        // `from_hex` still reduces
+        assert_eq!(from_hex(p_plus_1), FpBN256::ONE);
+    }
+}
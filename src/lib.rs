@@ -1,4 +1,12 @@
 //! # SP1 Poseidon2 Hash (bn256)
+//!
+//! `no_std` with `alloc`; the `std` feature only adds the RNG helpers.
+#![no_std]
+
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
 pub mod fields;
 pub mod merkle_tree;
 pub mod poseidon2;
@@ -1,13 +1,59 @@
-use crate::fields::bn256::FpBN256;
-use std::marker::PhantomData;
+use crate::fields::bn256::{FpBN256, ModMathInPlace};
+use alloc::{borrow::ToOwned, vec::Vec};
+use core::marker::PhantomData;
+
+/// How a permutation `P` compresses inputs `x`, zero-padded to its width,
+/// into a single element (Poseidon2 paper, Section 5.2).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CompressionMode {
+    /// `P(x)[0]`. Not the mode the paper recommends, kept for compatibility
+    /// with earlier digests.
+    #[default]
+    Truncation,
+    /// `P(x)[0] + x[0]`, the truncated permutation with feed-forward.
+    FeedForward,
+    /// Jive_b: the sum over all lanes of `P(x) + x`.
+    Jive,
+}
+
+impl CompressionMode {
+    /// Output of the mode, given the padded `input` and its permutation.
+    pub fn finish(self, input: &[FpBN256], output: &[FpBN256]) -> FpBN256 {
+        match self {
+            CompressionMode::Truncation => output[0],
+            CompressionMode::FeedForward => {
+                let mut res = output[0];
+                res.add_in_place(&input[0]);
+                res
+            }
+            CompressionMode::Jive => {
+                let mut res = FpBN256::ZERO;
+                for (x, y) in input.iter().zip(output) {
+                    res.add_in_place(x);
+                    res.add_in_place(y);
+                }
+                res
+            }
+        }
+    }
+}
 
 pub trait MerkleTreeHash {
     fn compress(&self, input: &[&FpBN256]) -> FpBN256;
 }
 
+/// A [`MerkleTreeHash`] that can also compress in the other
+/// [`CompressionMode`]s; `compress` is the [`CompressionMode::Truncation`]
+/// case.
+pub trait MerkleTreeHashModes: MerkleTreeHash {
+    fn compress_with_mode(&self, input: &[&FpBN256], mode: CompressionMode) -> FpBN256;
+}
+
 #[derive(Clone, Debug)]
 pub struct MerkleTree<P: MerkleTreeHash> {
     perm: P,
+    mode: CompressionMode,
+    compress: fn(&P, &[&FpBN256], CompressionMode) -> FpBN256,
     field: PhantomData<FpBN256>,
 }
 
@@ -15,10 +61,16 @@ impl<P: MerkleTreeHash> MerkleTree<P> {
     pub fn new(perm: P) -> Self {
         MerkleTree {
             perm,
+            mode: CompressionMode::default(),
+            compress: |perm, input, _| perm.compress(input),
             field: PhantomData,
         }
     }
 
+    pub fn mode(&self) -> CompressionMode {
+        self.mode
+    }
+
     fn round_up_pow_n(input: usize, n: usize) -> usize {
         debug_assert!(n >= 1);
         let mut res = 1;
@@ -55,7 +107,7 @@ impl<P: MerkleTreeHash> MerkleTree<P> {
             let mut new_nodes: Vec<FpBN256> = Vec::with_capacity(new_len);
             for i in (0..nodes.len()).step_by(2) {
                 let inp = [&nodes[i], &nodes[i + 1]];
-                let dig = self.perm.compress(&inp);
+                let dig = (self.compress)(&self.perm, &inp, self.mode);
                 new_nodes.push(dig);
             }
             nodes = new_nodes;
@@ -63,3 +115,14 @@ impl<P: MerkleTreeHash> MerkleTree<P> {
         nodes[0].to_owned()
     }
 }
+
+impl<P: MerkleTreeHashModes> MerkleTree<P> {
+    pub fn with_mode(perm: P, mode: CompressionMode) -> Self {
+        MerkleTree {
+            perm,
+            mode,
+            compress: |perm, input, mode| perm.compress_with_mode(input, mode),
+            field: PhantomData,
+        }
+    }
+}
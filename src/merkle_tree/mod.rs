@@ -1 +1,2 @@
+pub mod merkle_damgard;
 pub mod merkle_tree_fp;
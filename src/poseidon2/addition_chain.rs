@@ -0,0 +1,92 @@
+use alloc::{vec, vec::Vec};
+
+/// Sbox degrees must be below this bound. Star chains, which are searched
+/// here, are optimal addition chains for all exponents below 12509.
+pub const MAX_SBOX_DEGREE: usize = 1024;
+
+/// Upper bound on the chain length for exponents below `MAX_SBOX_DEGREE`.
+pub(crate) const MAX_CHAIN_LEN: usize = 14;
+
+/// Shortest addition chain for `d`, as steps `(i, j)`: starting from
+/// `a[0] = 1`, step `k` computes `a[k + 1] = a[i] + a[j]`. Evaluating `x^d`
+/// costs one multiplication per step (a squaring if `i == j`).
+pub fn addition_chain(d: usize) -> Vec<(usize, usize)> {
+    assert!((1..MAX_SBOX_DEGREE).contains(&d));
+    let mut chain = vec![1];
+    let mut steps = Vec::new();
+    for len in 0.. {
+        if search(d, len, &mut chain, &mut steps) {
+            break;
+        }
+    }
+    steps
+}
+
+// Depth-first search over star chains, where each element is the previous
+// one plus an earlier element, of at most `len` steps.
+fn search(d: usize, len: usize, chain: &mut Vec<usize>, steps: &mut Vec<(usize, usize)>) -> bool {
+    let last = chain[chain.len() - 1];
+    if last == d {
+        return true;
+    }
+    let remaining = len - steps.len();
+    if remaining == 0 || last << remaining < d {
+        return false;
+    }
+
+    let k = chain.len() - 1;
+    for j in (0..=k).rev() {
+        let next = last + chain[j];
+        if next > d {
+            continue;
+        }
+        chain.push(next);
+        steps.push((k, j));
+        if search(d, len, chain, steps) {
+            return true;
+        }
+        chain.pop();
+        steps.pop();
+    }
+    false
+}
+
+#[cfg(test)]
+mod addition_chain_tests {
+    use super::*;
+
+    fn evaluate(chain: &[(usize, usize)]) -> usize {
+        let mut a = vec![1];
+        for &(i, j) in chain {
+            a.push(a[i] + a[j]);
+        }
+        a[a.len() - 1]
+    }
+
+    #[test]
+    fn optimal_lengths() {
+        // l(n) from OEIS A003313
+        for (d, len) in [
+            (1, 0),
+            (3, 2),
+            (5, 3),
+            (7, 4),
+            (11, 5),
+            (127, 10),
+            (191, 11),
+        ] {
+            let chain = addition_chain(d);
+            assert_eq!(chain.len(), len, "d = {}", d);
+            assert_eq!(evaluate(&chain), d);
+        }
+    }
+
+    #[test]
+    fn small_degrees() {
+        for d in (1..256).chain([MAX_SBOX_DEGREE - 1]) {
+            let chain = addition_chain(d);
+            assert!(chain.len() <= MAX_CHAIN_LEN);
+            assert_eq!(evaluate(&chain), d);
+        }
+    }
+}
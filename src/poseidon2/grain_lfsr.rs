@@ -0,0 +1,129 @@
+use crate::fields::bn256::{FpBN256, ModulusBN254, U256Field};
+use alloc::{vec, vec::Vec};
+use crypto_bigint::modular::constant_mod::ResidueParams;
+
+/// Grain LFSR in self-shrinking mode, as used by the HorizenLabs reference
+/// sage scripts (`poseidon2_rust_params.sage`) to derive Poseidon2 round
+/// constants for prime fields and x^d sboxes.
+#[derive(Clone, Debug)]
+pub struct GrainLfsr {
+    // 80-bit register, bit `i` is the i-th oldest bit of the sequence
+    state: u128,
+}
+
+impl GrainLfsr {
+    const STATE_SIZE: usize = 80;
+
+    /// Seeds the register with the instance description:
+    /// field type (2 bits, 1 = prime field), sbox type (4 bits, 0 = x^d),
+    /// field size n (12 bits), t (12 bits), R_F (10 bits), R_P (10 bits)
+    /// and 30 bits set to one, then discards the first 160 output bits.
+    pub fn new(t: usize, rounds_f: usize, rounds_p: usize) -> Self {
+        let mut lfsr = GrainLfsr { state: 0 };
+        let mut pos = 0;
+        let mut append = |value: usize, bits: usize| {
+            for i in (0..bits).rev() {
+                lfsr.state |= (((value >> i) & 1) as u128) << pos;
+                pos += 1;
+            }
+        };
+        append(1, 2);
+        append(0, 4);
+        append(Self::field_size(), 12);
+        append(t, 12);
+        append(rounds_f, 10);
+        append(rounds_p, 10);
+        append((1 << 30) - 1, 30);
+        debug_assert_eq!(pos, Self::STATE_SIZE);
+
+        for _ in 0..160 {
+            lfsr.update();
+        }
+        lfsr
+    }
+
+    /// Bit length of the BN254 scalar field modulus.
+    pub fn field_size() -> usize {
+        ModulusBN254::MODULUS.bits()
+    }
+
+    fn update(&mut self) -> bool {
+        let bit = |i: usize| (self.state >> i) & 1;
+        let new_bit = bit(62) ^ bit(51) ^ bit(38) ^ bit(23) ^ bit(13) ^ bit(0);
+        self.state = (self.state >> 1) | (new_bit << (Self::STATE_SIZE - 1));
+        new_bit == 1
+    }
+
+    /// Next output bit of the self-shrinking generator: bits are consumed in
+    /// pairs, the second one is output if the first one is set.
+    pub fn next_bit(&mut self) -> bool {
+        loop {
+            let select = self.update();
+            let bit = self.update();
+            if select {
+                return bit;
+            }
+        }
+    }
+
+    /// Reads `field_size()` bits, most significant bit first.
+    pub fn next_uint(&mut self) -> U256Field {
+        let mut res = U256Field::ZERO;
+        for _ in 0..Self::field_size() {
+            res = res.shl_vartime(1);
+            if self.next_bit() {
+                res = res.wrapping_add(&U256Field::ONE);
+            }
+        }
+        res
+    }
+
+    /// Uniform field element, sampled by rejecting integers >= p.
+    pub fn next_field_element(&mut self) -> FpBN256 {
+        loop {
+            let n = self.next_uint();
+            if n < ModulusBN254::MODULUS {
+                return FpBN256::new(&n);
+            }
+        }
+    }
+
+    /// Field element obtained by reducing the next integer modulo p, as the
+    /// reference script does when sampling matrix entries.
+    pub fn next_field_element_reduced(&mut self) -> FpBN256 {
+        FpBN256::new(&self.next_uint())
+    }
+}
+
+/// Round constants for a Poseidon2 instance over the BN254 scalar field.
+///
+/// Full rounds get `t` constants, partial rounds a single one in lane 0 (the
+/// other lanes are zero), consumed in round order from the Grain LFSR.
+pub fn generate_round_constants(t: usize, rounds_f: usize, rounds_p: usize) -> Vec<Vec<FpBN256>> {
+    let mut grain = GrainLfsr::new(t, rounds_f, rounds_p);
+    let r_f = rounds_f / 2;
+    (0..rounds_f + rounds_p)
+        .map(|r| {
+            let mut rc = vec![FpBN256::ZERO; t];
+            if r < r_f || r >= r_f + rounds_p {
+                rc.iter_mut().for_each(|c| *c = grain.next_field_element());
+            } else {
+                rc[0] = grain.next_field_element();
+            }
+            rc
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod grain_lfsr_tests {
+    use super::*;
+    use crate::poseidon2::poseidon2_instance_bn256::RC3;
+
+    #[test]
+    fn regenerates_rc3() {
+        assert_eq!(GrainLfsr::field_size(), 254);
+        let rc = generate_round_constants(3, 8, 56);
+        assert_eq!(rc.concat(), RC3.as_flattened());
+    }
+}
@@ -1,4 +1,18 @@
+pub mod addition_chain;
+pub mod grain_lfsr;
+pub mod internal_matrix;
 #[allow(clippy::module_inception)]
 pub mod poseidon2;
+pub mod poseidon2_fixed;
+pub mod poseidon2_gnark;
 pub mod poseidon2_instance_bn256;
+pub mod poseidon2_noir;
 pub mod poseidon2_params;
+#[cfg(feature = "serde")]
+pub mod poseidon2_params_serde;
+pub mod poseidon2_safe;
+pub mod poseidon2_sponge;
+pub mod poseidon2_trace;
+pub mod registry;
+pub mod round_numbers;
+pub mod sage_params;
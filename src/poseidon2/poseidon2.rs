@@ -1,18 +1,43 @@
-use super::poseidon2_params::Poseidon2Params;
-use crate::fields::bn256::FpBN256;
-use crate::{fields::bn256::ModMathInPlace, merkle_tree::merkle_tree_fp::MerkleTreeHash};
-use core::ops::{AddAssign, MulAssign};
-use std::sync::Arc;
+use super::{
+    poseidon2_fixed::Poseidon2Fixed,
+    poseidon2_params::{Poseidon2Error, Poseidon2Params, Poseidon2ParamsRef},
+    poseidon2_trace::Poseidon2Trace,
+};
+use crate::fields::bn256::{FpBN256, ModMathInPlace, U256Field};
+use crate::merkle_tree::merkle_tree_fp::{CompressionMode, MerkleTreeHash, MerkleTreeHashModes};
+use crate::utils;
+use alloc::{borrow::ToOwned, vec, vec::Vec};
+use core::ops::{AddAssign, SubAssign};
+
+// Calls `Poseidon2Fixed::<t>::$f` on a state slice of runtime width `t`.
+macro_rules! dispatch_width {
+    ($t:expr, $f:ident, $params:expr, $state:expr) => {
+        dispatch_width!(
+            @widths $t, $f, $params, $state;
+            2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24
+        )
+    };
+    (@widths $t:expr, $f:ident, $params:expr, $state:expr; $($w:literal)*) => {
+        match $t {
+            $($w => Poseidon2Fixed::<$w>::$f($params, $state.try_into().unwrap()),)*
+            t => unreachable!("width {} rejected by Poseidon2Params::try_new", t),
+        }
+    };
+}
+
+// Smallest batch for which `permute_batch` hands work to rayon.
+#[cfg(feature = "parallel")]
+const PARALLEL_MIN_BATCH: usize = 64;
 
 #[derive(Clone, Debug)]
 pub struct Poseidon2 {
-    pub(crate) params: Arc<Poseidon2Params>,
+    pub(crate) params: Poseidon2ParamsRef,
 }
 
 impl Poseidon2 {
-    pub fn new(params: &Arc<Poseidon2Params>) -> Self {
+    pub fn new(params: impl Into<Poseidon2ParamsRef>) -> Self {
         Poseidon2 {
-            params: Arc::clone(params),
+            params: params.into(),
         }
     }
 
@@ -21,241 +46,265 @@ impl Poseidon2 {
     }
 
     pub fn permutation(&self, input: &[FpBN256]) -> Vec<FpBN256> {
-        let t = self.params.t;
-        assert_eq!(input.len(), t);
-
         let mut current_state = input.to_owned();
+        self.permute_slice_in_place(&mut current_state);
+        current_state
+    }
 
-        // Linear layer at beginning
-        self.matmul_external(&mut current_state);
+    /// Like [`Self::permutation`], but returns an error instead of panicking
+    /// if `input` does not have t elements.
+    pub fn try_permutation(&self, input: &[FpBN256]) -> Result<Vec<FpBN256>, Poseidon2Error> {
+        self.check_state_length(input.len())?;
+        Ok(self.permutation(input))
+    }
+
+    /// Like [`Self::permute_slice_in_place`], but returns an error instead of
+    /// panicking if `state` does not have t elements.
+    pub fn try_permute_slice_in_place(&self, state: &mut [FpBN256]) -> Result<(), Poseidon2Error> {
+        self.check_state_length(state.len())?;
+        self.permute_slice_in_place(state);
+        Ok(())
+    }
 
-        for r in 0..self.params.rounds_f_beginning {
-            current_state = self.add_rc(&current_state, &self.params.round_constants[r]);
-            current_state = self.sbox(&current_state);
-            self.matmul_external(&mut current_state);
+    fn check_state_length(&self, len: usize) -> Result<(), Poseidon2Error> {
+        if len != self.params.t {
+            return Err(Poseidon2Error::InvalidStateLength {
+                expected: self.params.t,
+                got: len,
+            });
         }
+        Ok(())
+    }
+
+    /// In-place permutation of a fixed-size state, without heap allocation.
+    pub fn permute_in_place<const T: usize>(&self, state: &mut [FpBN256; T]) {
+        assert_eq!(self.params.t, T);
+        Poseidon2Fixed::<T>::permute_with(&self.params, state);
+    }
+
+    /// In-place permutation of a state slice of length t, without heap allocation.
+    pub fn permute_slice_in_place(&self, state: &mut [FpBN256]) {
+        assert_eq!(state.len(), self.params.t);
+        dispatch_width!(self.params.t, permute_with, &self.params, state);
+    }
+
+    /// Permutes every state of `states` in place, with the same result as
+    /// [`Self::permute_in_place`] on each. With the `parallel` feature,
+    /// batches of at least `PARALLEL_MIN_BATCH` states are split over the
+    /// rayon thread pool.
+    pub fn permute_batch<const T: usize>(&self, states: &mut [[FpBN256; T]]) {
+        assert_eq!(self.params.t, T);
+
+        #[cfg(feature = "parallel")]
+        if states.len() >= PARALLEL_MIN_BATCH {
+            use rayon::prelude::*;
 
-        let p_end = self.params.rounds_f_beginning + self.params.rounds_p;
-        for r in self.params.rounds_f_beginning..p_end {
-            current_state[0].add_assign(&self.params.round_constants[r][0]);
-            current_state[0] = self.sbox_p(&current_state[0]);
-            self.matmul_internal(&mut current_state, &self.params.mat_internal_diag_m_1);
+            let permute = |state: &mut [FpBN256; T]| {
+                Poseidon2Fixed::<T>::permute_with(&self.params, state);
+            };
+            #[cfg(not(feature = "op-count"))]
+            states
+                .par_iter_mut()
+                .with_min_len(PARALLEL_MIN_BATCH / 4)
+                .for_each(permute);
+            // the op counters are per thread, credit the operations of the
+            // workers to the calling thread
+            #[cfg(feature = "op-count")]
+            {
+                use crate::fields::op_count::{self, OpCounts};
+
+                let before = op_count::snapshot();
+                let counts = states
+                    .par_iter_mut()
+                    .with_min_len(PARALLEL_MIN_BATCH / 4)
+                    .map(|state| op_count::measure(|| permute(state)).1)
+                    .reduce(|| OpCounts::ZERO, |a, b| a + b);
+                op_count::set(before + counts);
+            }
+            return;
         }
 
-        for r in p_end..self.params.rounds {
-            current_state = self.add_rc(&current_state, &self.params.round_constants[r]);
-            current_state = self.sbox(&current_state);
-            self.matmul_external(&mut current_state);
+        for state in states.iter_mut() {
+            Poseidon2Fixed::<T>::permute_with(&self.params, state);
         }
+    }
+
+    /// Permutation as written in the paper, adding a round-constant vector in
+    /// every round. Equivalent to [`Self::permutation`], kept as a reference.
+    pub fn permutation_unoptimized(&self, input: &[FpBN256]) -> Vec<FpBN256> {
+        assert_eq!(input.len(), self.params.t);
+        let mut current_state = input.to_owned();
+        dispatch_width!(
+            self.params.t,
+            permute_unoptimized_with,
+            &self.params,
+            &mut current_state[..]
+        );
         current_state
     }
 
-    fn sbox(&self, input: &[FpBN256]) -> Vec<FpBN256> {
-        input.iter().map(|el| self.sbox_p(el)).collect()
+    /// Permutation recording the state after the initial linear layer and,
+    /// for every round, before and after each of its steps.
+    pub fn permutation_with_trace(&self, input: &[FpBN256]) -> Poseidon2Trace {
+        assert_eq!(input.len(), self.params.t);
+        let mut current_state = input.to_owned();
+        dispatch_width!(
+            self.params.t,
+            permute_with_trace,
+            &self.params,
+            &mut current_state[..]
+        )
     }
 
-    fn sbox_p(&self, input: &FpBN256) -> FpBN256 {
-        let mut input2 = *input;
-        input2.square_in_place();
+    /// Slow reference permutation multiplying by the full external and
+    /// internal matrices, for differential testing of the fast linear layers.
+    pub fn permutation_reference(&self, input: &[FpBN256]) -> Vec<FpBN256> {
+        let params = &self.params;
+        assert_eq!(input.len(), params.t);
 
-        match self.params.d {
-            3 => {
-                let mut out = input2;
-                out.mul_assign(input);
-                out
-            }
-            5 => {
-                let mut out = input2;
-                out.square_in_place();
-                out.mul_assign(input);
-                out
-            }
-            7 => {
-                let mut out = input2;
-                out.square_in_place();
-                out.mul_assign(&input2);
-                out.mul_assign(input);
-                out
-            }
-            _ => {
-                panic!()
+        let mat_external = params.mat_external();
+        let mat_internal = params.mat_internal();
+        let sbox = |x: &mut FpBN256| *x = x.pow(&U256Field::from_u64(params.d as u64));
+        let add_rc = |state: &mut [FpBN256], rc: &[FpBN256]| {
+            state.iter_mut().zip(rc).for_each(|(s, c)| s.add_assign(c));
+        };
+
+        // Linear layer at beginning
+        let mut current_state = Poseidon2Params::mat_vec_mul(&mat_external, input);
+
+        let p_end = params.rounds_f_beginning + params.rounds_p;
+        for r in 0..params.rounds {
+            add_rc(&mut current_state, params.round_constants(r));
+            if r >= params.rounds_f_beginning && r < p_end {
+                sbox(&mut current_state[0]);
+                current_state = Poseidon2Params::mat_vec_mul(&mat_internal, &current_state);
+            } else {
+                current_state.iter_mut().for_each(sbox);
+                current_state = Poseidon2Params::mat_vec_mul(&mat_external, &current_state);
             }
         }
+        current_state
     }
 
-    fn matmul_m4(&self, input: &mut [FpBN256]) {
-        let t = self.params.t;
-        let t4 = t / 4;
-        for i in 0..t4 {
-            let start_index = i * 4;
-            let mut t_0 = input[start_index];
-            t_0.add_assign(&input[start_index + 1]);
-            let mut t_1 = input[start_index + 2];
-            t_1.add_assign(&input[start_index + 3]);
-            let mut t_2 = input[start_index + 1];
-            t_2.double_in_place();
-            t_2.add_assign(&t_1);
-            let mut t_3 = input[start_index + 3];
-            t_3.double_in_place();
-            t_3.add_assign(&t_0);
-            let mut t_4 = t_1;
-            t_4.double_in_place();
-            t_4.double_in_place();
-            t_4.add_assign(&t_3);
-            let mut t_5 = t_0;
-            t_5.double_in_place();
-            t_5.double_in_place();
-            t_5.add_assign(&t_2);
-            let mut t_6 = t_3;
-            t_6.add_assign(&t_5);
-            let mut t_7 = t_2;
-            t_7.add_assign(&t_4);
-            input[start_index] = t_6;
-            input[start_index + 1] = t_5;
-            input[start_index + 2] = t_7;
-            input[start_index + 3] = t_4;
+    /// Inverse of [`Self::permutation`], running the rounds backwards with
+    /// the inverse linear layers and the inverse sbox `x^(1/d)`.
+    ///
+    /// Computes the inverse matrices on every call and is much slower than
+    /// the forward direction.
+    pub fn inverse_permutation(&self, input: &[FpBN256]) -> Vec<FpBN256> {
+        let params = &self.params;
+        assert_eq!(input.len(), params.t);
+
+        let mat_external_inv = utils::mat_inverse(&params.mat_external());
+        let mat_internal_inv = utils::mat_inverse(&params.mat_internal());
+        let e = params.sbox_inverse_exponent();
+        let sbox_inv = |x: &mut FpBN256| *x = x.pow(&e);
+        let sub_rc = |state: &mut [FpBN256], rc: &[FpBN256]| {
+            state.iter_mut().zip(rc).for_each(|(s, c)| s.sub_assign(c));
+        };
+
+        let mut current_state = input.to_owned();
+        let p_end = params.rounds_f_beginning + params.rounds_p;
+        for r in (p_end..params.rounds).rev() {
+            current_state = Poseidon2Params::mat_vec_mul(&mat_external_inv, &current_state);
+            current_state.iter_mut().for_each(sbox_inv);
+            sub_rc(&mut current_state, params.round_constants(r));
         }
-    }
 
-    fn matmul_external(&self, input: &mut [FpBN256]) {
-        let t = self.params.t;
-        match t {
-            2 => {
-                // Matrix circ(2, 1)
-                let mut sum = input[0];
-                sum.add_assign(&input[1]);
-                input[0].add_assign(&sum);
-                input[1].add_assign(&sum);
-            }
-            3 => {
-                // Matrix circ(2, 1, 1)
-                let mut sum = input[0];
-                sum.add_assign(&input[1]);
-                sum.add_assign(&input[2]);
-                input[0].add_assign(&sum);
-                input[1].add_assign(&sum);
-                input[2].add_assign(&sum);
-            }
-            4 => {
-                // Applying cheap 4x4 MDS matrix to each 4-element part of the state
-                self.matmul_m4(input);
-            }
-            8 | 12 | 16 | 20 | 24 => {
-                // Applying cheap 4x4 MDS matrix to each 4-element part of the state
-                self.matmul_m4(input);
-
-                // Applying second cheap matrix for t > 4
-                let t4 = t / 4;
-                let mut stored = [FpBN256::ZERO; 4];
-                for l in 0..4 {
-                    stored[l] = input[l];
-                    for j in 1..t4 {
-                        stored[l].add_assign(&input[4 * j + l]);
-                    }
-                }
-                for i in 0..input.len() {
-                    input[i].add_assign(&stored[i % 4]);
-                }
-            }
-            _ => {
-                panic!()
-            }
+        for r in (params.rounds_f_beginning..p_end).rev() {
+            current_state = Poseidon2Params::mat_vec_mul(&mat_internal_inv, &current_state);
+            sbox_inv(&mut current_state[0]);
+            current_state[0].sub_assign(&params.round_constants(r)[0]);
         }
+
+        for r in (0..params.rounds_f_beginning).rev() {
+            current_state = Poseidon2Params::mat_vec_mul(&mat_external_inv, &current_state);
+            current_state.iter_mut().for_each(sbox_inv);
+            sub_rc(&mut current_state, params.round_constants(r));
+        }
+
+        // Linear layer at beginning
+        Poseidon2Params::mat_vec_mul(&mat_external_inv, &current_state)
     }
 
-    fn matmul_internal(&self, input: &mut [FpBN256], mat_internal_diag_m_1: &[FpBN256]) {
+    /// Fixed-length hash of `N` field elements.
+    ///
+    /// As recommended in the Poseidon(2) paper, the capacity element (the last
+    /// lane) is initialized with `N * 2^64 + (o - 1)` for output length `o = 1`,
+    /// so inputs of different lengths are domain separated and no padding is
+    /// needed. The remaining `t - 1` lanes are used as rate.
+    pub fn hash<const N: usize>(&self, input: &[FpBN256; N]) -> FpBN256 {
         let t = self.params.t;
+        let rate = t - 1;
 
-        match t {
-            2 => {
-                // [2, 1]
-                // [1, 3]
-                let mut sum = input[0];
-                sum.add_assign(&input[1]);
-                input[0].add_assign(&sum);
-                input[1].double_in_place();
-                input[1].add_assign(&sum);
-            }
-            3 => {
-                // [2, 1, 1]
-                // [1, 2, 1]
-                // [1, 1, 3]
-                let mut sum = input[0];
-                sum.add_assign(&input[1]);
-                sum.add_assign(&input[2]);
-                input[0].add_assign(&sum);
-                input[1].add_assign(&sum);
-                input[2].double_in_place();
-                input[2].add_assign(&sum);
-            }
-            4 | 8 | 12 | 16 | 20 | 24 => {
-                // Compute input sum
-                let mut sum = input[0];
-                input
-                    .iter()
-                    .skip(1)
-                    .take(t - 1)
-                    .for_each(|el| sum.add_assign(el));
-                // Add sum + diag entry * element to each element
-                for i in 0..input.len() {
-                    input[i].mul_assign(&mat_internal_diag_m_1[i]);
-                    input[i].add_assign(&sum);
-                }
-            }
-            _ => {
-                panic!()
+        let mut state = vec![FpBN256::ZERO; t];
+        state[rate] = FpBN256::new(&U256Field::from_u128((N as u128) << 64));
+
+        if N == 0 {
+            return self.permutation(&state)[0];
+        }
+        for chunk in input.chunks(rate) {
+            for (s, el) in state.iter_mut().zip(chunk) {
+                s.add_in_place(el);
             }
+            state = self.permutation(&state);
         }
-    }
-
-    fn add_rc(&self, input: &[FpBN256], rc: &[FpBN256]) -> Vec<FpBN256> {
-        input
-            .iter()
-            .zip(rc.iter())
-            .map(|(a, b)| {
-                let mut r = *a;
-                r.add_assign(b);
-                r
-            })
-            .collect()
+        state[0]
     }
 }
 
 impl MerkleTreeHash for Poseidon2 {
     fn compress(&self, input: &[&FpBN256]) -> FpBN256 {
-        self.permutation(&[input[0].to_owned(), input[1].to_owned(), FpBN256::ZERO])[0]
+        self.compress_with_mode(input, CompressionMode::Truncation)
+    }
+}
+
+impl MerkleTreeHashModes for Poseidon2 {
+    fn compress_with_mode(&self, input: &[&FpBN256], mode: CompressionMode) -> FpBN256 {
+        let t = self.params.t;
+        assert!(input.len() <= t);
+        let mut state = vec![FpBN256::ZERO; t];
+        for (s, el) in state.iter_mut().zip(input) {
+            *s = **el;
+        }
+        mode.finish(&state, &self.permutation(&state))
     }
 }
 
 #[cfg(test)]
 mod poseidon2_tests_bn256 {
     use super::*;
+    use crate::poseidon2::poseidon2_trace::RoundType;
     use crate::{
         fields::{
             bn256::{FpBN256, U256Field},
             utils::from_hex,
         },
-        poseidon2::poseidon2_instance_bn256::POSEIDON2_BN256_PARAMS,
+        poseidon2::poseidon2_instance_bn256::{
+            ALL_INSTANCES, POSEIDON2_BN256_PARAMS, POSEIDON2_BN256_T4_PARAMS,
+            POSEIDON2_BN256_T16_PARAMS,
+        },
     };
 
     type Scalar = FpBN256;
 
+    #[cfg(feature = "std")]
+    use crate::fields::utils::random_scalar;
+
+    // fallback fixed scalar for no_std builds
+    #[cfg(not(feature = "std"))]
+    fn random_scalar() -> Scalar {
+        use core::sync::atomic::{AtomicU64, Ordering};
+
+        static COUNTER: AtomicU64 = AtomicU64::new(420);
+        let seed = COUNTER.fetch_add(69, Ordering::Relaxed);
+        Scalar::new(&U256Field::from_u64(seed))
+    }
+
     #[test]
     fn consistent_perm() {
-        #[cfg(feature = "std")]
-        use crate::fields::utils::random_scalar;
         #[cfg(feature = "std")]
         static TESTRUNS: usize = 50;
-
-        // fallback fixed scalar for no_std builds
-        #[cfg(not(feature = "std"))]
-        fn random_scalar() -> Scalar {
-            use core::sync::atomic::{AtomicU64, Ordering};
-
-            static COUNTER: AtomicU64 = AtomicU64::new(420);
-            let seed = COUNTER.fetch_add(69, Ordering::Relaxed);
-            Scalar::new(&U256Field::from_u64(seed))
-        }
         #[cfg(not(feature = "std"))]
         static TESTRUNS: usize = 1;
 
@@ -280,6 +329,93 @@ mod poseidon2_tests_bn256 {
         }
     }
 
+    #[test]
+    fn hash_length_separation() {
+        let poseidon2 = Poseidon2::new(&POSEIDON2_BN256_PARAMS);
+        let a = Scalar::new(&U256Field::from_u64(7));
+        let b = Scalar::new(&U256Field::from_u64(8));
+        let c = Scalar::new(&U256Field::from_u64(9));
+
+        assert_ne!(poseidon2.hash(&[a]), poseidon2.hash(&[a, Scalar::ZERO]));
+        assert_ne!(poseidon2.hash(&[]), poseidon2.hash(&[Scalar::ZERO]));
+
+        let iv2 = Scalar::new(&U256Field::from_u128(2 << 64));
+        assert_eq!(
+            poseidon2.hash(&[a, b]),
+            poseidon2.permutation(&[a, b, iv2])[0]
+        );
+
+        let iv3 = Scalar::new(&U256Field::from_u128(3 << 64));
+        let mut state = poseidon2.permutation(&[a, b, iv3]);
+        state[0].add_assign(&c);
+        assert_eq!(poseidon2.hash(&[a, b, c]), poseidon2.permutation(&state)[0]);
+    }
+
+    #[test]
+    fn equivalent_round_constants() {
+        for params in ALL_INSTANCES {
+            let poseidon2 = Poseidon2::new(params);
+            let t = poseidon2.get_t();
+            assert_eq!(params.opt_round_constants_first.len(), t);
+            assert_eq!(
+                params.opt_round_constants_partial.len(),
+                params.rounds_p - 1
+            );
+            for seed in 0..4u64 {
+                let input: Vec<Scalar> = (0..t as u64)
+                    .map(|i| Scalar::new(&U256Field::from_u64(seed * 1000 + i * i + 7)))
+                    .collect();
+                assert_eq!(
+                    poseidon2.permutation(&input),
+                    poseidon2.permutation_unoptimized(&input)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn permute_in_place() {
+        let poseidon2 = Poseidon2::new(&POSEIDON2_BN256_PARAMS);
+        let mut state: [Scalar; 3] =
+            core::array::from_fn(|i| Scalar::new(&U256Field::from_u64(i as u64 + 1)));
+        let expected = poseidon2.permutation(&state);
+        poseidon2.permute_in_place(&mut state);
+        assert_eq!(state.to_vec(), expected);
+
+        let poseidon2 = Poseidon2::new(&POSEIDON2_BN256_T16_PARAMS);
+        let mut state = vec![Scalar::ONE; 16];
+        let expected = poseidon2.permutation(&state);
+        poseidon2.permute_slice_in_place(&mut state);
+        assert_eq!(state, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn permute_in_place_wrong_width() {
+        let poseidon2 = Poseidon2::new(&POSEIDON2_BN256_PARAMS);
+        poseidon2.permute_in_place(&mut [Scalar::ZERO; 4]);
+    }
+
+    #[test]
+    fn try_permutation() {
+        let poseidon2 = Poseidon2::new(&POSEIDON2_BN256_PARAMS);
+        let input = [Scalar::ONE; 3];
+        assert_eq!(
+            poseidon2.try_permutation(&input),
+            Ok(poseidon2.permutation(&input))
+        );
+        assert_eq!(
+            poseidon2.try_permutation(&[Scalar::ONE; 4]),
+            Err(Poseidon2Error::InvalidStateLength {
+                expected: 3,
+                got: 4
+            })
+        );
+        let mut state = [Scalar::ONE; 2];
+        assert!(poseidon2.try_permute_slice_in_place(&mut state).is_err());
+        assert_eq!(state, [Scalar::ONE; 2]);
+    }
+
     #[test]
     fn kats() {
         let poseidon2 = Poseidon2::new(&POSEIDON2_BN256_PARAMS);
@@ -301,4 +437,221 @@ mod poseidon2_tests_bn256 {
             from_hex("0x1ed25194542b12eef8617361c3ba7c52e660b145994427cc86296242cf766ec8")
         );
     }
+
+    #[test]
+    fn kats_t4() {
+        // Test vector of the t=4 instance from the Barretenberg test suite
+        let poseidon2 = Poseidon2::new(&POSEIDON2_BN256_T4_PARAMS);
+        let input: Vec<Scalar> = (0..4)
+            .map(|i| Scalar::new(&U256Field::from_u64(i)))
+            .collect();
+        let perm = poseidon2.permutation(&input);
+        assert_eq!(
+            perm,
+            vec![
+                from_hex("0x01bd538c2ee014ed5141b29e9ae240bf8db3fe5b9a38629a9647cf8d76c01737"),
+                from_hex("0x239b62e7db98aa3a2a8f6a0d2fa1709e7a35959aa6c7034814d9daa90cbac662"),
+                from_hex("0x04cbb44c61d928ed06808456bf758cbf0c18d1e15a7b6dbc8245fa7515d5e3cb"),
+                from_hex("0x2e11c5cff2a22c64d01304b778d78f6998eff1ab73163a35603f54794c30847a"),
+            ]
+        );
+    }
+
+    #[test]
+    fn matches_reference() {
+        #[cfg(feature = "std")]
+        static TESTRUNS: usize = 3;
+        #[cfg(not(feature = "std"))]
+        static TESTRUNS: usize = 1;
+
+        let check = |poseidon2: Poseidon2| {
+            let t = poseidon2.get_t();
+            for _ in 0..TESTRUNS {
+                let input: Vec<Scalar> = (0..t).map(|_| random_scalar()).collect();
+                assert_eq!(
+                    poseidon2.permutation(&input),
+                    poseidon2.permutation_reference(&input),
+                    "t = {}",
+                    t
+                );
+            }
+        };
+
+        for params in ALL_INSTANCES {
+            check(Poseidon2::new(params));
+        }
+
+        // remaining widths, with a cheap invertible diagonal instead of the
+        // sampled one
+        for t in
+            (5..=crate::poseidon2::poseidon2_params::MAX_WIDTH).filter(|&t| !t.is_multiple_of(4))
+        {
+            let diag: Vec<Scalar> = (1..=t as u64)
+                .map(|i| Scalar::new(&U256Field::from_u64(i)))
+                .collect();
+            let params = Poseidon2Params::builder(t, 5, 8, 57)
+                .mat_internal_diag_m_1(&diag)
+                .build()
+                .unwrap();
+            check(Poseidon2::new(alloc::sync::Arc::new(params)));
+        }
+    }
+
+    #[test]
+    fn permute_batch() {
+        fn check<const T: usize>(poseidon2: Poseidon2, n: usize) {
+            let mut states: Vec<[Scalar; T]> = (0..n)
+                .map(|_| core::array::from_fn(|_| random_scalar()))
+                .collect();
+            let expected: Vec<Vec<Scalar>> =
+                states.iter().map(|s| poseidon2.permutation(s)).collect();
+            poseidon2.permute_batch(&mut states);
+            let got: Vec<Vec<Scalar>> = states.iter().map(|s| s.to_vec()).collect();
+            assert_eq!(got, expected);
+        }
+
+        check::<3>(Poseidon2::new(&POSEIDON2_BN256_PARAMS), 0);
+        check::<3>(Poseidon2::new(&POSEIDON2_BN256_PARAMS), 5);
+        check::<3>(Poseidon2::new(&POSEIDON2_BN256_PARAMS), 150);
+        check::<4>(Poseidon2::new(&POSEIDON2_BN256_T4_PARAMS), 100);
+    }
+
+    #[test]
+    fn permutation_with_trace() {
+        let poseidon2 = Poseidon2::new(&POSEIDON2_BN256_T4_PARAMS);
+        let params = &poseidon2.params;
+        let input: Vec<Scalar> = (0..4).map(|_| random_scalar()).collect();
+        let trace = poseidon2.permutation_with_trace(&input);
+
+        assert_eq!(trace.input, input);
+        assert_eq!(trace.output(), poseidon2.permutation(&input));
+        assert_eq!(trace.rounds.len(), params.rounds);
+        let mut state = trace.after_initial_linear_layer.clone();
+        for (r, round) in trace.rounds.iter().enumerate() {
+            let partial =
+                r >= params.rounds_f_beginning && r < params.rounds_f_beginning + params.rounds_p;
+            assert_eq!(
+                round.round_type,
+                if partial {
+                    RoundType::Partial
+                } else {
+                    RoundType::Full
+                }
+            );
+            assert_eq!(round.state_before, state);
+            for (i, el) in round.after_add_rc.iter().enumerate() {
+                let mut expected = state[i];
+                expected.add_assign(&params.round_constants(r)[i]);
+                assert_eq!(*el, expected);
+            }
+            let sboxed = if partial { 1 } else { 4 };
+            assert_eq!(round.after_sbox[sboxed..], round.after_add_rc[sboxed..]);
+            state = round.after_linear_layer.clone();
+        }
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&trace).unwrap();
+            assert!(json.contains(r#""round_type":"partial""#));
+            assert_eq!(
+                serde_json::from_str::<Poseidon2Trace>(&json).unwrap(),
+                trace
+            );
+        }
+    }
+
+    #[test]
+    fn inverse_permutation() {
+        for params in ALL_INSTANCES {
+            let poseidon2 = Poseidon2::new(params);
+            let t = poseidon2.get_t();
+            let input: Vec<Scalar> = (0..t).map(|_| random_scalar()).collect();
+            let perm = poseidon2.permutation(&input);
+            assert_eq!(poseidon2.inverse_permutation(&perm), input);
+            assert_eq!(
+                poseidon2.permutation(&poseidon2.inverse_permutation(&input)),
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn inverse_sbox_degrees() {
+        let x = Scalar::new(&U256Field::from_u64(0xdead_beef));
+        for d in [5, 7, 11, 17] {
+            let params = Poseidon2Params::builder(3, d, 8, 56).build().unwrap();
+            let e = params.sbox_inverse_exponent();
+            assert_eq!(
+                x.pow(&U256Field::from_u64(d as u64)).pow(&e),
+                x,
+                "d = {}",
+                d
+            );
+        }
+    }
+
+    #[test]
+    fn instances() {
+        for params in ALL_INSTANCES {
+            let poseidon2 = Poseidon2::new(params);
+            let t = poseidon2.get_t();
+            assert_eq!(params.round_constants.len(), params.rounds * t);
+            assert_eq!(params.mat_internal().len(), t);
+
+            let input: Vec<Scalar> = (0..t)
+                .map(|i| Scalar::new(&U256Field::from_u64(i as u64)))
+                .collect();
+            let perm = poseidon2.permutation(&input);
+            assert_eq!(perm.len(), t);
+            assert_ne!(perm, input);
+        }
+    }
+
+    #[test]
+    fn compression_modes() {
+        use crate::merkle_tree::merkle_tree_fp::MerkleTree;
+
+        // implements only the required method, as downstream hashes do
+        struct TruncationOnly(Poseidon2);
+        impl MerkleTreeHash for TruncationOnly {
+            fn compress(&self, input: &[&FpBN256]) -> FpBN256 {
+                self.0.compress(input)
+            }
+        }
+
+        for params in ALL_INSTANCES {
+            let poseidon2 = Poseidon2::new(params);
+            let t = poseidon2.get_t();
+            let (l, r) = (random_scalar(), random_scalar());
+            let mut input = vec![Scalar::ZERO; t];
+            input[0] = l;
+            input[1] = r;
+            let perm = poseidon2.permutation(&input);
+
+            let truncated = poseidon2.compress(&[&l, &r]);
+            assert_eq!(truncated, perm[0]);
+            assert_eq!(
+                poseidon2.compress_with_mode(&[&l, &r], CompressionMode::Truncation),
+                truncated
+            );
+            assert_eq!(
+                poseidon2.compress_with_mode(&[&l, &r], CompressionMode::FeedForward),
+                perm[0] + l
+            );
+            let jive = perm.iter().fold(l + r, |acc, y| acc + y);
+            assert_eq!(
+                poseidon2.compress_with_mode(&[&l, &r], CompressionMode::Jive),
+                jive
+            );
+
+            let leaves = [l, r];
+            let mut tree = MerkleTree::new(poseidon2.clone());
+            assert_eq!(tree.mode(), CompressionMode::Truncation);
+            assert_eq!(tree.accumulate(&leaves), truncated);
+            let mut tree = MerkleTree::new(TruncationOnly(poseidon2.clone()));
+            assert_eq!(tree.accumulate(&leaves), truncated);
+            let mut tree = MerkleTree::with_mode(poseidon2, CompressionMode::Jive);
+            assert_eq!(tree.accumulate(&leaves), jive);
+        }
+    }
 }
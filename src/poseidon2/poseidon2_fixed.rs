@@ -0,0 +1,470 @@
+use super::addition_chain::MAX_CHAIN_LEN;
+use super::poseidon2_params::{
+    Poseidon2Error, Poseidon2Params, Poseidon2ParamsRef, is_supported_width,
+};
+use super::poseidon2_trace::{Poseidon2Trace, RoundTrace, RoundType};
+use crate::fields::bn256::{FpBN256, ModMathInPlace, U256Field};
+
+/// Poseidon2 permutation with the state width `T` as part of the type.
+///
+/// States are `[FpBN256; T]`, so a state of the wrong width does not compile,
+/// and unsupported widths are rejected when the type is instantiated. The
+/// linear layers dispatch on `T` at compile time.
+#[derive(Clone, Debug)]
+pub struct Poseidon2Fixed<const T: usize> {
+    pub(crate) params: Poseidon2ParamsRef,
+}
+
+impl<const T: usize> Poseidon2Fixed<T> {
+    const SUPPORTED_WIDTH: () = assert!(is_supported_width(T), "unsupported Poseidon2 width");
+
+    /// Panics if `params` is an instance of a different width.
+    pub fn new(params: impl Into<Poseidon2ParamsRef>) -> Self {
+        Self::try_new(params).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_new(params: impl Into<Poseidon2ParamsRef>) -> Result<Self, Poseidon2Error> {
+        let () = Self::SUPPORTED_WIDTH;
+        let params = params.into();
+        if params.t != T {
+            return Err(Poseidon2Error::WidthMismatch {
+                expected: T,
+                got: params.t,
+            });
+        }
+        Ok(Poseidon2Fixed { params })
+    }
+
+    pub fn get_params(&self) -> &Poseidon2Params {
+        &self.params
+    }
+
+    pub fn permute(&self, state: &mut [FpBN256; T]) {
+        Self::permute_with(&self.params, state);
+    }
+
+    pub fn permutation(&self, input: &[FpBN256; T]) -> [FpBN256; T] {
+        let mut state = *input;
+        self.permute(&mut state);
+        state
+    }
+
+    /// Fixed-length hash of `N` field elements, see [`super::poseidon2::Poseidon2::hash`].
+    pub fn hash<const N: usize>(&self, input: &[FpBN256; N]) -> FpBN256 {
+        let rate = T - 1;
+
+        let mut state = [FpBN256::ZERO; T];
+        state[rate] = FpBN256::new(&U256Field::from_u128((N as u128) << 64));
+
+        if N == 0 {
+            self.permute(&mut state);
+            return state[0];
+        }
+        for chunk in input.chunks(rate) {
+            for (s, el) in state.iter_mut().zip(chunk) {
+                s.add_in_place(el);
+            }
+            self.permute(&mut state);
+        }
+        state[0]
+    }
+
+    pub(crate) fn permute_with(params: &Poseidon2Params, state: &mut [FpBN256; T]) {
+        let () = Self::SUPPORTED_WIDTH;
+        debug_assert_eq!(params.t, T);
+
+        // Linear layer at beginning
+        Self::matmul_external(state, &params.mat_external);
+
+        for r in 0..params.rounds_f_beginning {
+            Self::add_rc(state, params.round_constants(r));
+            Self::sbox(state, params);
+            Self::matmul_external(state, &params.mat_external);
+        }
+
+        // Partial rounds with equivalent round constants: one vector addition
+        // up front, then a single scalar addition per round
+        let rounds_p = params.rounds_p;
+        if rounds_p > 0 {
+            Self::add_rc(state, &params.opt_round_constants_first);
+        }
+        for r in 0..rounds_p {
+            state[0] = Self::sbox_p(&state[0], params);
+            if r + 1 < rounds_p {
+                state[0].add_in_place(&params.opt_round_constants_partial[r]);
+            }
+            Self::matmul_internal(state, &params.mat_internal_diag_m_1);
+        }
+
+        let p_end = params.rounds_f_beginning + rounds_p;
+        for r in p_end..params.rounds {
+            Self::add_rc(state, params.round_constants(r));
+            Self::sbox(state, params);
+            Self::matmul_external(state, &params.mat_external);
+        }
+    }
+
+    /// Permutation as written in the paper, adding a round-constant vector in
+    /// every round.
+    pub(crate) fn permute_unoptimized_with(params: &Poseidon2Params, state: &mut [FpBN256; T]) {
+        let () = Self::SUPPORTED_WIDTH;
+        debug_assert_eq!(params.t, T);
+
+        // Linear layer at beginning
+        Self::matmul_external(state, &params.mat_external);
+
+        for r in 0..params.rounds_f_beginning {
+            Self::add_rc(state, params.round_constants(r));
+            Self::sbox(state, params);
+            Self::matmul_external(state, &params.mat_external);
+        }
+
+        let p_end = params.rounds_f_beginning + params.rounds_p;
+        for r in params.rounds_f_beginning..p_end {
+            state[0].add_in_place(&params.round_constants(r)[0]);
+            state[0] = Self::sbox_p(&state[0], params);
+            Self::matmul_internal(state, &params.mat_internal_diag_m_1);
+        }
+
+        for r in p_end..params.rounds {
+            Self::add_rc(state, params.round_constants(r));
+            Self::sbox(state, params);
+            Self::matmul_external(state, &params.mat_external);
+        }
+    }
+
+    /// [`Self::permute_unoptimized_with`], recording every intermediate state.
+    pub(crate) fn permute_with_trace(
+        params: &Poseidon2Params,
+        state: &mut [FpBN256; T],
+    ) -> Poseidon2Trace {
+        let () = Self::SUPPORTED_WIDTH;
+        debug_assert_eq!(params.t, T);
+
+        let input = state.to_vec();
+        // Linear layer at beginning
+        Self::matmul_external(state, &params.mat_external);
+        let after_initial_linear_layer = state.to_vec();
+
+        let p_end = params.rounds_f_beginning + params.rounds_p;
+        let rounds = (0..params.rounds)
+            .map(|r| {
+                let state_before = state.to_vec();
+                let round_type = if r >= params.rounds_f_beginning && r < p_end {
+                    RoundType::Partial
+                } else {
+                    RoundType::Full
+                };
+
+                Self::add_rc(state, params.round_constants(r));
+                let after_add_rc = state.to_vec();
+                match round_type {
+                    RoundType::Full => Self::sbox(state, params),
+                    RoundType::Partial => state[0] = Self::sbox_p(&state[0], params),
+                }
+                let after_sbox = state.to_vec();
+                match round_type {
+                    RoundType::Full => Self::matmul_external(state, &params.mat_external),
+                    RoundType::Partial => {
+                        Self::matmul_internal(state, &params.mat_internal_diag_m_1)
+                    }
+                }
+
+                RoundTrace {
+                    round_type,
+                    state_before,
+                    after_add_rc,
+                    after_sbox,
+                    after_linear_layer: state.to_vec(),
+                }
+            })
+            .collect();
+
+        Poseidon2Trace {
+            input,
+            after_initial_linear_layer,
+            rounds,
+        }
+    }
+
+    fn sbox(input: &mut [FpBN256; T], params: &Poseidon2Params) {
+        input
+            .iter_mut()
+            .for_each(|el| *el = Self::sbox_p(el, params));
+    }
+
+    pub(crate) fn sbox_p(input: &FpBN256, params: &Poseidon2Params) -> FpBN256 {
+        let mut input2 = *input;
+        input2.square_in_place();
+
+        match params.d {
+            5 => {
+                let mut out = input2;
+                out.square_in_place();
+                out.mul_in_place(input);
+                out
+            }
+            7 => {
+                let mut out = input2;
+                out.square_in_place();
+                out.mul_in_place(&input2);
+                out.mul_in_place(input);
+                out
+            }
+            11 => {
+                let mut out = input2;
+                out.square_in_place();
+                out.square_in_place();
+                out.mul_in_place(&input2);
+                out.mul_in_place(input);
+                out
+            }
+            _ => {
+                // General exponent, evaluated along the precomputed addition chain
+                let mut powers = [FpBN256::ZERO; MAX_CHAIN_LEN + 1];
+                powers[0] = *input;
+                for (k, &(i, j)) in params.sbox_chain.iter().enumerate() {
+                    let mut out = powers[i];
+                    out.mul_in_place(&powers[j]);
+                    powers[k + 1] = out;
+                }
+                powers[params.sbox_chain.len()]
+            }
+        }
+    }
+
+    fn matmul_m4(input: &mut [FpBN256; T]) {
+        for chunk in input.chunks_exact_mut(4) {
+            let mut t_0 = chunk[0];
+            t_0.add_in_place(&chunk[1]);
+            let mut t_1 = chunk[2];
+            t_1.add_in_place(&chunk[3]);
+            let mut t_2 = chunk[1];
+            t_2.double_in_place();
+            t_2.add_in_place(&t_1);
+            let mut t_3 = chunk[3];
+            t_3.double_in_place();
+            t_3.add_in_place(&t_0);
+            let mut t_4 = t_1;
+            t_4.double_in_place();
+            t_4.double_in_place();
+            t_4.add_in_place(&t_3);
+            let mut t_5 = t_0;
+            t_5.double_in_place();
+            t_5.double_in_place();
+            t_5.add_in_place(&t_2);
+            let mut t_6 = t_3;
+            t_6.add_in_place(&t_5);
+            let mut t_7 = t_2;
+            t_7.add_in_place(&t_4);
+            chunk[0] = t_6;
+            chunk[1] = t_5;
+            chunk[2] = t_7;
+            chunk[3] = t_4;
+        }
+    }
+
+    pub(crate) fn matmul_external(input: &mut [FpBN256; T], mat_external: &[FpBN256]) {
+        match T {
+            2 => {
+                // Matrix circ(2, 1)
+                let mut sum = input[0];
+                sum.add_in_place(&input[1]);
+                input[0].add_in_place(&sum);
+                input[1].add_in_place(&sum);
+            }
+            3 => {
+                // Matrix circ(2, 1, 1)
+                let mut sum = input[0];
+                sum.add_in_place(&input[1]);
+                sum.add_in_place(&input[2]);
+                input[0].add_in_place(&sum);
+                input[1].add_in_place(&sum);
+                input[2].add_in_place(&sum);
+            }
+            4 => {
+                // Applying cheap 4x4 MDS matrix to each 4-element part of the state
+                Self::matmul_m4(input);
+            }
+            _ if T.is_multiple_of(4) => {
+                // Applying cheap 4x4 MDS matrix to each 4-element part of the state
+                Self::matmul_m4(input);
+
+                // Applying second cheap matrix for t > 4
+                let t4 = T / 4;
+                let mut stored = [FpBN256::ZERO; 4];
+                for l in 0..4 {
+                    stored[l] = input[l];
+                    for j in 1..t4 {
+                        stored[l].add_in_place(&input[4 * j + l]);
+                    }
+                }
+                for i in 0..T {
+                    input[i].add_in_place(&stored[i % 4]);
+                }
+            }
+            _ => {
+                // No structured matrix for this width, dense multiplication
+                let state = *input;
+                for (out, row) in input.iter_mut().zip(mat_external.chunks_exact(T)) {
+                    *out = FpBN256::ZERO;
+                    for (m, s) in row.iter().zip(&state) {
+                        let mut tmp = *m;
+                        tmp.mul_in_place(s);
+                        out.add_in_place(&tmp);
+                    }
+                }
+            }
+        }
+    }
+
+    pub(crate) fn matmul_internal(input: &mut [FpBN256; T], mat_internal_diag_m_1: &[FpBN256]) {
+        match T {
+            2 => {
+                // [2, 1]
+                // [1, 3]
+                let mut sum = input[0];
+                sum.add_in_place(&input[1]);
+                input[0].add_in_place(&sum);
+                input[1].double_in_place();
+                input[1].add_in_place(&sum);
+            }
+            3 => {
+                // [2, 1, 1]
+                // [1, 2, 1]
+                // [1, 1, 3]
+                let mut sum = input[0];
+                sum.add_in_place(&input[1]);
+                sum.add_in_place(&input[2]);
+                input[0].add_in_place(&sum);
+                input[1].add_in_place(&sum);
+                input[2].double_in_place();
+                input[2].add_in_place(&sum);
+            }
+            _ => {
+                // Compute input sum
+                let mut sum = input[0];
+                input.iter().skip(1).for_each(|el| {
+                    sum.add_in_place(el);
+                });
+                // Add sum + diag entry * element to each element
+                for i in 0..T {
+                    input[i].mul_in_place(&mat_internal_diag_m_1[i]);
+                    input[i].add_in_place(&sum);
+                }
+            }
+        }
+    }
+
+    fn add_rc(input: &mut [FpBN256; T], rc: &[FpBN256]) {
+        input.iter_mut().zip(rc.iter()).for_each(|(a, b)| {
+            a.add_in_place(b);
+        });
+    }
+}
+
+#[cfg(test)]
+mod poseidon2_fixed_tests {
+    use super::*;
+    use crate::poseidon2::{
+        poseidon2::Poseidon2,
+        poseidon2_instance_bn256::{ALL_INSTANCES, POSEIDON2_BN256_PARAMS},
+    };
+
+    type Scalar = FpBN256;
+
+    fn check_width<const T: usize>(params: impl Into<Poseidon2ParamsRef>) {
+        let params = params.into();
+        let poseidon2 = Poseidon2Fixed::<T>::new(params.clone());
+        let input: [Scalar; T] =
+            core::array::from_fn(|i| Scalar::new(&U256Field::from_u64(i as u64 + 3)));
+
+        // agrees with the dynamic permutation
+        let dynamic = Poseidon2::new(params.clone());
+        assert_eq!(
+            poseidon2.permutation(&input).to_vec(),
+            dynamic.permutation(&input)
+        );
+        assert_eq!(poseidon2.hash(&input), dynamic.hash(&input));
+
+        assert_eq!(
+            dynamic.permutation(&input),
+            dynamic.permutation_unoptimized(&input)
+        );
+
+        // the linear layers agree with the explicit matrices
+        let mut state = input;
+        Poseidon2Fixed::<T>::matmul_external(&mut state, &params.mat_external);
+        assert_eq!(
+            state.to_vec(),
+            Poseidon2Params::mat_vec_mul(&Poseidon2Params::default_mat_external(T), &input)
+        );
+        let mut state = input;
+        Poseidon2Fixed::<T>::matmul_internal(&mut state, &params.mat_internal_diag_m_1);
+        assert_eq!(
+            state.to_vec(),
+            Poseidon2Params::mat_vec_mul(&params.mat_internal(), &input)
+        );
+    }
+
+    #[test]
+    fn matches_dynamic() {
+        for params in ALL_INSTANCES {
+            match params.t {
+                2 => check_width::<2>(params),
+                3 => check_width::<3>(params),
+                4 => check_width::<4>(params),
+                8 => check_width::<8>(params),
+                12 => check_width::<12>(params),
+                16 => check_width::<16>(params),
+                20 => check_width::<20>(params),
+                24 => check_width::<24>(params),
+                t => unreachable!("no built-in instance of width {}", t),
+            }
+        }
+    }
+
+    #[test]
+    fn dense_external_layer() {
+        use alloc::sync::Arc;
+
+        let params = |t| Arc::new(Poseidon2Params::builder(t, 5, 8, 57).build().unwrap());
+        check_width::<5>(params(5));
+        check_width::<6>(params(6));
+        check_width::<7>(params(7));
+    }
+
+    #[test]
+    fn sbox_degrees() {
+        let input = Scalar::new(&U256Field::from_u64(0x1234_5678_9abc));
+        for d in [5, 7, 11, 17, 19, 101, 1021] {
+            let params = Poseidon2Params::builder(3, d, 8, 56)
+                .skip_security_check()
+                .build()
+                .unwrap();
+            assert_eq!(
+                Poseidon2Fixed::<3>::sbox_p(&input, &params),
+                input.pow(&U256Field::from_u64(d as u64)),
+                "d = {}",
+                d
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn params_width_mismatch() {
+        Poseidon2Fixed::<4>::new(&POSEIDON2_BN256_PARAMS);
+    }
+
+    #[test]
+    fn try_new_width_mismatch() {
+        assert_eq!(
+            Poseidon2Fixed::<4>::try_new(&POSEIDON2_BN256_PARAMS).map(|_| ()),
+            Err(Poseidon2Error::WidthMismatch {
+                expected: 4,
+                got: 3
+            })
+        );
+    }
+}
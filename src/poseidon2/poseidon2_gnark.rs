@@ -0,0 +1,251 @@
+//! The BN254 Poseidon2 of gnark-crypto (`ecc/bn254/fr/poseidon2`), with its
+//! default parameters t = 2, R_F = 6, R_P = 50, d = 5, and the
+//! Merkle-Damgård hasher built on its compression function.
+//!
+//! This follows gnark-crypto's source, but has not been checked against
+//! its output yet, see the tests.
+//!
+//! The linear layers are the ones of this crate for t = 2. The round
+//! constants are derived from the seed
+//! `Poseidon2-BN254[t=2,rF=6,rP=50,d=5]`: `h_0 = Keccak256(seed)`, then
+//! `h_{i+1} = Keccak256(h_i)`, and `h_1, h_2, ...` reduced mod p are the
+//! constants in round order, one per lane in full rounds and one (for lane
+//! 0) in partial rounds.
+
+use super::poseidon2_fixed::Poseidon2Fixed;
+use super::poseidon2_instance_bn256::POSEIDON2_BN256_GNARK_PARAMS;
+use crate::fields::bn256::{FpBN256, ModMathInPlace, ModulusBN254, U256Field};
+use alloc::{format, vec, vec::Vec};
+use core::fmt;
+use crypto_bigint::{Encoding, modular::constant_mod::ResidueParams};
+use sha3::{Digest, Keccak256};
+
+/// Size of a field element, and of the hasher's blocks, in bytes.
+pub const BLOCK_SIZE: usize = 32;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GnarkError {
+    /// A block is not the canonical encoding of a field element, i.e. not
+    /// below p.
+    NonCanonicalBlock,
+    /// A write of at least one block is not a whole number of blocks.
+    InvalidLength(usize),
+}
+
+impl fmt::Display for GnarkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GnarkError::NonCanonicalBlock => write!(f, "block is not a canonical field element"),
+            GnarkError::InvalidLength(len) => write!(
+                f,
+                "input of {} bytes is not a multiple of {} bytes",
+                len, BLOCK_SIZE
+            ),
+        }
+    }
+}
+
+/// Round constants of gnark-crypto for the given instance, with zeros in the
+/// lanes of the partial rounds without a constant.
+pub fn gnark_round_constants(
+    t: usize,
+    rounds_f: usize,
+    rounds_p: usize,
+    d: usize,
+) -> Vec<Vec<FpBN256>> {
+    let seed = format!(
+        "Poseidon2-BN254[t={},rF={},rP={},d={}]",
+        t, rounds_f, rounds_p, d
+    );
+    let mut rnd: [u8; 32] = Keccak256::digest(seed.as_bytes()).into();
+    let mut next = || {
+        rnd = Keccak256::digest(rnd).into();
+        FpBN256::new(&U256Field::from_be_bytes(rnd))
+    };
+
+    let r = rounds_f / 2;
+    (0..rounds_f + rounds_p)
+        .map(|round| {
+            if round >= r && round < r + rounds_p {
+                let mut row = vec![FpBN256::ZERO; t];
+                row[0] = next();
+                row
+            } else {
+                (0..t).map(|_| next()).collect()
+            }
+        })
+        .collect()
+}
+
+/// gnark-crypto's `Permutation.Compress`: the second lane of the permutation
+/// of `(left, right)`, plus `right`.
+pub fn compress(left: &FpBN256, right: &FpBN256) -> FpBN256 {
+    let perm = Poseidon2Fixed::<2>::new(&POSEIDON2_BN256_GNARK_PARAMS);
+    compress_with(&perm, left, right)
+}
+
+fn compress_with(perm: &Poseidon2Fixed<2>, left: &FpBN256, right: &FpBN256) -> FpBN256 {
+    let mut out = perm.permutation(&[*left, *right])[1];
+    out.add_in_place(right);
+    out
+}
+
+/// gnark-crypto's `poseidon2.NewMerkleDamgardHasher()`: starting from a zero
+/// state, every 32-byte block `b` of the input updates the state to
+/// `compress(state, b)`. There is no length padding, see [`Self::write`] for
+/// partial blocks. The digest is the state as 32 big-endian bytes.
+#[derive(Clone, Debug)]
+pub struct GnarkMerkleDamgardHasher {
+    perm: Poseidon2Fixed<2>,
+    state: FpBN256,
+}
+
+impl Default for GnarkMerkleDamgardHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GnarkMerkleDamgardHasher {
+    pub fn new() -> Self {
+        GnarkMerkleDamgardHasher {
+            perm: Poseidon2Fixed::new(&POSEIDON2_BN256_GNARK_PARAMS),
+            state: FpBN256::ZERO,
+        }
+    }
+
+    /// Absorbs `data` block by block. As in gnark-crypto's `Write`, a write
+    /// shorter than one block is left-padded with zeros to a single block,
+    /// while a longer write has to be a whole number of blocks. A write is
+    /// absorbed as a whole or not at all: on an error, including a
+    /// non-canonical block after valid ones, the state is left unchanged.
+    pub fn write(&mut self, data: &[u8]) -> Result<(), GnarkError> {
+        if data.is_empty() {
+            return Ok(());
+        }
+        if data.len() < BLOCK_SIZE {
+            let mut block = [0u8; BLOCK_SIZE];
+            block[BLOCK_SIZE - data.len()..].copy_from_slice(data);
+            self.state = self.absorb_block(self.state, &block)?;
+            return Ok(());
+        }
+        if !data.len().is_multiple_of(BLOCK_SIZE) {
+            return Err(GnarkError::InvalidLength(data.len()));
+        }
+        let mut state = self.state;
+        for block in data.chunks_exact(BLOCK_SIZE) {
+            state = self.absorb_block(state, block.try_into().unwrap())?;
+        }
+        self.state = state;
+        Ok(())
+    }
+
+    fn absorb_block(
+        &self,
+        state: FpBN256,
+        block: &[u8; BLOCK_SIZE],
+    ) -> Result<FpBN256, GnarkError> {
+        let int = U256Field::from_be_bytes(*block);
+        if int >= ModulusBN254::MODULUS {
+            return Err(GnarkError::NonCanonicalBlock);
+        }
+        Ok(compress_with(&self.perm, &state, &FpBN256::new(&int)))
+    }
+
+    /// Absorbs field elements, one block each.
+    pub fn write_elements(&mut self, elements: &[FpBN256]) {
+        for el in elements {
+            self.state = compress_with(&self.perm, &self.state, el);
+        }
+    }
+
+    pub fn sum(&self) -> [u8; BLOCK_SIZE] {
+        self.state.retrieve().to_be_bytes()
+    }
+
+    pub fn reset(&mut self) {
+        self.state = FpBN256::ZERO;
+    }
+}
+
+#[cfg(test)]
+mod poseidon2_gnark_tests {
+    use super::*;
+    use crate::fields::utils::from_hex;
+
+    #[test]
+    fn round_constants() {
+        let rc = gnark_round_constants(2, 6, 50, 5);
+        assert_eq!(rc.len(), 56);
+        assert!(rc[3..53].iter().all(|row| row[1] == FpBN256::ZERO));
+        assert_eq!(rc[0][0], RC_FIRST);
+        assert_eq!(rc[55][1], RC_LAST);
+    }
+
+    #[test]
+    fn hasher() {
+        let mut hasher = GnarkMerkleDamgardHasher::new();
+        assert_eq!(hasher.sum(), [0; 32]);
+
+        let one = FpBN256::ONE;
+        let two = FpBN256::ONE.add(&FpBN256::ONE);
+        let mut bytes = [0u8; 64];
+        bytes[31] = 1;
+        bytes[63] = 2;
+        hasher.write(&bytes).unwrap();
+        let expected = compress(&compress(&FpBN256::ZERO, &one), &two);
+        assert_eq!(hasher.sum(), expected.retrieve().to_be_bytes());
+        assert_eq!(expected, DIGEST_1_2);
+
+        // a write shorter than a block is left-padded
+        hasher.reset();
+        hasher.write(&[0x01, 0x02]).unwrap();
+        let mut padded = GnarkMerkleDamgardHasher::new();
+        padded.write_elements(&[FpBN256::new(&U256Field::from_u64(0x0102))]);
+        assert_eq!(hasher.sum(), padded.sum());
+        assert_eq!(hasher.sum(), DIGEST_SHORT.retrieve().to_be_bytes());
+
+        // a longer write with a partial last block is rejected
+        hasher.reset();
+        let mut ragged = bytes[..33].to_vec();
+        ragged[32] = 7;
+        assert_eq!(hasher.write(&ragged), Err(GnarkError::InvalidLength(33)));
+        assert_eq!(hasher.sum(), [0; 32]);
+
+        // a non-canonical block discards the whole write
+        hasher.reset();
+        hasher.write(&[0x01, 0x02]).unwrap();
+        let before = hasher.sum();
+        let modulus = ModulusBN254::MODULUS.to_be_bytes();
+        assert_eq!(
+            hasher.write(&[bytes.as_slice(), &modulus].concat()),
+            Err(GnarkError::NonCanonicalBlock)
+        );
+        assert_eq!(hasher.sum(), before);
+    }
+
+    #[test]
+    fn permutation() {
+        let perm = Poseidon2Fixed::<2>::new(&POSEIDON2_BN256_GNARK_PARAMS);
+        assert_eq!(perm.permutation(&[FpBN256::ZERO, FpBN256::ONE]), PERM_0_1);
+    }
+
+    // The vectors below were computed with an independent Python
+    // implementation of gnark-crypto's construction (Keccak-256 from
+    // OpenSSL), not with gnark-crypto itself, so they cannot catch a
+    // misreading of it. `testdata/gnark_vectors/main.go` prints the same
+    // values with gnark-crypto; they have not been compared with its output
+    // yet.
+    const RC_FIRST: FpBN256 =
+        from_hex("0x1da4d6adfb0d0b494584f763db50a81908580a5f5e295e168b9b8d31770fac4f");
+    const RC_LAST: FpBN256 =
+        from_hex("0x08a50897c06aafe6ea414fb1bceca2267cd4a39486729fbc6d5d1bb7a172ebd2");
+    const PERM_0_1: [FpBN256; 2] = [
+        from_hex("0x1cea81d812c4552760b21d75d1fd4c11e43e3f60c67632e7e38f656236378e5d"),
+        from_hex("0x1ae0efd28c01163c0a58440757ef2339affb17836ad3b1fedacabeaab56ca0e1"),
+    ];
+    const DIGEST_1_2: FpBN256 =
+        from_hex("0x09d2e656ec5144af0711a5528a3af6ebc908d9050b3455edf3b5d0218820875c");
+    const DIGEST_SHORT: FpBN256 =
+        from_hex("0x0cd46a41300c8776427960500f15abb698da52a7d053127f7751c0c12053f536");
+}
@@ -2,363 +2,11431 @@ use super::poseidon2_params::Poseidon2Params;
 use crate::fields::bn256::FpBN256;
 use crate::fields::utils::from_hex;
 
-use lazy_static::lazy_static;
-use std::sync::Arc;
+use alloc::borrow::Cow;
 
 type Scalar = FpBN256;
 
-lazy_static! {
-    pub static ref MAT_DIAG3_M_1: Vec<Scalar> = vec![
-        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
-        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
-        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
-    ];
-    pub static ref MAT_INTERNAL3: Vec<Vec<Scalar>> = vec![
-        vec![
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
-        ],
-        vec![
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
-        ],
-        vec![
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
-        ],
-    ];
-    pub static ref RC3: Vec<Vec<Scalar>> = vec![
-        vec![
-            from_hex("0x1d066a255517b7fd8bddd3a93f7804ef7f8fcde48bb4c37a59a09a1a97052816"),
-            from_hex("0x29daefb55f6f2dc6ac3f089cebcc6120b7c6fef31367b68eb7238547d32c1610"),
-            from_hex("0x1f2cb1624a78ee001ecbd88ad959d7012572d76f08ec5c4f9e8b7ad7b0b4e1d1"),
-        ],
-        vec![
-            from_hex("0x0aad2e79f15735f2bd77c0ed3d14aa27b11f092a53bbc6e1db0672ded84f31e5"),
-            from_hex("0x2252624f8617738cd6f661dd4094375f37028a98f1dece66091ccf1595b43f28"),
-            from_hex("0x1a24913a928b38485a65a84a291da1ff91c20626524b2b87d49f4f2c9018d735"),
-        ],
-        vec![
-            from_hex("0x22fc468f1759b74d7bfc427b5f11ebb10a41515ddff497b14fd6dae1508fc47a"),
-            from_hex("0x1059ca787f1f89ed9cd026e9c9ca107ae61956ff0b4121d5efd65515617f6e4d"),
-            from_hex("0x02be9473358461d8f61f3536d877de982123011f0bf6f155a45cbbfae8b981ce"),
-        ],
-        vec![
-            from_hex("0x0ec96c8e32962d462778a749c82ed623aba9b669ac5b8736a1ff3a441a5084a4"),
-            from_hex("0x292f906e073677405442d9553c45fa3f5a47a7cdb8c99f9648fb2e4d814df57e"),
-            from_hex("0x274982444157b86726c11b9a0f5e39a5cc611160a394ea460c63f0b2ffe5657e"),
-        ],
-        vec![
-            from_hex("0x1a1d063e54b1e764b63e1855bff015b8cedd192f47308731499573f23597d4b5"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x26abc66f3fdf8e68839d10956259063708235dccc1aa3793b91b002c5b257c37"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x0c7c64a9d887385381a578cfed5aed370754427aabca92a70b3c2b12ff4d7be8"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x1cf5998769e9fab79e17f0b6d08b2d1eba2ebac30dc386b0edd383831354b495"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x0f5e3a8566be31b7564ca60461e9e08b19828764a9669bc17aba0b97e66b0109"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x18df6a9d19ea90d895e60e4db0794a01f359a53a180b7d4b42bf3d7a531c976e"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x04f7bf2c5c0538ac6e4b782c3c6e601ad0ea1d3a3b9d25ef4e324055fa3123dc"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x29c76ce22255206e3c40058523748531e770c0584aa2328ce55d54628b89ebe6"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x198d425a45b78e85c053659ab4347f5d65b1b8e9c6108dbe00e0e945dbc5ff15"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x25ee27ab6296cd5e6af3cc79c598a1daa7ff7f6878b3c49d49d3a9a90c3fdf74"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x138ea8e0af41a1e024561001c0b6eb1505845d7d0c55b1b2c0f88687a96d1381"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x306197fb3fab671ef6e7c2cba2eefd0e42851b5b9811f2ca4013370a01d95687"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x1a0c7d52dc32a4432b66f0b4894d4f1a21db7565e5b4250486419eaf00e8f620"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x2b46b418de80915f3ff86a8e5c8bdfccebfbe5f55163cd6caa52997da2c54a9f"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x12d3e0dc0085873701f8b777b9673af9613a1af5db48e05bfb46e312b5829f64"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x263390cf74dc3a8870f5002ed21d089ffb2bf768230f648dba338a5cb19b3a1f"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x0a14f33a5fe668a60ac884b4ca607ad0f8abb5af40f96f1d7d543db52b003dcd"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x28ead9c586513eab1a5e86509d68b2da27be3a4f01171a1dd847df829bc683b9"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x1c6ab1c328c3c6430972031f1bdb2ac9888f0ea1abe71cffea16cda6e1a7416c"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x1fc7e71bc0b819792b2500239f7f8de04f6decd608cb98a932346015c5b42c94"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x03e107eb3a42b2ece380e0d860298f17c0c1e197c952650ee6dd85b93a0ddaa8"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x2d354a251f381a4669c0d52bf88b772c46452ca57c08697f454505f6941d78cd"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x094af88ab05d94baf687ef14bc566d1c522551d61606eda3d14b4606826f794b"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x19705b783bf3d2dc19bcaeabf02f8ca5e1ab5b6f2e3195a9d52b2d249d1396f7"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x09bf4acc3a8bce3f1fcc33fee54fc5b28723b16b7d740a3e60cef6852271200e"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x1803f8200db6013c50f83c0c8fab62843413732f301f7058543a073f3f3b5e4e"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x0f80afb5046244de30595b160b8d1f38bf6fb02d4454c0add41f7fef2faf3e5c"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x126ee1f8504f15c3d77f0088c1cfc964abcfcf643f4a6fea7dc3f98219529d78"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x23c203d10cfcc60f69bfb3d919552ca10ffb4ee63175ddf8ef86f991d7d0a591"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x2a2ae15d8b143709ec0d09705fa3a6303dec1ee4eec2cf747c5a339f7744fb94"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x07b60dee586ed6ef47e5c381ab6343ecc3d3b3006cb461bbb6b5d89081970b2b"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x27316b559be3edfd885d95c494c1ae3d8a98a320baa7d152132cfe583c9311bd"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x1d5c49ba157c32b8d8937cb2d3f84311ef834cc2a743ed662f5f9af0c0342e76"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x2f8b124e78163b2f332774e0b850b5ec09c01bf6979938f67c24bd5940968488"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x1e6843a5457416b6dc5b7aa09a9ce21b1d4cba6554e51d84665f75260113b3d5"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x11cdf00a35f650c55fca25c9929c8ad9a68daf9ac6a189ab1f5bc79f21641d4b"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x21632de3d3bbc5e42ef36e588158d6d4608b2815c77355b7e82b5b9b7eb560bc"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x0de625758452efbd97b27025fbd245e0255ae48ef2a329e449d7b5c51c18498a"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x2ad253c053e75213e2febfd4d976cc01dd9e1e1c6f0fb6b09b09546ba0838098"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x1d6b169ed63872dc6ec7681ec39b3be93dd49cdd13c813b7d35702e38d60b077"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x1660b740a143664bb9127c4941b67fed0be3ea70a24d5568c3a54e706cfef7fe"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x0065a92d1de81f34114f4ca2deef76e0ceacdddb12cf879096a29f10376ccbfe"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x1f11f065202535987367f823da7d672c353ebe2ccbc4869bcf30d50a5871040d"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x26596f5c5dd5a5d1b437ce7b14a2c3dd3bd1d1a39b6759ba110852d17df0693e"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x16f49bc727e45a2f7bf3056efcf8b6d38539c4163a5f1e706743db15af91860f"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x1abe1deb45b3e3119954175efb331bf4568feaf7ea8b3dc5e1a4e7438dd39e5f"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x0e426ccab66984d1d8993a74ca548b779f5db92aaec5f102020d34aea15fba59"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x0e7c30c2e2e8957f4933bd1942053f1f0071684b902d534fa841924303f6a6c6"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x0812a017ca92cf0a1622708fc7edff1d6166ded6e3528ead4c76e1f31d3fc69d"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x21a5ade3df2bc1b5bba949d1db96040068afe5026edd7a9c2e276b47cf010d54"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x01f3035463816c84ad711bf1a058c6c6bd101945f50e5afe72b1a5233f8749ce"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x0b115572f038c0e2028c2aafc2d06a5e8bf2f9398dbd0fdf4dcaa82b0f0c1c8b"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x1c38ec0b99b62fd4f0ef255543f50d2e27fc24db42bc910a3460613b6ef59e2f"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x1c89c6d9666272e8425c3ff1f4ac737b2f5d314606a297d4b1d0b254d880c53e"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x03326e643580356bf6d44008ae4c042a21ad4880097a5eb38b71e2311bb88f8f"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x268076b0054fb73f67cee9ea0e51e3ad50f27a6434b5dceb5bdde2299910a4c9"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-            from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
-        ],
-        vec![
-            from_hex("0x1acd63c67fbc9ab1626ed93491bda32e5da18ea9d8e4f10178d04aa6f8747ad0"),
-            from_hex("0x19f8a5d670e8ab66c4e3144be58ef6901bf93375e2323ec3ca8c86cd2a28b5a5"),
-            from_hex("0x1c0dc443519ad7a86efa40d2df10a011068193ea51f6c92ae1cfbb5f7b9b6893"),
-        ],
-        vec![
-            from_hex("0x14b39e7aa4068dbe50fe7190e421dc19fbeab33cb4f6a2c4180e4c3224987d3d"),
-            from_hex("0x1d449b71bd826ec58f28c63ea6c561b7b820fc519f01f021afb1e35e28b0795e"),
-            from_hex("0x1ea2c9a89baaddbb60fa97fe60fe9d8e89de141689d1252276524dc0a9e987fc"),
-        ],
-        vec![
-            from_hex("0x0478d66d43535a8cb57e9c1c3d6a2bd7591f9a46a0e9c058134d5cefdb3c7ff1"),
-            from_hex("0x19272db71eece6a6f608f3b2717f9cd2662e26ad86c400b21cde5e4a7b00bebe"),
-            from_hex("0x14226537335cab33c749c746f09208abb2dd1bd66a87ef75039be846af134166"),
-        ],
-        vec![
-            from_hex("0x01fd6af15956294f9dfe38c0d976a088b21c21e4a1c2e823f912f44961f9a9ce"),
-            from_hex("0x18e5abedd626ec307bca190b8b2cab1aaee2e62ed229ba5a5ad8518d4e5f2a57"),
-            from_hex("0x0fc1bbceba0590f5abbdffa6d3b35e3297c021a3a409926d0e2d54dc1c84fda6"),
-        ],
-    ];
-    pub static ref POSEIDON2_BN256_PARAMS: Arc<Poseidon2Params> = Arc::new(Poseidon2Params::new(
-        3,
-        5,
-        8,
-        56,
-        &MAT_DIAG3_M_1,
-        &MAT_INTERNAL3,
-        &RC3
-    ));
+pub const MAT_DIAG3_M_1: [Scalar; 3] = [
+    from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+];
+pub const MAT_INTERNAL3: [[Scalar; 3]; 3] = [
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+    ],
+];
+pub const MAT_EXTERNAL3: [[Scalar; 3]; 3] = [
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+    ],
+];
+pub const RC3: [[Scalar; 3]; 64] = [
+    [
+        from_hex("0x1d066a255517b7fd8bddd3a93f7804ef7f8fcde48bb4c37a59a09a1a97052816"),
+        from_hex("0x29daefb55f6f2dc6ac3f089cebcc6120b7c6fef31367b68eb7238547d32c1610"),
+        from_hex("0x1f2cb1624a78ee001ecbd88ad959d7012572d76f08ec5c4f9e8b7ad7b0b4e1d1"),
+    ],
+    [
+        from_hex("0x0aad2e79f15735f2bd77c0ed3d14aa27b11f092a53bbc6e1db0672ded84f31e5"),
+        from_hex("0x2252624f8617738cd6f661dd4094375f37028a98f1dece66091ccf1595b43f28"),
+        from_hex("0x1a24913a928b38485a65a84a291da1ff91c20626524b2b87d49f4f2c9018d735"),
+    ],
+    [
+        from_hex("0x22fc468f1759b74d7bfc427b5f11ebb10a41515ddff497b14fd6dae1508fc47a"),
+        from_hex("0x1059ca787f1f89ed9cd026e9c9ca107ae61956ff0b4121d5efd65515617f6e4d"),
+        from_hex("0x02be9473358461d8f61f3536d877de982123011f0bf6f155a45cbbfae8b981ce"),
+    ],
+    [
+        from_hex("0x0ec96c8e32962d462778a749c82ed623aba9b669ac5b8736a1ff3a441a5084a4"),
+        from_hex("0x292f906e073677405442d9553c45fa3f5a47a7cdb8c99f9648fb2e4d814df57e"),
+        from_hex("0x274982444157b86726c11b9a0f5e39a5cc611160a394ea460c63f0b2ffe5657e"),
+    ],
+    [
+        from_hex("0x1a1d063e54b1e764b63e1855bff015b8cedd192f47308731499573f23597d4b5"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x26abc66f3fdf8e68839d10956259063708235dccc1aa3793b91b002c5b257c37"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0c7c64a9d887385381a578cfed5aed370754427aabca92a70b3c2b12ff4d7be8"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1cf5998769e9fab79e17f0b6d08b2d1eba2ebac30dc386b0edd383831354b495"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0f5e3a8566be31b7564ca60461e9e08b19828764a9669bc17aba0b97e66b0109"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x18df6a9d19ea90d895e60e4db0794a01f359a53a180b7d4b42bf3d7a531c976e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x04f7bf2c5c0538ac6e4b782c3c6e601ad0ea1d3a3b9d25ef4e324055fa3123dc"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x29c76ce22255206e3c40058523748531e770c0584aa2328ce55d54628b89ebe6"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x198d425a45b78e85c053659ab4347f5d65b1b8e9c6108dbe00e0e945dbc5ff15"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x25ee27ab6296cd5e6af3cc79c598a1daa7ff7f6878b3c49d49d3a9a90c3fdf74"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x138ea8e0af41a1e024561001c0b6eb1505845d7d0c55b1b2c0f88687a96d1381"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x306197fb3fab671ef6e7c2cba2eefd0e42851b5b9811f2ca4013370a01d95687"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1a0c7d52dc32a4432b66f0b4894d4f1a21db7565e5b4250486419eaf00e8f620"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2b46b418de80915f3ff86a8e5c8bdfccebfbe5f55163cd6caa52997da2c54a9f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x12d3e0dc0085873701f8b777b9673af9613a1af5db48e05bfb46e312b5829f64"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x263390cf74dc3a8870f5002ed21d089ffb2bf768230f648dba338a5cb19b3a1f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0a14f33a5fe668a60ac884b4ca607ad0f8abb5af40f96f1d7d543db52b003dcd"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x28ead9c586513eab1a5e86509d68b2da27be3a4f01171a1dd847df829bc683b9"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1c6ab1c328c3c6430972031f1bdb2ac9888f0ea1abe71cffea16cda6e1a7416c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1fc7e71bc0b819792b2500239f7f8de04f6decd608cb98a932346015c5b42c94"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x03e107eb3a42b2ece380e0d860298f17c0c1e197c952650ee6dd85b93a0ddaa8"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2d354a251f381a4669c0d52bf88b772c46452ca57c08697f454505f6941d78cd"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x094af88ab05d94baf687ef14bc566d1c522551d61606eda3d14b4606826f794b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x19705b783bf3d2dc19bcaeabf02f8ca5e1ab5b6f2e3195a9d52b2d249d1396f7"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x09bf4acc3a8bce3f1fcc33fee54fc5b28723b16b7d740a3e60cef6852271200e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1803f8200db6013c50f83c0c8fab62843413732f301f7058543a073f3f3b5e4e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0f80afb5046244de30595b160b8d1f38bf6fb02d4454c0add41f7fef2faf3e5c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x126ee1f8504f15c3d77f0088c1cfc964abcfcf643f4a6fea7dc3f98219529d78"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x23c203d10cfcc60f69bfb3d919552ca10ffb4ee63175ddf8ef86f991d7d0a591"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2a2ae15d8b143709ec0d09705fa3a6303dec1ee4eec2cf747c5a339f7744fb94"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x07b60dee586ed6ef47e5c381ab6343ecc3d3b3006cb461bbb6b5d89081970b2b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x27316b559be3edfd885d95c494c1ae3d8a98a320baa7d152132cfe583c9311bd"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1d5c49ba157c32b8d8937cb2d3f84311ef834cc2a743ed662f5f9af0c0342e76"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2f8b124e78163b2f332774e0b850b5ec09c01bf6979938f67c24bd5940968488"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1e6843a5457416b6dc5b7aa09a9ce21b1d4cba6554e51d84665f75260113b3d5"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x11cdf00a35f650c55fca25c9929c8ad9a68daf9ac6a189ab1f5bc79f21641d4b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x21632de3d3bbc5e42ef36e588158d6d4608b2815c77355b7e82b5b9b7eb560bc"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0de625758452efbd97b27025fbd245e0255ae48ef2a329e449d7b5c51c18498a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2ad253c053e75213e2febfd4d976cc01dd9e1e1c6f0fb6b09b09546ba0838098"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1d6b169ed63872dc6ec7681ec39b3be93dd49cdd13c813b7d35702e38d60b077"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1660b740a143664bb9127c4941b67fed0be3ea70a24d5568c3a54e706cfef7fe"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0065a92d1de81f34114f4ca2deef76e0ceacdddb12cf879096a29f10376ccbfe"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1f11f065202535987367f823da7d672c353ebe2ccbc4869bcf30d50a5871040d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x26596f5c5dd5a5d1b437ce7b14a2c3dd3bd1d1a39b6759ba110852d17df0693e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x16f49bc727e45a2f7bf3056efcf8b6d38539c4163a5f1e706743db15af91860f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1abe1deb45b3e3119954175efb331bf4568feaf7ea8b3dc5e1a4e7438dd39e5f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0e426ccab66984d1d8993a74ca548b779f5db92aaec5f102020d34aea15fba59"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0e7c30c2e2e8957f4933bd1942053f1f0071684b902d534fa841924303f6a6c6"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0812a017ca92cf0a1622708fc7edff1d6166ded6e3528ead4c76e1f31d3fc69d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x21a5ade3df2bc1b5bba949d1db96040068afe5026edd7a9c2e276b47cf010d54"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x01f3035463816c84ad711bf1a058c6c6bd101945f50e5afe72b1a5233f8749ce"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0b115572f038c0e2028c2aafc2d06a5e8bf2f9398dbd0fdf4dcaa82b0f0c1c8b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1c38ec0b99b62fd4f0ef255543f50d2e27fc24db42bc910a3460613b6ef59e2f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1c89c6d9666272e8425c3ff1f4ac737b2f5d314606a297d4b1d0b254d880c53e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x03326e643580356bf6d44008ae4c042a21ad4880097a5eb38b71e2311bb88f8f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x268076b0054fb73f67cee9ea0e51e3ad50f27a6434b5dceb5bdde2299910a4c9"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1acd63c67fbc9ab1626ed93491bda32e5da18ea9d8e4f10178d04aa6f8747ad0"),
+        from_hex("0x19f8a5d670e8ab66c4e3144be58ef6901bf93375e2323ec3ca8c86cd2a28b5a5"),
+        from_hex("0x1c0dc443519ad7a86efa40d2df10a011068193ea51f6c92ae1cfbb5f7b9b6893"),
+    ],
+    [
+        from_hex("0x14b39e7aa4068dbe50fe7190e421dc19fbeab33cb4f6a2c4180e4c3224987d3d"),
+        from_hex("0x1d449b71bd826ec58f28c63ea6c561b7b820fc519f01f021afb1e35e28b0795e"),
+        from_hex("0x1ea2c9a89baaddbb60fa97fe60fe9d8e89de141689d1252276524dc0a9e987fc"),
+    ],
+    [
+        from_hex("0x0478d66d43535a8cb57e9c1c3d6a2bd7591f9a46a0e9c058134d5cefdb3c7ff1"),
+        from_hex("0x19272db71eece6a6f608f3b2717f9cd2662e26ad86c400b21cde5e4a7b00bebe"),
+        from_hex("0x14226537335cab33c749c746f09208abb2dd1bd66a87ef75039be846af134166"),
+    ],
+    [
+        from_hex("0x01fd6af15956294f9dfe38c0d976a088b21c21e4a1c2e823f912f44961f9a9ce"),
+        from_hex("0x18e5abedd626ec307bca190b8b2cab1aaee2e62ed229ba5a5ad8518d4e5f2a57"),
+        from_hex("0x0fc1bbceba0590f5abbdffa6d3b35e3297c021a3a409926d0e2d54dc1c84fda6"),
+    ],
+];
+// Equivalent partial round constants of RC3, as computed by
+// `Poseidon2Params::equivalent_round_constants`.
+pub const OPT_RC3_FIRST: [Scalar; 3] = [
+    from_hex("0x1a1d063e54b1e764b63e1855bff015b8cedd192f47308731499573f23597d4b5"),
+    from_hex("0x14adb47d132c2bd169899f03b2e4d5b3e9e730c6ba01bba37f884fd32ae1ba50"),
+    from_hex("0x218d5513a0a7d39021abbe8657f89d32fddcd59382b09bbda00207a9aee95667"),
+];
+pub const OPT_RC3_PARTIAL: [Scalar; 55] = [
+    from_hex("0x289cace2273767ad34841f3c2d3f220538639401bc3560aa90aa49ebb0ad35c1"),
+    from_hex("0x0caf37e88a8f2c1f539982bd11a9e2b9e94dd1c3ea78696c4149254980d443f0"),
+    from_hex("0x0cbbeaf548e56a4446b6b445f5cc15135311d6dc91946b4cd65863b800392ead"),
+    from_hex("0x156ecfb2e7ba61ae9299f47993db63a21189fc8a6b2a9891bfa62aa66f213fc1"),
+    from_hex("0x0efd359862dee179bcdf34449db124aaf276f8c1ff7acbdc6bafa7c0ed93683a"),
+    from_hex("0x0c0e353d2fb47eb0c2d8767e4c96b6b5fc881b7389c1e92406e722c60eb5dbb7"),
+    from_hex("0x12052df76f7c3214bf26d768bcc30d7f431b530a7b855e27b4e2ed6d2f6518f0"),
+    from_hex("0x2f6b09540c70511a208c9bc5028a4a2f638101503cdd511849ab69aaec9cee33"),
+    from_hex("0x10c37005703638024b5aff2da92623baaecc07e720df6f2da01d6cc4021ba145"),
+    from_hex("0x00e6fab457be88686fe290a09459077f206e1e3aa65989f7d27009777fcf1c37"),
+    from_hex("0x274192f026ffcdf9bff220cf9fcb1492e97eb3e03e2903b7b177975267d7658e"),
+    from_hex("0x163e51841c2acf3f6afe59dc44cd2e20f097694d9157f2e904e161db1b6e86f7"),
+    from_hex("0x1d0be079501a1be8a8e8371b5248134a58c84186526ca7c5cc183f6e5603cebc"),
+    from_hex("0x04adcc15d8528d19fab74d05a4e95f73d05b099e2d6c181142c525bf3e26df04"),
+    from_hex("0x2daac5a18f82298c277a78bd7614b3090b5da09dc7b888929758bbc8658271a2"),
+    from_hex("0x1a1d7110939eec09afb55685bb89089c7f4943f6798159fdcd477c7dfbc4560b"),
+    from_hex("0x0b2fe02adf2a7ab2e51f087df226bf965b632b0323e5445505c89554b22f831e"),
+    from_hex("0x0b71d1965f508d26d80c1ef5fa57c66c63273ea48c4952c6040759dd8672d13c"),
+    from_hex("0x1d91e61dc9d270d521fd83516cb86a0e6e54d37f5003e8c546833eb3e64d3611"),
+    from_hex("0x175c6b0e19bcbe91f8fc53132c26e9342549cfbdd7c38e2f07361f7bb5899d61"),
+    from_hex("0x21ea8b6923225cb61ca473cb4d3191cc729b24dfa9fa4f39c126d1c7e746c17c"),
+    from_hex("0x1bd0035420089015cb3b29b53db68de1df82861bb78f5fd54dd3abb669ca1067"),
+    from_hex("0x1676a972667bc2e5e1213f8bb507142b03c28bb16ad7c1aab9f9d6aa906d66a9"),
+    from_hex("0x1d9b3de3bbe9cf5689908fcbaf6878a299d1f68861cec12a640d2b810610a716"),
+    from_hex("0x2aa5fdc9b2237f327352ebc6258ce6392e47eb8ce97802fb8e6bd54980f78d75"),
+    from_hex("0x270d4512a9234a11091051388c277a3bce214303b30a48efe5055308be81f095"),
+    from_hex("0x0d3d9ad703f0fccbf1c39b7e17a3a3e77bf0aeb44b485025fecf35e0f22cf1d3"),
+    from_hex("0x179e1ac0cada09112af7996d3c0b0169a7c37a3ed344db57b1166ce6b1bce247"),
+    from_hex("0x19e669238b749242e61689eaf273a2fd91ccd631df8b296fea4a10afd6bda445"),
+    from_hex("0x20f74b3cc96cca69bc513d4f5e5625437f30f29334b2337e5a959b88170a27aa"),
+    from_hex("0x19f8bf18572e8095ddbe5df5f2024a75107e7734b68a1e7d844fb098990d4199"),
+    from_hex("0x1543cd4d4039ddf19f9bdd61236e6fe15c0baa7125ed06998c7cfc3300358261"),
+    from_hex("0x2a82f58bdbd3277d476225c47cd1a1cebf096e72951705f7da70dde540601e62"),
+    from_hex("0x10f9658dd9896b3142529b650833f9355ed75426a782620e2d6ef3dc505933f1"),
+    from_hex("0x133d85ecdf84c647d0a633a001035dd62957820a98056d59432dd4b27f8d7dd3"),
+    from_hex("0x298e74ff24b1a3abcd349708fb44287d1e759c8420534265f160e21bdb8f6a37"),
+    from_hex("0x127c28e75b000b9be82e646b1115e62702a407b6eac5b12045661356648f6ca3"),
+    from_hex("0x16d1f04dcca502159a4b7f50d035877836d62710c2605d4e440bf98fd0923915"),
+    from_hex("0x11609814b96ec374f000d05d3e3d1f55cc90a818205ba3b67fdc0cf64df736b3"),
+    from_hex("0x22560bb6a45067b8a30e9d45f7b94b6687becffc8e770a6a6656bd6e44339754"),
+    from_hex("0x2dee8f213a6501a35ad34a0237c178f78efd3851f3fc9ba78884656efc97d10a"),
+    from_hex("0x0d0eeac9cff557a640ed467d9c6a49433000432af99ef6a8ea4c39bdddd0a286"),
+    from_hex("0x0355ce6730f3a45d2fc8c56889373c2275bcac9039dff316b41901f0379cd6f2"),
+    from_hex("0x094ee2c6c6de93613026cf7e70093c2b0952e0d50da55d096f42665540236901"),
+    from_hex("0x1d060a29b8a91cb33a6247a31724f468d4c756705a2663e01c0dbbef98bfbad9"),
+    from_hex("0x20c931875a15bb02ab8af2fdbc8a6f994e9ed9f05e12037976008fe4b78d9c44"),
+    from_hex("0x2bd0ce84c91c6de18c18b536fb96c38f07ddf5badd68e19e56fd3a9820b4708a"),
+    from_hex("0x0f97a465fdffc6a6913b2e5a11b26db2a21a2d72f0d1db5381c98432a888b377"),
+    from_hex("0x0e74f51136525b434c8e8bfa378130e5eb20004f5a802961b911227fbff4fb0b"),
+    from_hex("0x251390f1a0efcdaedf7307ee421f4b8c91ddaff23040e6af2ae6a1b37555ec2b"),
+    from_hex("0x254f53d1cf504c3e6dbaecb2373648358af3ffcd8e7b0f2cd3c470c3d3b4b917"),
+    from_hex("0x22fba3ec67a3762ba8b527b181fbb2345028ee17718f51e9d14d30ef5cd4ea05"),
+    from_hex("0x04ecfc7bc50541fdcc25328cbeec1afaffff58c14f3dd3d21a683e5b82d3bec9"),
+    from_hex("0x130d0c8277666dcc5ebccb0a95de5b724a53eb9d7acf6860c2e7da7324662e37"),
+    from_hex("0x149692b675e8b5275463c1b1ae95ba930f81363d38d520011359a308943075b4"),
+];
+
+/// Poseidon2 over BN254 with t = 3, d = 5, R_F = 8, R_P = 56. All tables are
+/// compile-time constants, so no initialization happens at runtime.
+pub static POSEIDON2_BN256_PARAMS: Poseidon2Params = Poseidon2Params {
+    t: 3,
+    d: 5,
+    sbox_chain: Cow::Borrowed(&[(0, 0), (1, 1), (2, 0)]),
+    rounds_f_beginning: 4,
+    rounds_p: 56,
+    rounds_f_end: 4,
+    rounds: 64,
+    mat_external: Cow::Borrowed(MAT_EXTERNAL3.as_flattened()),
+    mat_internal_diag_m_1: Cow::Borrowed(&MAT_DIAG3_M_1),
+    round_constants: Cow::Borrowed(RC3.as_flattened()),
+    opt_round_constants_first: Cow::Borrowed(&OPT_RC3_FIRST),
+    opt_round_constants_partial: Cow::Borrowed(&OPT_RC3_PARTIAL),
+};
+
+// Instances for the remaining widths with fast linear layers, with
+// R_F = 8 and R_P chosen for 128-bit security. Round constants are derived
+// with the Grain LFSR, internal diagonals for t >= 4 are the output of
+// `internal_matrix::generate_mat_internal_diag_m_1` for the same instance.
+// The gnark-crypto instance shares the linear layers of t = 2, its round
+// constants are the output of `poseidon2_gnark::gnark_round_constants`.
+// As for t = 3, all tables are evaluated at compile time and checked against
+// the runtime construction in the tests; tables too large to be copied
+// around as constants are `static`s.
+pub const MAT_DIAG2_M_1: [Scalar; 2] = [
+    from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+];
+pub const MAT_INTERNAL2: [[Scalar; 2]; 2] = [
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+    ],
+];
+pub const MAT_EXTERNAL2: [[Scalar; 2]; 2] = [
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+    ],
+];
+pub const RC2: [[Scalar; 2]; 64] = [
+    [
+        from_hex("0x09c46e9ec68e9bd4fe1faaba294cba38a71aa177534cdd1b6c7dc0dbd0abd7a7"),
+        from_hex("0x0c0356530896eec42a97ed937f3135cfc5142b3ae405b8343c1d83ffa604cb81"),
+    ],
+    [
+        from_hex("0x1e28a1d935698ad1142e51182bb54cf4a00ea5aabd6268bd317ea977cc154a30"),
+        from_hex("0x27af2d831a9d2748080965db30e298e40e5757c3e008db964cf9e2b12b91251f"),
+    ],
+    [
+        from_hex("0x1e6f11ce60fc8f513a6a3cfe16ae175a41291462f214cd0879aaf43545b74e03"),
+        from_hex("0x2a67384d3bbd5e438541819cb681f0be04462ed14c3613d8f719206268d142d3"),
+    ],
+    [
+        from_hex("0x0b66fdf356093a611609f8e12fbfecf0b985e381f025188936408f5d5c9f45d0"),
+        from_hex("0x012ee3ec1e78d470830c61093c2ade370b26c83cc5cebeeddaa6852dbdb09e21"),
+    ],
+    [
+        from_hex("0x0252ba5f6760bfbdfd88f67f8175e3fd6cd1c431b099b6bb2d108e7b445bb1b9"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x179474cceca5ff676c6bec3cef54296354391a8935ff71d6ef5aeaad7ca932f1"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2c24261379a51bfa9228ff4a503fd4ed9c1f974a264969b37e1a2589bbed2b91"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1cc1d7b62692e63eac2f288bd0695b43c2f63f5001fc0fc553e66c0551801b05"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x255059301aada98bb2ed55f852979e9600784dbf17fbacd05d9eff5fd9c91b56"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x28437be3ac1cb2e479e1f5c0eccd32b3aea24234970a8193b11c29ce7e59efd9"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x28216a442f2e1f711ca4fa6b53766eb118548da8fb4f78d4338762c37f5f2043"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2c1f47cd17fa5adf1f39f4e7056dd03feee1efce03094581131f2377323482c9"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x07abad02b7a5ebc48632bcc9356ceb7dd9dafca276638a63646b8566a621afc9"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0230264601ffdf29275b33ffaab51dfe9429f90880a69cd137da0c4d15f96c3c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1bc973054e51d905a0f168656497ca40a864414557ee289e717e5d66899aa0a9"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2e1c22f964435008206c3157e86341edd249aff5c2d8421f2a6b22288f0a67fc"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1224f38df67c5378121c1d5f461bbc509e8ea1598e46c9f7a70452bc2bba86b8"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x02e4e69d8ba59e519280b4bd9ed0068fd7bfe8cd9dfeda1969d2989186cde20e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1f1eccc34aaba0137f5df81fc04ff3ee4f19ee364e653f076d47e9735d98018e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1672ad3d709a353974266c3039a9a7311424448032cd1819eacb8a4d4284f582"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x283e3fdc2c6e420c56f44af5192b4ae9cda6961f284d24991d2ed602df8c8fc7"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1c2a3d120c550ecfd0db0957170fa013683751f8fdff59d6614fbd69ff394bcc"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x216f84877aac6172f7897a7323456efe143a9a43773ea6f296cb6b8177653fbd"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2c0d272becf2a75764ba7e8e3e28d12bceaa47ea61ca59a411a1f51552f94788"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x16e34299865c0e28484ee7a74c454e9f170a5480abe0508fcb4a6c3d89546f43"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x175ceba599e96f5b375a232a6fb9cc71772047765802290f48cd939755488fc5"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0c7594440dc48c16fead9e1758b028066aa410bfbc354f54d8c5ffbb44a1ee32"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1a3c29bc39f21bb5c466db7d7eb6fd8f760e20013ccf912c92479882d919fd8d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0ccfdd906f3426e5c0986ea049b253400855d349074f5a6695c8eeabcd22e68f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x14f6bc81d9f186f62bdb475ce6c9411866a7a8a3fd065b3ce0e699b67dd9e796"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0962b82789fb3d129702ca70b2f6c5aacc099810c9c495c888edeb7386b97052"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1a880af7074d18b3bf20c79de25127bc13284ab01ef02575afef0c8f6a31a86d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x10cba18419a6a332cd5e77f0211c154b20af2924fc20ff3f4c3012bb7ae9311b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x057e62a9a8f89b3ebdc76ba63a9eaca8fa27b7319cae3406756a2849f302f10d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x287c971de91dc0abd44adf5384b4988cb961303bbf65cff5afa0413b44280cee"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x21df3388af1687bbb3bca9da0cca908f1e562bc46d4aba4e6f7f7960e306891d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1be5c887d25bce703e25cc974d0934cd789df8f70b498fd83eff8b560e1682b3"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x268da36f76e568fb68117175cea2cd0dd2cb5d42fda5acea48d59c2706a0d5c1"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0e17ab091f6eae50c609beaf5510ececc5d8bb74135ebd05bd06460cc26a5ed6"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x04d727e728ffa0a67aee535ab074a43091ef62d8cf83d270040f5caa1f62af40"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0ddbd7bf9c29341581b549762bc022ed33702ac10f1bfd862b15417d7e39ca6e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2790eb3351621752768162e82989c6c234f5b0d1d3af9b588a29c49c8789654b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1e457c601a63b73e4471950193d8a570395f3d9ab8b2fd0984b764206142f9e9"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x21ae64301dca9625638d6ab2bbe7135ffa90ecd0c43ff91fc4c686fc46e091b0"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0379f63c8ce3468d4da293166f494928854be9e3432e09555858534eed8d350b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x002d56420359d0266a744a080809e054ca0e4921a46686ac8c9f58a324c35049"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x123158e5965b5d9b1d68b3cd32e10bbeda8d62459e21f4090fc2c5af963515a6"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0be29fc40847a941661d14bbf6cbe0420fbb2b6f52836d4e60c80eb49cad9ec1"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1ac96991dec2bb0557716142015a453c36db9d859cad5f9a233802f24fdf4c1a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1596443f763dbcc25f4964fc61d23b3e5e12c9fa97f18a9251ca3355bcb0627e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x12e0bcd3654bdfa76b2861d4ec3aeae0f1857d9f17e715aed6d049eae3ba3212"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0fc92b4f1bbea82b9ea73d4af9af2a50ceabac7f37154b1904e6c76c7cf964ba"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1f9c0b1610446442d6f2e592a8013f40b14f7c7722236f4f9c7e965233872762"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0ebd74244ae72675f8cde06157a782f4050d914da38b4c058d159f643dbbf4d3"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2cb7f0ed39e16e9f69a9fafd4ab951c03b0671e97346ee397a839839dccfc6d1"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1a9d6e2ecff022cc5605443ee41bab20ce761d0514ce526690c72bca7352d9bf"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2a115439607f335a5ea83c3bc44a9331d0c13326a9a7ba3087da182d648ec72f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x23f9b6529b5d040d15b8fa7aee3e3410e738b56305cd44f29535c115c5a4c060"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x05872c16db0f72a2249ac6ba484bb9c3a3ce97c16d58b68b260eb939f0e6e8a7"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1300bdee08bb7824ca20fb80118075f40219b6151d55b5c52b624a7cdeddf6a7"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x19b9b63d2f108e17e63817863a8f6c288d7ad29916d98cb1072e4e7b7d52b376"),
+        from_hex("0x015bee1357e3c015b5bda237668522f613d1c88726b5ec4224a20128481b4f7f"),
+    ],
+    [
+        from_hex("0x2953736e94bb6b9f1b9707a4f1615e4efe1e1ce4bab218cbea92c785b128ffd1"),
+        from_hex("0x0b069353ba091618862f806180c0385f851b98d372b45f544ce7266ed6608dfc"),
+    ],
+    [
+        from_hex("0x304f74d461ccc13115e4e0bcfb93817e55aeb7eb9306b64e4f588ac97d81f429"),
+        from_hex("0x15bbf146ce9bca09e8a33f5e77dfe4f5aad2a164a4617a4cb8ee5415cde913fc"),
+    ],
+    [
+        from_hex("0x0ab4dfe0c2742cde44901031487964ed9b8f4b850405c10ca9ff23859572c8c6"),
+        from_hex("0x0e32db320a044e3197f45f7649a19675ef5eedfea546dea9251de39f9639779a"),
+    ],
+];
+pub const OPT_RC2_FIRST: [Scalar; 2] = [
+    from_hex("0x0252ba5f6760bfbdfd88f67f8175e3fd6cd1c431b099b6bb2d108e7b445bb1b9"),
+    from_hex("0x1162bab48a98e02e415ed847c85e366997f6856ffdf2ef5cdf378bfd654319fd"),
+];
+pub const OPT_RC2_PARTIAL: [Scalar; 55] = [
+    from_hex("0x0318dd0c31068f9c958689fa937af97cde214a8c9c06413d0811af580bb30c7a"),
+    from_hex("0x2ad5dae7b59b961354933bf2b35674772341467f41ee9dc13012deb0b0386891"),
+    from_hex("0x1f0f2ec7b23df042f2220859ef6c6538bb54f8d2af87bb6fc30a2fa3cf695193"),
+    from_hex("0x04c70f872fe3b3e57c34bd54c5bb6b1607eb4dadc61da2c654ad5f2d892bb112"),
+    from_hex("0x004d0635165becf34338f61b2998635e7e82e2d253b28cc9c1c1fe93476c8999"),
+    from_hex("0x2102807d9f1c440094065c69f0c66842fecbf70022ec82deda515f7a1cb8005c"),
+    from_hex("0x2c63c5ba52fea84756b569db5f7546bdcff00ce9dfdcb4af5e0fec47a1d791e6"),
+    from_hex("0x003c0aaca626721c34b7b1f7ffb595dc663ece9c6c9230153ccce69fac5d0075"),
+    from_hex("0x2690f8c16e18cb5e067d017485fe7cc6ab1d6e6017e4e799e12df42fb0b2af96"),
+    from_hex("0x22707850e59ca9f191300144bef66b87656ee2997023433c32180e58f595e4f0"),
+    from_hex("0x2b46ea858a15bb42ad9bdc6479f55239d609b86e390253efc6432e0ee713ff58"),
+    from_hex("0x30198f9ebe0f85d68a716ba6f75e48d42af115cfb164d3a48389694680ffa5be"),
+    from_hex("0x2e169e93ce0d80634fe175bacf28c6844130d09f3b57dea33f5861cad44e454d"),
+    from_hex("0x0575d4c7a403131f5ed8e4fe3412838122655ebf9e406b8a8c901e689f5adaf0"),
+    from_hex("0x1a9605dfc384fa77a074c41a800c86d3119c921629292aed74c4289313419bc5"),
+    from_hex("0x0485dc4e74eaa331561427bdf4b5235dcc70693e352068e60e6e8b694c22dd0c"),
+    from_hex("0x259cdb2f399a2c8f51a9e0288bcdbca13b08ab55fdf3251e8ec8ea95d6a0f6db"),
+    from_hex("0x141c3c2bd9a669be565499e5cb0c4697d92c163abfc6b8f2da826e84656f174f"),
+    from_hex("0x1625e3385e96c9db16e28858e5caf29467eb7422dd7800c548e5ed9373fa7e6e"),
+    from_hex("0x18eec5d13cd3d4c9a26e2db7c821782f6d6c54dc29c95c07a362133962268869"),
+    from_hex("0x0f7c593e2ae366546922058a7907f092167584fc55cb497a69fb5cb4da05f604"),
+    from_hex("0x22186efe7bd56eea8f4ccb017415e6f4cee15dc96d705a0e2a140508bb73067c"),
+    from_hex("0x07145647ec1d60c435f6938d9a47ff9663f684ebdf1a89e08759e8e07239a9b0"),
+    from_hex("0x2124d6549029fbf678eaa4ac69fc049df531ee38cfec1cb305bbce56ae7a9f2d"),
+    from_hex("0x19bb5b48cd61dee0eb0353b68c4dd6d9e39c02aa01a0fd32da39751e4f6b27e4"),
+    from_hex("0x2593a586ff88014a5540cb755e101a51850f55bb6d6b3b0814acb3f3cd21c082"),
+    from_hex("0x148a996561b743b93db5c8398d9ca89de7de3b26bbc71cc8586f769e03d70cff"),
+    from_hex("0x2c26670666c2a5efbe6ca7f65fdca0c4cb60ac8f61f6c7ac9d3919db9fc3bc68"),
+    from_hex("0x2789722bcdb75780ce95601c59cb6ff2a04ae4ff4f551dc28d99c31ce08d05e1"),
+    from_hex("0x0e0fb818370111e6614f330a47e6306d25c87140c78f833501ed60cf06f02b94"),
+    from_hex("0x0f8e2a8d73fa1fb0eadc2864b3d688b3eba85ccec7502583d711c7a7149f9e1c"),
+    from_hex("0x02078158847d6ad8dc9e4c8054ed1751dbf2a2d9d3fd0e4011ed02bb66107ef4"),
+    from_hex("0x1ee536bc2c542a1d36ac0acf4995a9fc828b3897f99692e2369df97add57e437"),
+    from_hex("0x1a746933cc31a2f84f98d02d2c8a6bd8ed175f517d2f4a58f9cda87f009fa953"),
+    from_hex("0x1738f32df33d1231ff3e713c073a5024dcd31265c6cd4f45350f6fb575a0f0ae"),
+    from_hex("0x095541850f7c36644e40fd129dc18b2cbeacc6d1479018b6d0383771461b3809"),
+    from_hex("0x1653d5c6e529c583bc533bf15d888aed2a02895286160f2c0d018aad35b20f17"),
+    from_hex("0x23cd1b0dc09ff681a36eeedb3633b0144a5f61052ae5f3b6b591e0a063908abe"),
+    from_hex("0x24bd93f0d846d0648789881fc6ef1d8bcd0e1868fb75aa01bf4be41492752cd5"),
+    from_hex("0x2b1156b03672cb09e454fdce8f1fd161cb6fcebeefdd37ff634019607a9ab012"),
+    from_hex("0x05c27598f413b9848afa3779bfe3dd27c944fa0851a46032322783a4809090bf"),
+    from_hex("0x173ad10e2858465f4a77758aed1b5e43b55db4852492207f325526fe555efc43"),
+    from_hex("0x24ba552d07777826411c22857fd8ca2c0573c59c177c19d79694c04ec274a58f"),
+    from_hex("0x26fe4ba0946eebd67d03221e4c97f34be9429359938e5be13501d69b330ed5d0"),
+    from_hex("0x13b472637ede7347acb230306cdc3da57bb280cc581f2b03b4b3b82f762e559f"),
+    from_hex("0x021feec9cddca4ce7a3b6f0db2c80a820ccaddb2477a6e3c928929a186485b59"),
+    from_hex("0x0915769c692df0b3bc53c4639a33fb4ab122b832e73ed0e65082d7b3b09a4ba1"),
+    from_hex("0x27081454d60fdfb6307824ad1fbc80907196fc203d6b898ab21d36118fcf39ad"),
+    from_hex("0x09248dd243030bf47ad6adcefda7cba4ec551bfc3e63e654d533ffcec9194f86"),
+    from_hex("0x171b2ccdd41d9b81f2b8df721c04e18e64c3a4f6e04c44e87ea35c8b888d3afb"),
+    from_hex("0x1c30e4ef9408c050c779fb1c08c4bb029eb47431cb18286aef2793b53bd2d61a"),
+    from_hex("0x033272ba893ba61ae8ace5cf20664411173ea83fb7a155f87cc346182ada3439"),
+    from_hex("0x0b454a187536746bfae0a72023303fc59ccb1adf4937d2aefa9de117271db7f9"),
+    from_hex("0x195e8c3f8ab5f6751d39d32b98428693a741e742debd732069a3634a21c69fb3"),
+    from_hex("0x286fd43a25f4a82f1b10c0ba583448971961c56b5aa2b09a42c28cd6e2852d98"),
+];
+
+/// Poseidon2 over BN254 with t = 2, d = 5, R_F = 8, R_P = 56.
+pub static POSEIDON2_BN256_T2_PARAMS: Poseidon2Params = Poseidon2Params {
+    t: 2,
+    d: 5,
+    sbox_chain: Cow::Borrowed(&[(0, 0), (1, 1), (2, 0)]),
+    rounds_f_beginning: 4,
+    rounds_p: 56,
+    rounds_f_end: 4,
+    rounds: 64,
+    mat_external: Cow::Borrowed(MAT_EXTERNAL2.as_flattened()),
+    mat_internal_diag_m_1: Cow::Borrowed(&MAT_DIAG2_M_1),
+    round_constants: Cow::Borrowed(RC2.as_flattened()),
+    opt_round_constants_first: Cow::Borrowed(&OPT_RC2_FIRST),
+    opt_round_constants_partial: Cow::Borrowed(&OPT_RC2_PARTIAL),
+};
+
+pub const RC_GNARK2: [[Scalar; 2]; 56] = [
+    [
+        from_hex("0x1da4d6adfb0d0b494584f763db50a81908580a5f5e295e168b9b8d31770fac4f"),
+        from_hex("0x0946129a2e33b4e819707a56a3b3790eab80d0a0c7a0c63451fab2a59acc074c"),
+    ],
+    [
+        from_hex("0x2a39b9d5376afd35580abd6952986570867b87303b07140268794dad4b8f82ea"),
+        from_hex("0x27605717d1245c20c546b3c7014e5fa3e4a70e66ecd6de5d32448eea5e3069b2"),
+    ],
+    [
+        from_hex("0x24c896cb2594e17b973653193a470ed7e771ebb09a228d638e50809c03773632"),
+        from_hex("0x0911096c45dd9cda0d61d783957003db6d8701c7869a919ad8042e1b7d597a49"),
+    ],
+    [
+        from_hex("0x26ff6166f1e4b99e27eee6d352a8ce26f1daba01aad28021f11f38a70603afdc"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x008e2faedcf76d08ad6591ff90e50fea79bcb5e18cfb7d954d5b89437bf64b7e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x19c9da2379b598ace3ad4d1872470830f6184a3cec71eeb632f98820eb353d78"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0f7c4eb15d8b0b62a8f6a090ec9610a2ab3dfcdb57e2539aa459a40583dfe96b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x18b99417dc26b5e079750eba282362d1d46900b47dd5ff809171588b08ac3983"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1ee044081160b3eee2d4493feab82141c73f1c054b76320a8848af08a8d91a26"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x29bb95c8763efd3e0e87f5df12ee8a150455b6d7a14780d19122220366c258dc"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x22c23eec9cb13ff8a3ee9a363d740653215e8991f7f9ec12067b4705e9a5c9fb"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x23589e033a31a667680c8b18926c3be09115c7644c4f905cc7deefc1690b42dc"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x304e99b887f2e1e92c9c0cde5f2bdd4764f60b98a219f1f0dd64ec938a6a247c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x22e817865236ad3a76fbe88bbdf31fcae792f326271d53a3a493b2de7f7d8b4c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x10c9efe573e86fa5b238a3f5c70a00bf02bc7dcfcc4878e026b77c881dc8b1c9"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0a94f16be920d85f4d6f80e745c6bddbc084f309edffef023ea283c72b89bbb6"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x23ed72b4d01d14e3c7888fedb770494abc2c1ea81d6465b5df3da0ebcd69101a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x17c5115640e4cebeed0e6cbb511ac38498df815a77cb162de8d8f1022eb6bb74"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2e507fcca290d0d9cf765245750eb04773e09e1fc813959fb99680a82772e4fc"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0d4a98999f5b39176af6cce65c8d12162433f055cb71d70dfc8803013292bbbf"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x238d8022cc09c21ab3c01261a03dc125321d829e22a7a3b7a1bd3c335eccfa21"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x010cd8e4c2b7051cb81dc8292e35d8e943ce365613d5b39770e454ed9f4ae165"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x088027e54f2a3604b11178cf0ea3c6aa861173a90fb231238e03539590ecc027"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1b840f5311a2b1d4b4cd7aa7e5a9a6d161165468daa938108302b73e464819db"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2bf51a5da1828a1cf9b764b1e16c15929a3a346e44198ea0cb366fcd8db78dc1"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x206ad089d8d296ffe68a6a86767a7fe726b8872f9c7beef9d56a3a50f6f23827"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x24d19193171494fa1a54e0a99ac16d05eaec4b6d617c7c199fc07ff88eac550c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1dd654a2ca9d9f24f33d88246a40dfb32c40662278b9c0b995d9f9fbaf152138"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0d171025c925f6e259d20ecbd3a601108c85f05b0fe7793b8acf57f3789785e4"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x055bef435a43aec245cd99ccb0f7c8791d9e8cf2b80d95dd98b9908fed877d55"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x10d2ac8c61c8a2e88a2a3f42d9359a14be63d0ad4cfd9f203b75765d0e595f0e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x103479710e70996982a84057ec3ba6b2254d7966ddc41e85296e3d0790dcfa56"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2a366f0448fda3c05914ffb48c765da8de96f9aa340db1638225f8372921807b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x16be0fb8ef62da17919b6e0378d00594153bb8899aeb686c04626b63285837a4"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0417038500e9d06c60abbc7f0d0d24c32dec8a0b2aa5a4d52cfd8c78a15bc370"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x26a6873b43ffd2ccf66ec6f4493ff9b54f4d76480bc486a3e5a0308fdd013812"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0a3314a838f32630a96251914fe5ad262f3db9b2aa8aa9f7391922d36278c498"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0fde0c5429a6beb07f462d4821f48f86aeadb46a090b15a044f4b59399027da4"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0abc2d5049972a6b9b357e4163793b0bb517e1eb535a984df11a1c89cda2c8a9"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0dab51d6e3ebfa661d21722fb21e55051b427a5f173f7f17735205dbb77c464e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x29c36622598b511d51af6cc37123652fb21be5c2d68fb8efe9b92e70a8c1ae03"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2c03ec80adac2a33ae846bc0b700d0bcc41c4096e53ac6990d6bbe7ea2fbc85c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0918fdbe9cf3a59fbdb4c6852d065105317303116017d893b8b521e3cebe1e0d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1f19ec22e69ca33f599dd13cd7e495a8176a79df4f7acf79a9d2135acabe2359"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1c4b037c8ae85ee1eb32b872eb7f76928c4c76b29ceb001346447b2911080704"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2b68900ed906616d6c826d0bde341766ba3131e04d420de5af0a69c534efd8db"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x20ca92aa222fcc69448f8dac653c8daaa180ff6dfb397cef723d4f0c782bc7f0"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x10d22d05bdff6bb375276fc82057db337045a5ab7ac053941f6186289b66b2b6"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0b1ffdbb529367bb98f32ba45784cb435aa910b4a00636d1e5ca79e88bdd6cd9"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2da32b38e7984bc2ed757ec705eccf8282c7b4f82e5e515f6f89bcc33022ce9f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x042593ad87403f6d2674b8b55a886725b87eb33958031e94d292cecc6abed1bb"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x181fa1b4d067783a19d7367bf49b3f01051faedab463a6de9308fbd6e7d419f1"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x15aaa6cc9b7900b15683c95515c26028a8e35b00ed8a815c34927188c60de660"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1bf28a93209084bbbc63234f057254c280b1a636f5a0eced6787320212f75a7a"),
+        from_hex("0x1cdb8c8bee5426f02cd9e229776118f156273b312f805c8e6f8c9d81a620cb6f"),
+    ],
+    [
+        from_hex("0x08299c0abf196d53162e0facb5f1876f516df2505cc387a0f8ea0e8760d5ca7e"),
+        from_hex("0x221643d205fe82778a7b7b58cb65c4962d76c0072cabd1124117269d7c710b8a"),
+    ],
+    [
+        from_hex("0x2d036a95f81cf49bb7a0143a28c88767f6bd10c3f74b22db487920d43343dbff"),
+        from_hex("0x08a50897c06aafe6ea414fb1bceca2267cd4a39486729fbc6d5d1bb7a172ebd2"),
+    ],
+];
+pub const OPT_RC_GNARK2_FIRST: [Scalar; 2] = [
+    from_hex("0x26ff6166f1e4b99e27eee6d352a8ce26f1daba01aad28021f11f38a70603afdc"),
+    from_hex("0x3008444ef0f501b34ff882b91ee6a6906000eee6f31452003e51c5a14fd99aed"),
+];
+pub const OPT_RC_GNARK2_PARTIAL: [Scalar; 49] = [
+    from_hex("0x00751ce9669a05bf8adeaa7e79bfe0dba0f7d7a189d04e132975dc9b0e0e5849"),
+    from_hex("0x2566950c6281873d251318a450ec4a8c6af6a38438253673af0b1878d6cd0a36"),
+    from_hex("0x265dad88fde022b1ff7ef763546e8a385bc933b898c69394996148358220b208"),
+    from_hex("0x24a837657b41806f85b5bb4582c399a4a69a9e163f4e82ba8dc3752f13d7fbb4"),
+    from_hex("0x2dcc272482013a28ad58bfdb3f4f5053316aa4074a61a84e2a71fcdf81062c10"),
+    from_hex("0x28a7784084dfe1f4ac1326fd4628039aaaa2e32d9fad298b225f0d0f88aaf35c"),
+    from_hex("0x20384431789108ee0e331aea70f790de20abf6535c65e461dd87165ab85ac019"),
+    from_hex("0x2e159b1a5f798991e1a021850772fe9ee82afd27992fa1080408453aa2efd2b4"),
+    from_hex("0x25f415267a2d2f1c75fb43e06511b902f511eb5063d342700916c6264efbbcb5"),
+    from_hex("0x0fae32d51e072971c35fe7ce0c2366532ceb4052dd1b2cb7fafa6b27bd94e6b0"),
+    from_hex("0x136e7af77a4cebac6b3a5b87a5b17cadaa67e7221118e70f31e138a3a19c48ab"),
+    from_hex("0x1cafc448787411e62774af5616901581862b7ea26f5183d6e1f114b07d1e88d9"),
+    from_hex("0x0138444165b50263bdc8546169343913d06d82ee8967ed16e0b2ce2676324499"),
+    from_hex("0x21a17cebb855be41ba2799fe5ea9870f778d36721de8ec193d21fadd72bb7113"),
+    from_hex("0x2f6236f8cc2ebdddf960642a6cc65854eb6821013e42bf817669cf30747bf3ff"),
+    from_hex("0x073dc794f9179dc6356a9fbe8d1f051708997535db88f949cc08d0e99152e862"),
+    from_hex("0x0ff14e1f65b715d9bef86533a618d22af8407dd504ff7e7f5428a3dfced9a867"),
+    from_hex("0x0b3f96c61e2f661b8803e85e86bd0456d77605f69f4ad8bf3a2ce4a33e921e83"),
+    from_hex("0x0699a0515e6042873c3db23ad218fca1e3e08317bb76f0c40d2a6b347df35a43"),
+    from_hex("0x29b403d66e9b7e4a438ee88baade4d2c353c3896674f6485af57650bfca14e5b"),
+    from_hex("0x0c600a1df90785ca8214572881214cf204444ca525bf45abf19eb1496d02e3fa"),
+    from_hex("0x1d9a287649e9eaf87a353dd42d6fb851dee39cd7c02f404ad9d2263fa76d0164"),
+    from_hex("0x1397cde90e7e635588b7c8424c7f974654806ee6699dc0088e5d4d57ff7b59c5"),
+    from_hex("0x20de79f4d784b88604130a94ccfdf2af1d18cb516f093e946126792ef83c7177"),
+    from_hex("0x13c412c95ed989f3ee1432c80a2be382b88667212b977fb1b58fe92baa432cc7"),
+    from_hex("0x20758e606c88bdef6f5e35a8b4709b6a89565fc3b1265451c20d8b47eb8865c6"),
+    from_hex("0x08eedda5e70a35e8de53d68263fea0534dce27e716b418b637f34e2c87b671f5"),
+    from_hex("0x1d658b3e26bdaf32fa0e7c68eb0aa938cbae5b95710496a269582b317e308b7b"),
+    from_hex("0x15e60f010e7e8197a16210a62dfb037a0c574367ee32b2eec66816f806bea56f"),
+    from_hex("0x02cc86d8ae713b6656a361125ca9fefddb958e4952dfe975aefc7acae75678af"),
+    from_hex("0x2f8430f71f4625d142fd9479fb398c09d860705b32b7a648ab354880be81bbf9"),
+    from_hex("0x0b0074a0bbd47ee6c4e70da44158c6e59b3340421465525cb1a414e300bb4ccd"),
+    from_hex("0x2f3758b95dd7846463c509069fb2d5cce0f8a87f2d3201c766096d274f8ece33"),
+    from_hex("0x26164f50d83700cba683ca48e61ed5fe869a0c7a3f1c6775e619471117b11b6c"),
+    from_hex("0x043254c7b11088eb9a828c58fbd8d8ac68baa51b834d85836a8a3f3454886c6b"),
+    from_hex("0x013938e63e3c93dea806da9439776fa003d0071356d9a8df5a5b7621fa9f0535"),
+    from_hex("0x036fc68e727b22a22136ad8cbd0eca2039b24d032188c40329c635c3b3b3fa91"),
+    from_hex("0x10589be3cfea922ce8140a518bf1a2448f55f0777e55287dd4719593859d5195"),
+    from_hex("0x1bc8704ab2f3a11c55962123e35f5d700119e2198dc218301f36deb8b0eeae72"),
+    from_hex("0x16f843bb66104bcd2de6cbf9df55e2871c15854d00456ec64a008e11799698b5"),
+    from_hex("0x18eb48de6ab1f812e1eda04ee03f911ee93c51cad7eaddc2dd6883b8085f4c41"),
+    from_hex("0x298ff8f8a6e38e930a47329ebe79c4a4d1bcb35f570bb13d17a3c57115da208a"),
+    from_hex("0x1efe3872fc2e686b3d0dfef600bfe328b1b48130a67a2c6f4bb77baf2b4f7008"),
+    from_hex("0x24b4c7a3222c0847ec5f60c87a30d0ff822496aa64bb5d79219f81b785b84587"),
+    from_hex("0x17b45ffa008706dab35c9a64ab374ef62213a03a99fd972853ffc26e3b21582c"),
+    from_hex("0x11003a81980efb4119653a322a3823e6666369b72e665fc86d3298046a18a077"),
+    from_hex("0x0050c27ed4f9f249caaf5288ceae023dac2646969750bcf8f4ea43166e68c419"),
+    from_hex("0x1ed380cca25d0ac6aa1af75f37f4b690b04b6f340d53b64713e2ea3adfd1bc9f"),
+    from_hex("0x03525463c9d846c8757237a8265ac19f5d4ad4f20fc79d80ab91130146d523d3"),
+];
+
+/// The default BN254 instance of gnark-crypto, t = 2, d = 5, R_F = 6,
+/// R_P = 50, see [`super::poseidon2_gnark`]. R_F = 6 is below the bounds
+/// with the security margin of the paper.
+pub static POSEIDON2_BN256_GNARK_PARAMS: Poseidon2Params = Poseidon2Params {
+    t: 2,
+    d: 5,
+    sbox_chain: Cow::Borrowed(&[(0, 0), (1, 1), (2, 0)]),
+    rounds_f_beginning: 3,
+    rounds_p: 50,
+    rounds_f_end: 3,
+    rounds: 56,
+    mat_external: Cow::Borrowed(MAT_EXTERNAL2.as_flattened()),
+    mat_internal_diag_m_1: Cow::Borrowed(&MAT_DIAG2_M_1),
+    round_constants: Cow::Borrowed(RC_GNARK2.as_flattened()),
+    opt_round_constants_first: Cow::Borrowed(&OPT_RC_GNARK2_FIRST),
+    opt_round_constants_partial: Cow::Borrowed(&OPT_RC_GNARK2_PARTIAL),
+};
+
+pub const MAT_DIAG4_M_1: [Scalar; 4] = [
+    from_hex("0x10dc6e9c006ea38b04b1e03b4bd9490c0d03f98929ca1d7fb56821fd19d3b6e7"),
+    from_hex("0x0c28145b6a44df3e0149b3d0a30b3bb599df9756d4dd9b84a86b38cfb45a740b"),
+    from_hex("0x00544b8338791518b2c7645a50392798b21f75bb60e3596170067d00141cac15"),
+    from_hex("0x222c01175718386f2e2e82eb122789e352e105a3b8fa852613bc534433ee428b"),
+];
+pub const MAT_INTERNAL4: [[Scalar; 4]; 4] = [
+    [
+        from_hex("0x10dc6e9c006ea38b04b1e03b4bd9490c0d03f98929ca1d7fb56821fd19d3b6e8"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0c28145b6a44df3e0149b3d0a30b3bb599df9756d4dd9b84a86b38cfb45a740c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x00544b8338791518b2c7645a50392798b21f75bb60e3596170067d00141cac16"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x222c01175718386f2e2e82eb122789e352e105a3b8fa852613bc534433ee428c"),
+    ],
+];
+pub const MAT_EXTERNAL4: [[Scalar; 4]; 4] = [
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+    ],
+];
+pub const RC4: [[Scalar; 4]; 64] = [
+    [
+        from_hex("0x19b849f69450b06848da1d39bd5e4a4302bb86744edc26238b0878e269ed23e5"),
+        from_hex("0x265ddfe127dd51bd7239347b758f0a1320eb2cc7450acc1dad47f80c8dcf34d6"),
+        from_hex("0x199750ec472f1809e0f66a545e1e51624108ac845015c2aa3dfc36bab497d8aa"),
+        from_hex("0x157ff3fe65ac7208110f06a5f74302b14d743ea25067f0ffd032f787c7f1cdf8"),
+    ],
+    [
+        from_hex("0x2e49c43c4569dd9c5fd35ac45fca33f10b15c590692f8beefe18f4896ac94902"),
+        from_hex("0x0e35fb89981890520d4aef2b6d6506c3cb2f0b6973c24fa82731345ffa2d1f1e"),
+        from_hex("0x251ad47cb15c4f1105f109ae5e944f1ba9d9e7806d667ffec6fe723002e0b996"),
+        from_hex("0x13da07dc64d428369873e97160234641f8beb56fdd05e5f3563fa39d9c22df4e"),
+    ],
+    [
+        from_hex("0x0c009b84e650e6d23dc00c7dccef7483a553939689d350cd46e7b89055fd4738"),
+        from_hex("0x011f16b1c63a854f01992e3956f42d8b04eb650c6d535eb0203dec74befdca06"),
+        from_hex("0x0ed69e5e383a688f209d9a561daa79612f3f78d0467ad45485df07093f367549"),
+        from_hex("0x04dba94a7b0ce9e221acad41472b6bbe3aec507f5eb3d33f463672264c9f789b"),
+    ],
+    [
+        from_hex("0x0a3f2637d840f3a16eb094271c9d237b6036757d4bb50bf7ce732ff1d4fa28e8"),
+        from_hex("0x259a666f129eea198f8a1c502fdb38fa39b1f075569564b6e54a485d1182323f"),
+        from_hex("0x28bf7459c9b2f4c6d8e7d06a4ee3a47f7745d4271038e5157a32fdf7ede0d6a1"),
+        from_hex("0x0a1ca941f057037526ea200f489be8d4c37c85bbcce6a2aeec91bd6941432447"),
+    ],
+    [
+        from_hex("0x0c6f8f958be0e93053d7fd4fc54512855535ed1539f051dcb43a26fd926361cf"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x123106a93cd17578d426e8128ac9d90aa9e8a00708e296e084dd57e69caaf811"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x26e1ba52ad9285d97dd3ab52f8e840085e8fa83ff1e8f1877b074867cd2dee75"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1cb55cad7bd133de18a64c5c47b9c97cbe4d8b7bf9e095864471537e6a4ae2c5"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1dcd73e46acd8f8e0e2c7ce04bde7f6d2a53043d5060a41c7143f08e6e9055d0"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x011003e32f6d9c66f5852f05474a4def0cda294a0eb4e9b9b12b9bb4512e5574"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2b1e809ac1d10ab29ad5f20d03a57dfebadfe5903f58bafed7c508dd2287ae8c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2539de1785b735999fb4dac35ee17ed0ef995d05ab2fc5faeaa69ae87bcec0a5"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0c246c5a2ef8ee0126497f222b3e0a0ef4e1c3d41c86d46e43982cb11d77951d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x192089c4974f68e95408148f7c0632edbb09e6a6ad1a1c2f3f0305f5d03b527b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1eae0ad8ab68b2f06a0ee36eeb0d0c058529097d91096b756d8fdc2fb5a60d85"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x179190e5d0e22179e46f8282872abc88db6e2fdc0dee99e69768bd98c5d06bfb"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x29bb9e2c9076732576e9a81c7ac4b83214528f7db00f31bf6cafe794a9b3cd1c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x225d394e42207599403efd0c2464a90d52652645882aac35b10e590e6e691e08"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x064760623c25c8cf753d238055b444532be13557451c087de09efd454b23fd59"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x10ba3a0e01df92e87f301c4b716d8a394d67f4bf42a75c10922910a78f6b5b87"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0e070bf53f8451b24f9c6e96b0c2a801cb511bc0c242eb9d361b77693f21471c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1b94cd61b051b04dd39755ff93821a73ccd6cb11d2491d8aa7f921014de252fb"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1d7cb39bafb8c744e148787a2e70230f9d4e917d5713bb050487b5aa7d74070b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2ec93189bd1ab4f69117d0fe980c80ff8785c2961829f701bb74ac1f303b17db"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2db366bfdd36d277a692bb825b86275beac404a19ae07a9082ea46bd83517926"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x062100eb485db06269655cf186a68532985275428450359adc99cec6960711b8"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0761d33c66614aaa570e7f1e8244ca1120243f92fa59e4f900c567bf41f5a59b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x20fc411a114d13992c2705aa034e3f315d78608a0f7de4ccf7a72e494855ad0d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x25b5c004a4bdfcb5add9ec4e9ab219ba102c67e8b3effb5fc3a30f317250bc5a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x23b1822d278ed632a494e58f6df6f5ed038b186d8474155ad87e7dff62b37f4b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x22734b4c5c3f9493606c4ba9012499bf0f14d13bfcfcccaa16102a29cc2f69e0"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x26c0c8fe09eb30b7e27a74dc33492347e5bdff409aa3610254413d3fad795ce5"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x070dd0ccb6bd7bbae88eac03fa1fbb26196be3083a809829bbd626df348ccad9"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x12b6595bdb329b6fb043ba78bb28c3bec2c0a6de46d8c5ad6067c4ebfd4250da"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x248d97d7f76283d63bec30e7a5876c11c06fca9b275c671c5e33d95bb7e8d729"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1a306d439d463b0816fc6fd64cc939318b45eb759ddde4aa106d15d9bd9baaaa"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x28a8f8372e3c38daced7c00421cb4621f4f1b54ddc27821b0d62d3d6ec7c56cf"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0094975717f9a8a8bb35152f24d43294071ce320c829f388bc852183e1e2ce7e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x04d5ee4c3aa78f7d80fde60d716480d3593f74d4f653ae83f4103246db2e8d65"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2a6cf5e9aa03d4336349ad6fb8ed2269c7bef54b8822cc76d08495c12efde187"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2304d31eaab960ba9274da43e19ddeb7f792180808fd6e43baae48d7efcba3f3"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x03fd9ac865a4b2a6d5e7009785817249bff08a7e0726fcb4e1c11d39d199f0b0"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x00b7258ded52bbda2248404d55ee5044798afc3a209193073f7954d4d63b0b64"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x159f81ada0771799ec38fca2d4bf65ebb13d3a74f3298db36272c5ca65e92d9a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1ef90e67437fbc8550237a75bc28e3bb9000130ea25f0c5471e144cf4264431f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1e65f838515e5ff0196b49aa41a2d2568df739bc176b08ec95a79ed82932e30d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2b1b045def3a166cec6ce768d079ba74b18c844e570e1f826575c1068c94c33f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0832e5753ceb0ff6402543b1109229c165dc2d73bef715e3f1c6e07c168bb173"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x02f614e9cedfb3dc6b762ae0a37d41bab1b841c2e8b6451bc5a8e3c390b6ad16"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0e2427d38bd46a60dd640b8e362cad967370ebb777bedff40f6a0be27e7ed705"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0493630b7c670b6deb7c84d414e7ce79049f0ec098c3c7c50768bbe29214a53a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x22ead100e8e482674decdab17066c5a26bb1515355d5461a3dc06cc85327cea9"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x25b3e56e655b42cdaae2626ed2554d48583f1ae35626d04de5084e0b6d2a6f16"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1e32752ada8836ef5837a6cde8ff13dbb599c336349e4c584b4fdc0a0cf6f9d0"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2fa2a871c15a387cc50f68f6f3c3455b23c00995f05078f672a9864074d412e5"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2f569b8a9a4424c9278e1db7311e889f54ccbf10661bab7fcd18e7c7a7d83505"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x044cb455110a8fdd531ade530234c518a7df93f7332ffd2144165374b246b43d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x227808de93906d5d420246157f2e42b191fe8c90adfe118178ddc723a5319025"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x02fcca2934e046bc623adead873579865d03781ae090ad4a8579d2e7a6800355"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0ef915f0ac120b876abccceb344a1d36bad3f3c5ab91a8ddcbec2e060d8befac"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1797130f4b7a3e1777eb757bc6f287f6ab0fb85f6be63b09f3b16ef2b1405d38"),
+        from_hex("0x0a76225dc04170ae3306c85abab59e608c7f497c20156d4d36c668555decc6e5"),
+        from_hex("0x1fffb9ec1992d66ba1e77a7b93209af6f8fa76d48acb664796174b5326a31a5c"),
+        from_hex("0x25721c4fc15a3f2853b57c338fa538d85f8fbba6c6b9c6090611889b797b9c5f"),
+    ],
+    [
+        from_hex("0x0c817fd42d5f7a41215e3d07ba197216adb4c3790705da95eb63b982bfcaf75a"),
+        from_hex("0x13abe3f5239915d39f7e13c2c24970b6df8cf86ce00a22002bc15866e52b5a96"),
+        from_hex("0x2106feea546224ea12ef7f39987a46c85c1bc3dc29bdbd7a92cd60acb4d391ce"),
+        from_hex("0x21ca859468a746b6aaa79474a37dab49f1ca5a28c748bc7157e1b3345bb0f959"),
+    ],
+    [
+        from_hex("0x05ccd6255c1e6f0c5cf1f0df934194c62911d14d0321662a8f1a48999e34185b"),
+        from_hex("0x0f0e34a64b70a626e464d846674c4c8816c4fb267fe44fe6ea28678cb09490a4"),
+        from_hex("0x0558531a4e25470c6157794ca36d0e9647dbfcfe350d64838f5b1a8a2de0d4bf"),
+        from_hex("0x09d3dca9173ed2faceea125157683d18924cadad3f655a60b72f5864961f1455"),
+    ],
+    [
+        from_hex("0x0328cbd54e8c0913493f866ed03d218bf23f92d68aaec48617d4c722e5bd4335"),
+        from_hex("0x2bf07216e2aff0a223a487b1a7094e07e79e7bcc9798c648ee3347dd5329d34b"),
+        from_hex("0x1daf345a58006b736499c583cb76c316d6f78ed6a6dffc82111e11a63fe412df"),
+        from_hex("0x176563472456aaa746b694c60e1823611ef39039b2edc7ff391e6f2293d2c404"),
+    ],
+];
+pub const OPT_RC4_FIRST: [Scalar; 4] = [
+    from_hex("0x0c6f8f958be0e93053d7fd4fc54512855535ed1539f051dcb43a26fd926361cf"),
+    from_hex("0x27a675cd863f6f8fe3e7cb504cb8c6e9a8243f0acf98d96781c45db01c25dbdf"),
+    from_hex("0x18008cc1ab64cb558cb6787fb7bb0f37173913f83ee689dfa0590661e83b45a2"),
+    from_hex("0x15e47e8a5c9a844dc556ddd3f8419d33f78e76e133c9ff400d386bd0eda9470e"),
+];
+pub const OPT_RC4_PARTIAL: [Scalar; 55] = [
+    from_hex("0x08c86115ca150ebef0bca9d4c5b2d6b38993a2593717d912dabdab0058aeb978"),
+    from_hex("0x2a58d7c6c1deab17eee3867e9ef5519bd872e45bb3a3718a525cf947402a56e8"),
+    from_hex("0x01d883c0e470d0bc0234e933ccaf3550673bb792d618d1349b6cd0e7b0437a54"),
+    from_hex("0x11a73944a1f542fc582f3140fa32b11cfa79d3e54ac43b709412feed8345bdc9"),
+    from_hex("0x218b0f4d8e680a4bc50608106744218bc6163ef69025c8ba143bbe6ca4822951"),
+    from_hex("0x293991ed1c5613018c43f1157553a17dd06741ce9dd8cf3942a9802b4aa0a32e"),
+    from_hex("0x231d4806fdb9a16d9bf8bcfecbde9e8dc58940899f918de6d8724de916bc7093"),
+    from_hex("0x1b39447e3f950de29793959fc2ebd53ff3c6c48a0d5552361c7225a6f48b808b"),
+    from_hex("0x1dec06b87156629154a43796a4d2286a0e4e992d8ab9c0601121aae76b72ac49"),
+    from_hex("0x13c83d22ced50b7b70ad16796f62e9dd0fa8f02a1263b1a4e69ea690b5168200"),
+    from_hex("0x07883732bf333633d064ea471c24c5d21ebb1b0d9ce9d80e2de84081244a25d4"),
+    from_hex("0x05a5494685f01fc51d2a5d3f167158dd3fcc0e2c9a163d2c5f0fe73e66bf99eb"),
+    from_hex("0x1ae9179d954f908bbe5ff6518a831161204709fb925c182bebfdeffdabd36edf"),
+    from_hex("0x051f4e08e55ae61b18a2ba13d2706c7ab14be1527835f3ae1b6e941cebbdee97"),
+    from_hex("0x0d466823a428399320d6f4c552901e9808d6c7d479511370a83628b0f037d6a8"),
+    from_hex("0x296918ff263b5da4ddfedd18346a97f32bb8d41d24e530d846834cacba250244"),
+    from_hex("0x14ddc333c85662bedffbf78c4117024257f2f9e3a7ad7a18fbb7d2ec59c439ac"),
+    from_hex("0x16a75f6f4f602b0b0e9ca847555eb67e2e8b5b565bd2540fb047cf54ed875423"),
+    from_hex("0x02f0a56e49c1f80a5301f7581e61177c44418657543106c77ebf1cd6ebc2abde"),
+    from_hex("0x268f93cb73a173a96031e9e9c9fa75888a75cb7a45678769e1f03c3fbe4e1c80"),
+    from_hex("0x0ddbc1aff41130dc33d687ffe60bb1468f45f9cc5a86429fca1c7054c8acb7de"),
+    from_hex("0x081a9faf1af382a8c27aad3e1b51c89585e2a24bfa51c78c23c9fec7cb8afa83"),
+    from_hex("0x0f50ca9f05f7e9f6a0d8e4cc1d8c90b336f13627c7f4bd8a717d0a3da5c4841d"),
+    from_hex("0x2ddf76e36117d7739d3303a9caa933f235aa6f4e929641807d897464e9b1a3c6"),
+    from_hex("0x212a7d651d7fc82a1adaac220c5dcefea5891fa154ae43f01768e86881078ee1"),
+    from_hex("0x02af7d1c0174ce79e227c7049c9c2777d6c1e94b68d1a8da761f3ee6bb4c9991"),
+    from_hex("0x068f8a6e02dd8c5f5c7a998d0e51775fcf98ad9fe0d6c5399b9bcf0941d3ab18"),
+    from_hex("0x203039e0244e2ee5d04ffe0cd3b750c3c6357dd527047b2e65e5dcca0f29fd3f"),
+    from_hex("0x20e57d72b44cf8337e78074c0f395159a1a12da7e8ae0faf160514464365fe3d"),
+    from_hex("0x0d2f86b4e5f689677ecc0050b13ed9a0eb46168a187409ee6f1486bc60e2f8d1"),
+    from_hex("0x2489ce2c396bcb9b6dce17d977b4433c97072716c86574490b07dac0a29159f1"),
+    from_hex("0x207520d559fb0810a1f7295399aab2cadcef495c92d3cde3c267b12da3d7158f"),
+    from_hex("0x24a1986f035fed11287080cb0b8d73433ca6c4f9b7bca80d6e68f9e7e5c1e190"),
+    from_hex("0x08d18a07b5332090849255e14e3f49ef22d9bca2179eda8a9d48437e082f2f28"),
+    from_hex("0x19675c5897bfaa8043615f18525ad894722756d5f6cc19b16cd06946640f73d2"),
+    from_hex("0x2a336586b21aeb08cda84c0410be04243077eff1cba2483d2e68c1f959b64f48"),
+    from_hex("0x0f9e2747029694cebc7e29790a08c8751b6a423542dc4da8269ab1a4612b1345"),
+    from_hex("0x03af776144b36e91efee8b400f3e4eefb4bcc78a86a127a18ce51e68ae0124b0"),
+    from_hex("0x0baa3d6639bea66de3aa8dc24ef371bb11548a47361f31ab2ba67b4693a3be74"),
+    from_hex("0x12cc27ffe5fd7388c7481cf17532a6ff9fec52641e0d0db47acab52240ed1417"),
+    from_hex("0x2bb0469cf8f96ff64b2ee1ec954255f9f720046ea5ef3b8f4329f51ecebd34d4"),
+    from_hex("0x2f512cb59ac7058f5b37d1a3d8680845c392498d3528133a6a793997293a717e"),
+    from_hex("0x177d61cc6a076547eb5fd6b993c5b15fe3379b228f0854f4b133ca45c4c6870d"),
+    from_hex("0x1b29012d8c058103c14d90a082b787d30033ddfef49b29f3e473e68111f8e91f"),
+    from_hex("0x23ff4e8c5f3f33a4743542fc2b6892a91d8f0e55d2e2fccea16b9d8e5bcd524b"),
+    from_hex("0x0098c9b6712f0769dec6aea34450bc045b34d5964ec3ea97643f407101d2a609"),
+    from_hex("0x1a35589018b79b99065b0f8bb317988eaf4c7fa5c3fe3b5dd4f2f7d345b96de1"),
+    from_hex("0x24e0319d01b97b92e689086b45b922167448ebee0a049f591f50017c02792a77"),
+    from_hex("0x1763e7f4852da061503647f3f6c8889ed8c9b6a76a2d338ad3db9ea5d5509e18"),
+    from_hex("0x0f0670fb5a0c53d00b59605a1a43054f5584e98bef35639feb9e10a9ecbd2008"),
+    from_hex("0x166674568da1c47ea909f6fd9ce409573efd4e73b8cef2861470cff061496d51"),
+    from_hex("0x0f05d9317d7bd0ac2679c3aa23c8805cd132112a99ffec1eb34df5d75acbf161"),
+    from_hex("0x2d3aa191ea5ae714c091fdc0e66ce889f9b91635297b1c6a3d37c0b641206f9c"),
+    from_hex("0x0f002201910f5e0fc1130e455c19c9729fa48ff8883218131b95a5dbc9135ed2"),
+    from_hex("0x18e512358145d1421d00cb59beba56dd4bb8036af9684c760f8b5af3305d0e04"),
+];
+
+/// Poseidon2 over BN254 with t = 4, d = 5, R_F = 8, R_P = 56, the instance of
+/// Barretenberg and Noir, see [`super::poseidon2_noir`].
+pub static POSEIDON2_BN256_T4_PARAMS: Poseidon2Params = Poseidon2Params {
+    t: 4,
+    d: 5,
+    sbox_chain: Cow::Borrowed(&[(0, 0), (1, 1), (2, 0)]),
+    rounds_f_beginning: 4,
+    rounds_p: 56,
+    rounds_f_end: 4,
+    rounds: 64,
+    mat_external: Cow::Borrowed(MAT_EXTERNAL4.as_flattened()),
+    mat_internal_diag_m_1: Cow::Borrowed(&MAT_DIAG4_M_1),
+    round_constants: Cow::Borrowed(RC4.as_flattened()),
+    opt_round_constants_first: Cow::Borrowed(&OPT_RC4_FIRST),
+    opt_round_constants_partial: Cow::Borrowed(&OPT_RC4_PARTIAL),
+};
+
+pub const MAT_DIAG8_M_1: [Scalar; 8] = [
+    from_hex("0x05bffb5e301d8c468c35e24eb2165b6b71725fb7ac9a48efe5ce041bdb05676d"),
+    from_hex("0x2aa7a81812688343fc6d78073312996d75f4c5505db0ed22af5ec0df7888cdc7"),
+    from_hex("0x2f5856fd71dab60d78cc3af15a89c1e4d61ba189849a4cea10acc1dd228faf00"),
+    from_hex("0x12299a260999ac95d271e184968cda40bd4358877a6dcf43d779251fffa61348"),
+    from_hex("0x1443aad4693d692a62a8e21f03d5643a123f0c8783a3d27c275f9d01089685fb"),
+    from_hex("0x21561b0204a44488082e31472f5885a3adc179bb278233aedc4b316369ec9937"),
+    from_hex("0x0c7cc2afa53f9898f30a69b294a4e24f6b2176e1ae0ca49b021792d55e34e97d"),
+    from_hex("0x2dd221096053de389fae88e7caa5c43ab55e22aeb758ee130d1246c1dff47b53"),
+];
+pub const MAT_INTERNAL8: [[Scalar; 8]; 8] = [
+    [
+        from_hex("0x05bffb5e301d8c468c35e24eb2165b6b71725fb7ac9a48efe5ce041bdb05676e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x2aa7a81812688343fc6d78073312996d75f4c5505db0ed22af5ec0df7888cdc8"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x2f5856fd71dab60d78cc3af15a89c1e4d61ba189849a4cea10acc1dd228faf01"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x12299a260999ac95d271e184968cda40bd4358877a6dcf43d779251fffa61349"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x1443aad4693d692a62a8e21f03d5643a123f0c8783a3d27c275f9d01089685fc"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x21561b0204a44488082e31472f5885a3adc179bb278233aedc4b316369ec9938"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0c7cc2afa53f9898f30a69b294a4e24f6b2176e1ae0ca49b021792d55e34e97e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x2dd221096053de389fae88e7caa5c43ab55e22aeb758ee130d1246c1dff47b54"),
+    ],
+];
+pub const MAT_EXTERNAL8: [[Scalar; 8]; 8] = [
+    [
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+    ],
+];
+pub static RC8: [[Scalar; 8]; 65] = [
+    [
+        from_hex("0x0dad22d08a6b8d81d4a5ffc34b9677a7c5254c85e953551f9eba9a0a97590c10"),
+        from_hex("0x03cfc441111f1bab6e75957b9f0274e92d17aefc2d8da460435dfaf259bd1039"),
+        from_hex("0x27c6d1b8a2e2ce670376cd2970192a2a08e4290883f6ca49e7976c6c447d6392"),
+        from_hex("0x28f2882b9abfda8efc2d121da0c871d2d5313a53c67d0407eef4e93ee23e6c2d"),
+        from_hex("0x0a3943390fe4939fa2e931e18094c7c93aa107ced2bfaefcfe5e613a3f1262d5"),
+        from_hex("0x2a8f37d8da8f2319e188f8909007408b57fba3555bb784c1ad6c7b0755805456"),
+        from_hex("0x0cef8658e33f20c39649528f353ef5d03198c75683f8493534add693a4ff4f0f"),
+        from_hex("0x1cdc2a6f0f0e7c6c62ee1c0cad4fd11363a48db7095171faaf9a7b6e01c3e52b"),
+    ],
+    [
+        from_hex("0x0a2e681a2db19c8d1cfb0c28f0b7b3d9830d35fca8c0b076b4e64328060139d6"),
+        from_hex("0x16f013cd703be7de16b4361ce5c67cc2997f858e8a808885da67fbafd30d2717"),
+        from_hex("0x29af57131c9cf548be669fe76c675e6d90bee17a76cccf4ddc5d35f9a936ebb8"),
+        from_hex("0x21807b1ec6258bb155ed159da90b815dee119eae8138b1bdf950111bf5205b3b"),
+        from_hex("0x07c00db8c50a8c860fbcb8ebd234a54350bdbcb8747891a4e5adab1c4abc6909"),
+        from_hex("0x22bc08fc054d08a3dbb6094396d0de373a4c33205c49a1254cf25ebec1747765"),
+        from_hex("0x2dad58bdf3e24c78f21146de084d9b8afad58956fedce67d43f7d375a813bb2e"),
+        from_hex("0x292cee6bc19a64dc5a8a74036e676e238f60b4577db86213ced417906f1d6e91"),
+    ],
+    [
+        from_hex("0x27d655ae125928ad6b48bba54593b74ca0c23002b32a0a9ef375939a22387a8c"),
+        from_hex("0x0e0e2e781fe9ac0a97f0d961c7b359a415d1ead41c99eccee90e56b68fbfa2a8"),
+        from_hex("0x13fec23e678d1c9943b6daed8ed8212289116ab96344ded7ced8aac2f09f1672"),
+        from_hex("0x27550dd21647e37fc31640684e02cb07810669b1c1545443fd58204b2cde73c2"),
+        from_hex("0x1de6d5f356a92c48c7b9f6aa45c8a6216e0a6adbc799c0bec242054674cb8f09"),
+        from_hex("0x14c5347c4580363f74c885d1fbb378c61d953e490892b10baf84f6e1f3a3f39d"),
+        from_hex("0x27f2768023c4df7952ca0d967f14034e98c4ca76973ae1cddfccd212cbd78683"),
+        from_hex("0x1f4f499fc4853f7e189ad6d2269c467f77e15bbd28963c516b4fbf1ba7b88f95"),
+    ],
+    [
+        from_hex("0x0d62ba9a8de3b97c72a5425a97a7f027fbad23f299ff46bafa12dcffb0e72bf1"),
+        from_hex("0x22b649ae468f20c7cbfb435d932ba9ba4be14ea40c0c54e6bec970ab4c513f04"),
+        from_hex("0x03a1aae439e47e7e14186d5513da5e263e063a3da6d042c16e55cab2e81a8e95"),
+        from_hex("0x25c7eee1fce422600f5b1e78323f2e99cceee803f62763217eadbd046c1e0e6c"),
+        from_hex("0x1c66258f103a955274ed71de56169bba34ff6aea97a2c32b98805dc3b011a383"),
+        from_hex("0x29403b00757a647671a3691094aef420ad830f95743d45894d6d7f40aa356e4b"),
+        from_hex("0x13496363ef5f7d0e91fd1319fd5dc0269c5190d05d8a0ad28a3ce0638a133e0e"),
+        from_hex("0x1c6a4586c15c4638a1686921d7c204e2b6e1a88c1fc49dcfb87ded2a1ed7edc5"),
+    ],
+    [
+        from_hex("0x0da23df12d0bf47bb92f15ee189386c20f552d0bc66ae492b92d69556b2fe75a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x155be88bc5ddf8e0c6c741286cdd5ddd559279a455b9bbb61d5a0b004e545d5e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0553fbb5b32db860e0bb12cef3aab4e0dbbdb1ea8704d335a18fa8b4df112c36"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x12f6a3a61a3139221cb5875cf455b942580c00942499932dfcacf6f75cb80ad6"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2faf9e95457781b3c61de8a1faadda67f76f52bb81255fac8819bcc104071e05"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1c7639cb68d3cfa69f3d795d1a1dd4eba176d0c29701e7b677ea0750b4ccd1db"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0d9d85a46281f81502fbcfb430f4d08ab504c502d2feae2ab4a26d87bebea0dd"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x00a9b7074ea11d9931cf4af16952f398777aaa36413df14cedc101c7693812d3"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x07fab349ddb7d62a5edc0b6a62b300715634e5dccab808d8fa6ae62b406705a3"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x11f8f3afbd6fe6bda7d055a536ab3de9c40c8155b31d397f63ef21ac974cf52d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0007c569f8f49899805d22a1930883968a3cf058993e0adfd06da437ae50e830"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x15dfb18bf1b6087c0e638f2189a7b34534549ffe8fca5afccf7e528a3338cc92"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2140caa062d2fa72722ad48fb71bbc9f534043dab90dfa24b96664eaa2f0ac6a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x11c06fe4e5398d4704271b84e6023b07b189dd1d2023dd599b33defe4251b715"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0775f90bae5f8f8157eb73bbc7ae69b74d44761cf0df3b4666eeb2860878c0df"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0225befb64b758b837a4305e104d5e510d893653559d8768dc79325ff89d6b9f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x201a730245d9227f1232cc02f486c18cfa1fba40665ed3d1bbbdcd19b03cac4f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x128b63b12a6647f80c686aaab15b0563c1a33f475dc18caa434ab64f1e290acb"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0a34ce8153d4e2e1158de05d276530cf3c749c16c7e09f3b671a973b0efb0839"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2809c5d323665513c8c4a96ea4dc08e3aba43699bca1198f2b559915082495ce"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x23b929e7d71fe425f36f32975e9a68bbf83c16622678f061ed78a0c9ce318be3"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1ca1a93d02ae448ab773f95c6b88c0d01f89c0c3d25ad625042771dd5a7e74a9"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x03c7381ccdfd209a8cba5627a058c33f472b24d2b1a55d9dd47f60dc7b474e7e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x00be1a29bb668a25eaedffccef6c01c90688229e8fc4be9812e371d4d61c3015"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2c821bfbd5c415fc076fc83895edab9e5256ee826946a865b8c82f0189af4626"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0b8ff5b252182f6a15cff90144c1e7ac1678739882c90fe46d0a29db7a0cba77"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1c0e94b15306baf1b017623888b8d4c5402ee81fd213c4a5e42f52729deb7d5c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2031deb0f13ce17a5b44edbacf5a0410e23348c2c7657383a8ffdeb5c5ca1cd4"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0e34d14f44e8eb98ac078ec90eb718c3034249fbaeb13d24f3d5b6a4002018ac"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2a10f1b76f8b1cc429820030c58b3d23ebfb775a43b07928b9868891c2cb4cca"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0535bf4f7fb763f981d2c0c7ecd2237a461ac385e2235fb35222b512f1dbd1b7"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x12ccb3fbffeff74fb7ce3b9943f609dc0d7fe34e3b5e6b199628e86d5576cc18"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1f44cd78220a2e66be6c9eb3bcffc498a47f22c3eb476591cf456d518e3e126c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1d60e9e3444a748b8c22d7d10e825c536290b723fcdfa046436353d6f9abc073"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x05a3ccc67bd014ef2544927f9b96ff58e348954e40af67861ab88d78ff4b6688"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1be5b195896e9ec23b9d3eb898734c385272ed64bdcb236223f5a3436986dc6d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1d6427dd07a0b46a8ebacaa82e8c04de8e7e94a18849c9275b8b4827a873cdab"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2f09a403f946b692704fe4d5ae69625441c93a7f93dcb4f8f7d337baa735ad9a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1807540511593488e084b7297b6ff67b748c71731b6ca64aa8d3823e81f41b37"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0f1daab7a702ac80d108b24845bda04b732f3564ebe57392e470380199522e1d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1797d0f72bf0b47a45ceaf82df261439bc5e3b0ae55cf1069733d5a0b51765ca"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2adbb9b4003631c5e3d02ce959930c75d4d25281c86febc61dfdf35e00cfbdad"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1347ad87393ef928d2800fa2a548328ef9bddf5646da79d507d2f2055db335d6"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x15658e02d31e2c200dadf6a985652eccd30cb959168cde7b74c7239539d2df1e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x01f00dc28bced12a74c3ce687dd3fa996d6f8bb29bc60cda4f3aed191a6fe8d0"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x154ebcc230244b68b9a8f51a56ff0541a92ea2cbb3e77b263cb03a8bd89491f7"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1f65f74b523a8c875885378e93d1946c7a3f810e2eecdea94b80c4f741ea6cf9"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2504a559a4bd23ed689acc9b6cb4062479a6b29692e2b8cce40058daf0505778"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x24496d9926e4e565ef860e52396a2935689c87ca99abd046bc279ce1bfc43206"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2c9ccd22b143f4d8d483846d1960e7144a5c9354ea48cd1a71bdc82fbb929838"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2b9bd02e1b064a8db321a1cd212a7a6dc47274f6eb5fcfa4f17d6f690dddf8c4"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0b21f0004118382f32fe441fbf36c0a38ae41873ae42d3b6ea5f0c6816e00472"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x10756efb491852587059f3e48b605a3bebabaab0a1ee5ff1758eb30623b7602c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x127574a38823886d1f4841b540e29d67efe35aa8b3c685f969b6bc6cbab5064a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x24751d620ed2a3db4382265b8c33b666b85beae7aa87f38e28bd2f8d637ff9d0"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2e3d7208892e8a92e887fccccfe04d8d0fe994bc443641a9fc76c43da02f453d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x11c3f6719071e6810699be20e1b1858b9ed00d8166bf15434cd5e43507b9d62b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x00b86112693a4e7f3ac4eadfcc3f04d24a7afe51a87f82d4a435ad27f30aa2f9"),
+        from_hex("0x1a77348400a87f0180e294286a7a06c857f590e8ed17eed7a6b4cc1ca5a97929"),
+        from_hex("0x207feb0ffff2e419bc967dd544abbdd2dcc601316f49957f2319c9a04f56157d"),
+        from_hex("0x0df4aac4e6483265aa3fc462835a90380bc84004fc46d1a85e61baed3196ba48"),
+        from_hex("0x2f2df3db6ed0e27d3151201dfe7f57fc2e5ccc69c2458535dbdbb5d88aeabb4f"),
+        from_hex("0x2f51811c3568dbae396f86838c5c8b1bbf9b5bcb2ce98542e289184b95f74b7e"),
+        from_hex("0x1198ec466dc8c3f55b048369eac0490e835928891325d4cf4461da1d22230219"),
+        from_hex("0x19036827eb0714a1ee4fdd653d8667df192402e290dfdfc39bf005fdfa86c043"),
+    ],
+    [
+        from_hex("0x0978d774018c29f2818df365312371ce4082a6c8c52d2a965516b76436349069"),
+        from_hex("0x231ed2d94767eb9ace111af9317b233fee037214e3f6d7e366a5812c8fd1dce5"),
+        from_hex("0x0fbd8502ceba8ab93eb04d1db535d25f4c3be8acac4e8e0467b9d606653456c2"),
+        from_hex("0x20e9fac35dda464e436a4d2eae4cdd5b52c9b712d5b4bfa43334463f8478d4bb"),
+        from_hex("0x15ba9fc4b175278a12a5ab39ee0121b06dd09b923bdedb69a26182821445ba00"),
+        from_hex("0x0c3c9aec9048b10a06d062675b63417a1ae8704f2918382466bf575e22a654d3"),
+        from_hex("0x2795a46eb4c79595a9de2191c524f0aaf92f764153b23c357ed50bf3a91cddf0"),
+        from_hex("0x10ab7893c4253d861276ea30f229eb86b90c69985fb6dde4aa50e24828caa81a"),
+    ],
+    [
+        from_hex("0x2d4509d8159af62c44503f64fc01e953e90c6dced8ba75c58df2aa090a5fd359"),
+        from_hex("0x157946957f4feb94eae09b9a6c272204d107ea7b0e31b67524a6c9ee7f04e7b9"),
+        from_hex("0x30464efe02b65b862f9a0b59fea6d6934b393e65cdc3d29ae67a751a0d0f136a"),
+        from_hex("0x0965b3a2d05666d38ffcdac4e5ed020c742ba41d760e70f74dcd8f8b1324c901"),
+        from_hex("0x24531c784663d57639928f0a8f152038a779eebbd70f6585dacc971668ebec55"),
+        from_hex("0x2202070df8f85b79bf7debf24a9cc0f2cf30370f26a9372e01718dae7cb6171d"),
+        from_hex("0x08282fc0baa2e9eb76167b9ab2405db9c78f1f37b168be6435d5385a806e745a"),
+        from_hex("0x178c3d6e47cd33e5570311ec8ad8dbca0dbf54aadd1393738d898384c57795e7"),
+    ],
+    [
+        from_hex("0x12ad905f9d82f33643b1fefb90dcefffeb818d1ec3f4fc3d6857203eff5ed1ea"),
+        from_hex("0x229682c9e4165b6ed1a2870c14a1c8bb7b1c7d3b52f42ce0228b85480666b4c4"),
+        from_hex("0x25190d853744dc11d155de5e479640302a333d47cf18dbf1ff241ce42c88d4c9"),
+        from_hex("0x00db47fadc76bf4fd8dcfe26908fe48ab42d399da939ac1f167ca8600cfbe7bc"),
+        from_hex("0x2ba5e88adcbe025760f2c8654935f0557d14c6d4125a838f312f2ba439e2e94c"),
+        from_hex("0x1376bbae85699dcc294f850c858b4b370e51ff3139bc3e0a1e52eb58d08ca41b"),
+        from_hex("0x1333fda1e3d58e0747f5c7361d5075526933ea283eee2ac37b6042c6083b6a42"),
+        from_hex("0x28bfc4dc9594e9add687744b79f4feb979e2143d742d4e4d1d5bb64bb799a02a"),
+    ],
+];
+pub const OPT_RC8_FIRST: [Scalar; 8] = [
+    from_hex("0x0da23df12d0bf47bb92f15ee189386c20f552d0bc66ae492b92d69556b2fe75a"),
+    from_hex("0x12fe708675dca7e7f2cbb9513b03a8a3f661f07d3e0fd2469a980b238d375837"),
+    from_hex("0x01d5434c13885e6ac7eeaf269ff69cae2cce108cee8bfba3bb6698340190a056"),
+    from_hex("0x29039680ca8c26623e17bc50303c95ec10647edcfe6ef6558b624ac80f15fb23"),
+    from_hex("0x00bc21f97661fcf795785bb83094d991fcdb46e9053235f4c041b152014caf13"),
+    from_hex("0x124df646e6c0cb6d8b97621a9df3681a1ec2e90f2ac90824e84b786fb09fe113"),
+    from_hex("0x15030e15a8c8f94a20090942d2c9c06f814e1340950e4452e04ea123b0eac0bd"),
+    from_hex("0x22bd44c89f3022ba2f0e65258ec6e53d100c25ab9aeecfda078ef2365d3ca298"),
+];
+pub const OPT_RC8_PARTIAL: [Scalar; 56] = [
+    from_hex("0x0e8c8f57a6fac1d33fcb7b19ce977a7c5d9ab1882311bda095576e1ecdab860f"),
+    from_hex("0x015fe2880785f39e781c1fe5b7076917f29d9711ec5f378d5ba292fda7769570"),
+    from_hex("0x28b6ab61e3e76e84d07b14c7bcaf3fc53846ed241cb5890e0267422614493f2e"),
+    from_hex("0x1495e9b7df8cea443d3f8bbb4f9f8b1b963e8bdfb852894d884c115909399f41"),
+    from_hex("0x1cf516d410f62cfcfd0e26cc5af8c9621d8059150e78c29b7c8699ae8c53190c"),
+    from_hex("0x27fd8ee918a7b24f8abf79f1beecd6005a3bd425fee45f7c78f134679574c96f"),
+    from_hex("0x2638d6d83ca951fd2f61b7f86531b6673d6504480b19e54ab975a2550dc3abef"),
+    from_hex("0x139f6e183149180544f6df30324bfc025e933b83b36a52d6866a7af787fcef53"),
+    from_hex("0x14f7c0f7272f5230fb4a766d50257203070b8e970fe1ffaea62db3d8fbf8e812"),
+    from_hex("0x03e6d643fd6d3c1f1c1be2d00e6ab1d797460f7fb02d971dfb33a6f442877fd0"),
+    from_hex("0x1f435124ce942e07c8e19a06dc8a5fa83d23b3509def352b717de66edc35b941"),
+    from_hex("0x1652f9da6c6153223f1c0488bb72446035d9b1ee49a6a06dd384015a0113f03a"),
+    from_hex("0x206efd68f69fcef33090540fd11e9637f8c411c020064eb8f0878b8d47dd3a6a"),
+    from_hex("0x028df55ffa721ee895ba66e22c2dc386963aa487b8ddd0707da5107cf3f20e97"),
+    from_hex("0x18b45ffc7bdaf25f18de38949664a2775136987a00e0036f900d0dfac4e6ecd3"),
+    from_hex("0x001da17b09a94b0cab139324d4b505d17aebc51e4945a12db579d8b06255d27e"),
+    from_hex("0x136fdbbb65332c828d14ae1a46a13f23f7ca30f05e73f8fce12ed7fe2cbb93fb"),
+    from_hex("0x117d6cae9177a3ff90be0e1c6839e9f217f49a4f10486d8d19c745a22bce0ed0"),
+    from_hex("0x2a0cf3bcb2f2cdf0c3e21131ef0f5c81b192e16e27f5f2e2445f5bd167a083fa"),
+    from_hex("0x14e5883c0350c5a983d5908f1cc8380d71ff630b85d3a87b6c7c5335a38ce2df"),
+    from_hex("0x2723551c7c0070d1cda429a50f99b0aac89e7ba9eb893d3b73d1098a1cd486fc"),
+    from_hex("0x15cf638f07cb83f7145f9d1bfb1a2514df00dfb9ce9d2a9e2019b2d84bdea4b0"),
+    from_hex("0x0bfadc6507d79d91cf45b3b841960d16848017fd9187f621f1c970b7cfbbb104"),
+    from_hex("0x02e635aa1be20a8681bdb7e63a5ccd5ec45b0aa7ece43a0901a97011d0536184"),
+    from_hex("0x10c6505bbd419bd657c6e783999bda9becd5976fbf4680bf2c6ab21c93531ceb"),
+    from_hex("0x0b15b3b4191e4a4dfcfcce7c87aae078a22768269387dc548cb326082b4e05d2"),
+    from_hex("0x1e42c4801429ccb2ab436ded53c1f877430f92c6898236268f91c3c747baf6dc"),
+    from_hex("0x1a6dbdcf47b3527443c1b1f3d9425c88067d957de1852a5e4f1bb0375eb63bd7"),
+    from_hex("0x06b7626c2e36bd09d3d1b8c8b0971a3f6419240df5d52d439c35420163336e3d"),
+    from_hex("0x27d678136e332fff3686140e5cd9993545ec8d233afec1d1165fbb14b9e9c3e2"),
+    from_hex("0x0a11c3b119be934f3c05659751c951970e951b71440a665dba167dea0bec5dae"),
+    from_hex("0x2edf362c6579a6960ded53e728c93385c9dc78908912503983b161039697ce7f"),
+    from_hex("0x0fbfa7724c0fc092d703c02bdedf3aff07ee039665a353d86d92c80026a69356"),
+    from_hex("0x0aad2d6d1eb3f9485333182f3c63458b3e0ba781d091ef927a8bcb74806ed542"),
+    from_hex("0x167cb320adde5bcac4c95832df3a78cd294850506c85e87ea480192fecfc7c1a"),
+    from_hex("0x0a1dbb392174422ebbf9537b7fb801ad346bead88b508506b29eacc2689ee08e"),
+    from_hex("0x1e0309972f762cfc3d24c5668d5c5ef443117229423822b4fef3aca651da9525"),
+    from_hex("0x26b042e02bf857485d757f7b3e299e4b3fca4600691aab045e1d73f8567e302f"),
+    from_hex("0x1d06565fb2f56d3199c75d20d7b425cbdc7d595d21d42045894bc72d19442269"),
+    from_hex("0x09726376d10b6d821e707662c61a7a09dde22e7952eef875a0f178e83b13bd9a"),
+    from_hex("0x03dc1453874cbbf9407de92014942a48d349d1350c91e0bb9ec18e1d1b8ba04c"),
+    from_hex("0x0f4fd9eac0c8606f7e03e1f36c34adf36e2a18a9d95d89bfbe4a07d7d5979aa2"),
+    from_hex("0x1b5d6e4f7f63beda4682b05c5eefd3b90a28eee70f696eb0edd95c1aca1da2b0"),
+    from_hex("0x24646f07376e6dc809629210dc5de545b6ae785e51cb8d3b281746b9879a392d"),
+    from_hex("0x1fae90a1fa857441dd04c8bef56c28632992c90d3a5bc5f4a6d7668538ea9249"),
+    from_hex("0x0cc03188ce62dcd033385c81d3bd1523dddb36ecb0a1dea278b71061da440286"),
+    from_hex("0x074607457a18d660d9a6ae78b67ac9cd3c31e3e0b01a08ac0a1afe362c71ebfc"),
+    from_hex("0x04c2e8190289662edfdab2d1e4862f5c399949ea59589c0c9b7d6f748fdb0161"),
+    from_hex("0x2528f7138e13e6b753efcb471b45bbc50edff081487eb384c5ccf1a1cbf935cd"),
+    from_hex("0x08eb3ee1747f9f1fa4d31a1670eca4335cd56d701096f747eab56d5250cb0a10"),
+    from_hex("0x24138f584d929f3f865b1cc65ac2074dc7b6e832389c418a72cd74b0793435b3"),
+    from_hex("0x24a413ccb1daea26a750fe9b86eae0ffeee1605065da3d2ef7065dbeb65fbbaa"),
+    from_hex("0x0ee204641e742e8e5be17fd180d31eb6309d5f95e6b0701095339622ed36a120"),
+    from_hex("0x01683bd1918476bdf3d57b22c5624358d430396e63ff01fa6c358b231b75d291"),
+    from_hex("0x1b22ac823f15158bfd7d158bafaf7dd7a04d7904cd9eb539aed1fc18bbeb4b98"),
+    from_hex("0x18245d0a7d7fdad2d6160a2aa9ff62ef385072c50d45da272d13eb2ad95ba7e7"),
+];
+
+/// Poseidon2 over BN254 with t = 8, d = 5, R_F = 8, R_P = 57.
+pub static POSEIDON2_BN256_T8_PARAMS: Poseidon2Params = Poseidon2Params {
+    t: 8,
+    d: 5,
+    sbox_chain: Cow::Borrowed(&[(0, 0), (1, 1), (2, 0)]),
+    rounds_f_beginning: 4,
+    rounds_p: 57,
+    rounds_f_end: 4,
+    rounds: 65,
+    mat_external: Cow::Borrowed(MAT_EXTERNAL8.as_flattened()),
+    mat_internal_diag_m_1: Cow::Borrowed(&MAT_DIAG8_M_1),
+    round_constants: Cow::Borrowed(RC8.as_flattened()),
+    opt_round_constants_first: Cow::Borrowed(&OPT_RC8_FIRST),
+    opt_round_constants_partial: Cow::Borrowed(&OPT_RC8_PARTIAL),
+};
+
+pub const MAT_DIAG12_M_1: [Scalar; 12] = [
+    from_hex("0x20bb1e98f40bfe80b8e2f2c885ea13a2ea1f146ff61218c31075ad79dd8f4ffa"),
+    from_hex("0x1ed6abd05c8d678fa14c0c77d90f02fa1f8af249915fa6518f5f8d5e5c649fb2"),
+    from_hex("0x29107b18658b47d566f5063975d6bbc504382b81777e0796c7cb81f9b4e2cf46"),
+    from_hex("0x134ceec3ec069dd76fc9804ff029c2c27c5646986ff5dbeb17091d9c479ae923"),
+    from_hex("0x0ee2e4f4a3c23a1b71834d1a95ea402504b8d68fab6f74855c884df898c286f9"),
+    from_hex("0x0a469d3f3cb250181cc1e70c8227dace349b1c52fe8f7375744c8be5b80771e5"),
+    from_hex("0x0cfa92ab38d116f1cdc24ecc083ad1cb17a215de9968726a81970dde9cca70d6"),
+    from_hex("0x173df1a0df85f4533605f9578b1f58ccadf1f810e1fcca66808382efa84d684a"),
+    from_hex("0x2ced3bf3cf641c12a311b16b4107663388876a894e70c28c5498946c7fd8dcd4"),
+    from_hex("0x0912073a16428c84bfbb6170108adf6d168100ac5587c23c9405cf1f7ca8f13b"),
+    from_hex("0x250b310cd13063ee49c78680c1434853968f464d2fc99166c23a2e330dd71d54"),
+    from_hex("0x08b593b39852f7ad095a03a3eee546c388e02896d460454c6f1887c8d1c82e37"),
+];
+pub const MAT_INTERNAL12: [[Scalar; 12]; 12] = [
+    [
+        from_hex("0x20bb1e98f40bfe80b8e2f2c885ea13a2ea1f146ff61218c31075ad79dd8f4ffb"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x1ed6abd05c8d678fa14c0c77d90f02fa1f8af249915fa6518f5f8d5e5c649fb3"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x29107b18658b47d566f5063975d6bbc504382b81777e0796c7cb81f9b4e2cf47"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x134ceec3ec069dd76fc9804ff029c2c27c5646986ff5dbeb17091d9c479ae924"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0ee2e4f4a3c23a1b71834d1a95ea402504b8d68fab6f74855c884df898c286fa"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0a469d3f3cb250181cc1e70c8227dace349b1c52fe8f7375744c8be5b80771e6"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0cfa92ab38d116f1cdc24ecc083ad1cb17a215de9968726a81970dde9cca70d7"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x173df1a0df85f4533605f9578b1f58ccadf1f810e1fcca66808382efa84d684b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x2ced3bf3cf641c12a311b16b4107663388876a894e70c28c5498946c7fd8dcd5"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0912073a16428c84bfbb6170108adf6d168100ac5587c23c9405cf1f7ca8f13c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x250b310cd13063ee49c78680c1434853968f464d2fc99166c23a2e330dd71d55"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x08b593b39852f7ad095a03a3eee546c388e02896d460454c6f1887c8d1c82e38"),
+    ],
+];
+pub const MAT_EXTERNAL12: [[Scalar; 12]; 12] = [
+    [
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+    ],
+];
+pub static RC12: [[Scalar; 12]; 65] = [
+    [
+        from_hex("0x0f43621f15c4af9c4e52180b3ab6fbc9cf57863dc34e6190232841eb8b085634"),
+        from_hex("0x1668bae54907e5387889e1d55a4d1d3b135c9a652fa7a4d70bb5146f8b14ed41"),
+        from_hex("0x0a87adfff46809b3ec145fb43aa86f893a109673cd24d6cf2a49a56900487642"),
+        from_hex("0x1e9fde940aab66670c2b1181f6df82c382ed1b5d7462fbc51c4b5e409628c80d"),
+        from_hex("0x29361fe9bc57577ff4d84574c95e0ec17d135737a27e1eac39105d6e74e401b1"),
+        from_hex("0x0259de79b50700337c1c9cb31e1131663ee8855bd1b8a6ef42106d25579f488b"),
+        from_hex("0x0f6f5923c9465ce3725957788d208170001f1aab4a0a305485b891a5db328979"),
+        from_hex("0x02aaa6d934fd501ae8ec3c61243f27898b32ec59a591b55a3b2c4fc9a80bbbf1"),
+        from_hex("0x1f275665d09676b37c235095a09a20572cc9f7c560ef1bd70960531dfce0f9c9"),
+        from_hex("0x0a376d54e4746f30352fe60fe77549f66ca6fe9b489595c4b7f03d6fde4dc609"),
+        from_hex("0x0f9fb99ba9ed076b06f6a854af79956315b20c7c847afbcb82f3cc4a6319acd1"),
+        from_hex("0x2647fdf764202dc23974cb36f678f88acabf40a3af1efea82a25d4adba285a90"),
+    ],
+    [
+        from_hex("0x163d4024aa78a29e1b29d817e5a54d62c35a0cebbdf280d46da3801f7ca3aa60"),
+        from_hex("0x18d129e8e0a4e479164cdc899a2d3ebf43d974e4b09d8dba6c320946223cf182"),
+        from_hex("0x23ec490ac0fb5b14fb10e0fc78f0b9b6fe907eb7d5b98e6717a1dfeed2897b47"),
+        from_hex("0x038bb6dec1ced2b3f1616c3a3690a33905d9e9c800169848ea41525df1038a35"),
+        from_hex("0x0efa40ec3478a8da5b538017469d4e4cadbae65f3912fad7fd6bfe4f8aece05c"),
+        from_hex("0x07ee20d224c6f2996ab8695e8ab58872b31d1a53a5a8faf0ee710cc84652a020"),
+        from_hex("0x1296f3de85f6a1edcac3eb49942327025104453c66bc5e53503337c4fa17fe4a"),
+        from_hex("0x0b9f0fa8bd9d547076a3a7cd1df3101ac5db06e6aec5cb7ab6d39b4714cd4930"),
+        from_hex("0x03537733ee073b87fd683776edcd014f14d7507e6ad905cfe9912f73dca53e05"),
+        from_hex("0x233f0272e0c4f919fb9781fe66772917ce70d5d4f07a0b45042bc308e122a8a2"),
+        from_hex("0x3059b9ba4ba5d9561c3db928a6bc0d0318a59b5f2dcafacd6ca08b59bb2ebddf"),
+        from_hex("0x2d5a9419c340cc7c51846abbe6cc73da80246ba671af65d42acd80eb26285f01"),
+    ],
+    [
+        from_hex("0x28cbedcf154342ee2c306dfb3090bd8616b12de61aac4ba3a90f5c84971007f8"),
+        from_hex("0x213bdf6a17afa769b5e980c33c2789198bb4e2c6d2d460c80847e083299c1378"),
+        from_hex("0x1856c7cf971f9cf1b9268c77b4ce1cc25e46d598e4063150f21399b1df2c9160"),
+        from_hex("0x16a4784c343b05327e075242bcba3b3f0a1c574b761becd3a548be7bf56c28a3"),
+        from_hex("0x227015baeb62f71b7eb5faf4d53e02aa25319f3930d6805be62186c2c887c5cd"),
+        from_hex("0x2ebabc5d65747d5248a0bd417eb1f08e240bf16ca39517bb78f0bde113756c5e"),
+        from_hex("0x04294c633145298225eea35ae69c38eb16f7e801932977406dc87744fe0dba17"),
+        from_hex("0x20a7fd4cb2f474549d58a9bd35fe437d0ee4779d49d637c7e62bae43e416a71e"),
+        from_hex("0x2407c264f39d4fea0054ee11d49ffa37bc485e6b2a56abaf474c67f519278ca4"),
+        from_hex("0x26d6a590d67fe597886868c5f7b112f562d005aa0108d290856c4a73ae8dc76f"),
+        from_hex("0x0588e0144ff3f7ac60cd6dac45470ec514e8e0b29364618b1990be43844f9c90"),
+        from_hex("0x1655a28817d034b981d68b72ec7917a6bf82f2a997c9d234ea988a7adc885cbf"),
+    ],
+    [
+        from_hex("0x194dc63b0b759bd5d2f9fff9c7d93aa659bddb581ef7fc0a377f3721284c437a"),
+        from_hex("0x27f66e66bf165f89bada48e8a34cf487bb7d9574e6e30adb35a4ee99a976b259"),
+        from_hex("0x08c55d2b68cd123fd8566ebe8abe31bd6029bb9da9dc2a3574e927aa17b584dc"),
+        from_hex("0x0bddc60c6a8bc1ee5ddef1056937f8c7d0062ded8ee5c830d3235c111c4dd631"),
+        from_hex("0x19bd515f624646117bc4ea6976c4b47294a4e20485101c8248531c1cd6801f4f"),
+        from_hex("0x216a8df99767d84fe6753aefa885dbac162f863f528ceb36115f31aa3377011c"),
+        from_hex("0x0d4f51a5e0cb091576eaa21728d47ea6637f81f486d7b8bf8a9598c2d31ebd99"),
+        from_hex("0x1ba2df75aeabd0da93f98257ae2aee8d6e6ed88e6fcf8fc8e38e701c5b0cf4a0"),
+        from_hex("0x2e3b3ff1732fa2bbf4fe2394d3c46ac1f6cbd089598dd74e8e5f0102f35f0032"),
+        from_hex("0x2b8ff63f531c9f407966fca984e8470c0b89e727dc026e14ecd62801c40f5742"),
+        from_hex("0x26aeacadf785a14ff47435fa91e4082d28f61e6ca60d16aa5903e3ef629b5e92"),
+        from_hex("0x0a7017dab6dbca20961c8a03f99fa2ad051e2ed9e19ad14e8e9b2922dd43797e"),
+    ],
+    [
+        from_hex("0x2fcd24c963827617cdce5a14014ea5af93e46190242697ea182ff72b0479d09e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x298413df06c60b6f56e22c4b237c9a682d01e03230c84d0149a6e704c22ad5f9"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x23d87025bd3f3352a2a0c2f4703c9d10d845d00be55597465b12d0fd0c80135d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x25777b7dc170f1990a4537756b084a4fe6ec946b362f73995a86fd753dc5d17f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1a05ec70a771db4dbd3e928f645a5202912c0eacabf5f3e2449ea4672fe6f538"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x157d383a0a40cd84d5bcce99b5238dae37cfa07311b47f441402c24a70a2da70"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x23adce7a7095246ec0acdd4f44cee56e1dd349c99353b35e7f058a490a5776c8"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x28a78a8c74be2917b3f4d925391c74386570bb20504ca07655d9613068a6ae38"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2998c6107b89a079db6a42a6cb1140f2292b1d29cc813571aa8b5b0281ad99a6"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1d6417c714dc35362d500bc5c3e69d5d95f7693801bd976af94a536e33aeab15"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0cb5d2fcf0bf609426936548d3060351eddea4653ca31b1d0bed3479c2d8d2bb"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x05a772552bde1aeca89e8562941bad0c0046da3e1ad46b1783ae34e05c2627b7"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x222e2f3bc4dcec4275d26f99edb2f2bdb96721c4be2736bca2e19e05c8c6cbe8"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x01693e6e0a0ed4ae486d22a84d5f36f7c7ac6c4ab161750cce408dc6d85ba290"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2dd7278a597351e158c42fc05ef616eb37c4749b713585d5e0e37ada091913c1"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x138a7baad9fe0adbc5e15a05afd9f9ab81adcde6632dbd1c6e625ad1a44e7c59"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x27449334c2bd4916af3126cb62f1f65f8e59442723209956190f1b1e12295f06"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x082d5c05f010ddd7c7860ff0842e0dd5f543c66c54a99cc2357ae8fbfc4f9797"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0e58d85893bc856ef746224ec8f775019bbc7a2b128734bbb2ad782998929cd4"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x19e615561f2fccfefc5e7bd8a91d0eec064f13efa39f4752c38414c914af7b58"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x06b0079dcff25e7a4f14cbe0ed7211580ba9fade52413dfd97fadc912490b49d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1cad166c3863436fb3005fe9d47ebd6d8190b12a6b2ed5f0eb938dc5c9a570d0"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x00373c8d89f94b7ec87ca7c5e405246bd56b7543879266bb16993a410835bc87"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0b5ce685b104977696dfc5023580345a3dc8c3ab1c9bc451e1162561e0be2053"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2940a7fdcf1004beeb5eaa033014c933fcda85ff801bcdd6d65593946125b953"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0c194b50d092ecd9a8afb7ee4602ff9ae4d390d28d65c938bc3ee08b2840a3fa"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0caebb769b00ca8bb25621a62869ae11f2c69136555a4369de5de51159d521f8"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0cf3f5150ce1725ee5d4c31c962e728d34735a95f3e46dc37f42ea79b067f4ed"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2ef94a7f698e00d7e534a59ee6c55b0391bb6342f121d3ae61abf3451171b9b7"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2596cea8f3d3be305178f1131b3d0004217846b09eeb2a4ea2d5e3e2f86af2ff"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0ffd09263a4c0040581a28cd752d73abce7eb1b7e28fea49fc4b2e25b8629076"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0b7ab851fcd83492a436725c15ea60b9b496786a656572acdc98a1a5486cd839"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0057e2cab9000c8c2c72ac0945ce8ec6ef062dfed248ac4e11843fce99c47d12"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x20d795b787d04ba638aebb728c9dc0fe4693affa15933addb47ec94fc820cea7"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0fa35cec60bd5f5c96e39889ccc508e3bff3434c69934c1d442739d46d20a461"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x198ab5ed78a09c50ebe7011c5a1422c331d21805dd382ff3e9fff30aaf97ea57"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2479cdff07acb7a61b185d125fe98cbaf96d63c8f6a4a67a5fae4fa082884b74"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x231dffb273a36b5505f6b97fce8b215b13845f51e34ca519025c37dac74e6b3c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0b4e6584bff8c2ef606ca396f8c71de75ff943351dbf3f8407bb06f93e55e226"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x011189898119688cc6d8c088e1300e30734107dcc6c0866c42b40316e619d962"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x17cd979367205c7d09bdaabaa81ebb4e31993eacff14dbbd001ee80f42281d4d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x00f7a32685178282bc522d621cfd3c089fdd9c11e76ede52987c1e6690f2d7d9"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x04f8c40a97e28dc76270ade0e61860b18ada2e01466d08a7eee7ddf1cb77fefa"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1a85ca1e8641b5cd585c41276374daf325f11de38721df3973be90801fbebe9b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x18a54a7a63cae9fdc3a9969abec2d9bbaf97d9ae99896f1610751c7a129b48f5"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0e3ee5f81508929b701f6a244631ce4e8a25c02832f9ae85770bbe9c7a040630"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1f09078f3dab46bb46c659dadb53f98c4586ec718509f222ca9f849455ecc119"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2d30f138068689d3c5bf330c551f5e552911bb5567048621c540aa4ada7fbe66"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x09168af78f1adf4d69e108f7b509c43dafd108c5d87a2421fc7dea574aac44b1"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2f19a431d8d03fbba2da044961c6d6920e5340ac32314f025b66db1acfe82c98"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x04a17fc8d6a57395a5495ed1ded8a06ea45624cd1b85e3cf9c30c841cc7a6c07"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0b1b503a4d99389d807a7cd8cb1e1c7b87881512d04251ba0f9b26668511c8b3"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1a27def64d35b0dc44a00b12d42fa969228082a97e06ed7c85fec0df35106b6a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0da359b25a62ac7c80aeff176e3c2a24f34978042ebc131e0579134bf621a5bc"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2287ba9daf1365ce1ed2f9599fe95e4e19b5a6744c4cd89bd4a9f79ae45f68e5"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x21d1a8123d862670919a26f54370d937ff96c87eb250ea9cb542b988becd5b1b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x163cd5792b124086b78d81e70bf8765d131f05c580fd845065c23491af2648a8"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x099741234d38e4e41b57764791d6fea6e522fc1773cef3bef7e98484327e04cd"),
+        from_hex("0x0d1c1f6b8e9c21bcb44467c303c9c32ee5103f2bed5a5f82fe4fce6a5e5321cb"),
+        from_hex("0x2b46e9453ff8796197fcf96c5e6b89eaa03101fe1f9d025573433a215c14432d"),
+        from_hex("0x08fa33e2e1fd049d1bd92f5fe34e2098a0f56c99097e3f822fa3616fbfa18de1"),
+        from_hex("0x06c6ff6719dfd6214dbe3e9d894fc40eddc710ea85d29b73c5c0f0bbe7689d5a"),
+        from_hex("0x27a5642213df899e80b1365afd1ab8facf86fecb094277d74594384b3fe02eeb"),
+        from_hex("0x21f5c7afcbbf04ac234adbb2edadb98ab1ceda1fda0e33858f2cf226b1cb7510"),
+        from_hex("0x08173628fb47ce14a39d3b1c5a861e03bc76e4f677014fc8d0942b74eea4b815"),
+        from_hex("0x12575f8647b44d45e415c0c27edb5261640f50ab9cea63690ba60ddbcb9446ac"),
+        from_hex("0x241f071e842262bd502aadbde4e0b3da3b78e6315ded09d262c95f747d1e69c3"),
+        from_hex("0x0259650661345bbb098aaaaf58071b5e059731542b79f0e840e782a1b0d7c1e8"),
+        from_hex("0x10e5ea58bb155ad798c9fc610ed9513e72df5199e086f0325b0e383123e3e14f"),
+    ],
+    [
+        from_hex("0x2cb28f0c40f214ed89865255100264cf118e48e1b5c9556476560fe4015a9fb7"),
+        from_hex("0x2b8c2245518ea18766d1c1f7c9cde2dd3520a7eb5a625496d58084bcd62f0853"),
+        from_hex("0x210c52aace3329946c769c65dca979b4fd94a3f027f32d2e2958c646e6a03c3a"),
+        from_hex("0x2acd7e1455ec1ba3c84ebf2bbf2d86ddaf8a12f41b3ed6f4e5ff98bca1ff4d1b"),
+        from_hex("0x0b1a814a47a81eb8464f6bafe31b8b055166e9b871caf5146f999de131698509"),
+        from_hex("0x0c105801d7a64eda3f382f2d225241643e2c2a9007301308322b97c7ddcc5d40"),
+        from_hex("0x26aa5f6576056daf7ae8ae3c87f386700a4e26de68ec1dadfb4926eadf98afa9"),
+        from_hex("0x1a4f0c78d571788fb494cc0797f03a44912c9b77c49274f66e91e71fe25109f2"),
+        from_hex("0x134e5d6245f3a0d6a1ec5a69a2291d66add1d327a16a5f4e8639b492b8f75155"),
+        from_hex("0x11e6ff9bf1fcdb770e96a61a4357bcafeea13b9bdfdf09e738385a36258d5224"),
+        from_hex("0x198b03795e8c98fc132335ed964ddebcbf1741c20cd9b10963e4b1b6a53e12a2"),
+        from_hex("0x27b4e6922a47ddd107c752d83ffd27eeff76b62e7232dfdfa9e9b977bb8d2b84"),
+    ],
+    [
+        from_hex("0x2ec91c78804ce2f0a13cfba2b164db3866beb7720f7252e908eb00966cae2668"),
+        from_hex("0x2488229bbff9d6b36f83b38d4e5372667b52ee88f727e9a715fefb6dbcdf2d53"),
+        from_hex("0x05ebde6c2e83d81fe05780b19506e18e93ee5e01536d77460dcdf7d1f34eb1ed"),
+        from_hex("0x1cd8a0cb5dc6e2b67fc32be19aef22ce977ff484f2188e603bda24ea8dc1da4d"),
+        from_hex("0x085135e4a7a89dcdfd03d78e753a1da01847553706a58c9f2c668fe318050a49"),
+        from_hex("0x1702b88e6d0a6c810326e56a58847cc8065ebb21f3a74466cb1983c00b0a8cf3"),
+        from_hex("0x2218bcb2b68aa0373fb76c33ca3f2c09f01a0d3effc0a0897b0174f4f3076400"),
+        from_hex("0x2ee1d3c964dddfff94daec6e0e02b604903be97047800cc68201428cd6024182"),
+        from_hex("0x0149d8755657a3e81f783350bd1671eba5c066866e118781f3d707450e773bda"),
+        from_hex("0x033818acbeebada683b755443046de5f65a51be2caac0bb8a1a111dab2bf2283"),
+        from_hex("0x10243f1cf06a2eb5d5701347b2e7ad9c088b3bf73dd8799337407c242cc8070e"),
+        from_hex("0x24aa143d475c356688ee0bc6e30e6e0b72587b88525fbbea1f1ac43dad42568f"),
+    ],
+    [
+        from_hex("0x04712cd64dcf3358e37b00bddad2a3c6e77dba77d0b19b8187e7471f1c0fabe4"),
+        from_hex("0x22123070ab73aef7fd56d8f297599fc7078a741cdb0014a72efe31811c756318"),
+        from_hex("0x24a7c252fab1d28fe5cf3754dbcc02066f2054de39a0f2767579f675e52bd7fc"),
+        from_hex("0x09f3fd4c5e51db1531146097185d641cb1b497c96a51d7bd10313cd9e0d7bcaf"),
+        from_hex("0x1a1a52585439d7c78751de23cbb26dcd90faf9306da8b7555206b9333b036130"),
+        from_hex("0x046958b4c608468677dbc3f0833a8d5f440417d5680a2ad23bb0331b9709a677"),
+        from_hex("0x21231b60ee1fce7c57226a45d4366feac2bddeb63b667dc347031efe3cddf15a"),
+        from_hex("0x1e8c25cc2b98496b73a89444a645202da791bb4bc768b82816f402e7f6fc68ec"),
+        from_hex("0x0d68bd594ad770fb7a82ba7905fea85c150b4b335b5000fe05aa9a0aca01b1f7"),
+        from_hex("0x13c9ceeee08c137064fa514c154012813053ca329fa4c8da0805a428428a0ff7"),
+        from_hex("0x23fad6a546ecbbbe75b25184154e6af39e7bcca2b0083b632d70fc1241f6b5a1"),
+        from_hex("0x11ea79fb35b1a82c8bb6a9e1a17cc058975d8953279f362fc4c64944ea6c0b3d"),
+    ],
+];
+pub const OPT_RC12_FIRST: [Scalar; 12] = [
+    from_hex("0x2fcd24c963827617cdce5a14014ea5af93e46190242697ea182ff72b0479d09e"),
+    from_hex("0x10d62cec6a42836f44f7585aa158c59b5abcd367048b5d2dfba1bda5196b3bdc"),
+    from_hex("0x1f415526d362ddf0a5dc91dfa5e9c6c7460fc2abcbc305d25e131c5d9515973a"),
+    from_hex("0x2fd4584f7eb46d9dc4e5e5b2eeb3f5c678b7bcc7c8468b1f86f17ef624100525"),
+    from_hex("0x1b3e6d4d82a906cbe483e2d83ca349583c79de9eb0d1c0dd75c412e06829fbae"),
+    from_hex("0x2e78b361acb147e5eafa421da5efcb3db1476325e589027c78351d24a383592d"),
+    from_hex("0x121a2432a1845b6008073e22de60be50db820a4e318ae3cd0e88b9fc37194587"),
+    from_hex("0x25c7135545e6844a7a5de71fa4758fbd60fa285bc9b6251d2db6fd470a5d8ccb"),
+    from_hex("0x210a83cd89eba69a6e8929992dbf3767a5e82134b40f0634a3a10850afb05994"),
+    from_hex("0x0ea359de79771de8ac574317ef2da059e292064f6de94d45082cf08d069fa500"),
+    from_hex("0x2a200efd8c0ce29350456904c25e12b5f09ee6e63fce6db96f18f280139df42f"),
+    from_hex("0x0e369f9d216e15bb42fe0aed759a9240d8b6de60d2b837a6a18e582d133f4076"),
+];
+pub const OPT_RC12_PARTIAL: [Scalar; 56] = [
+    from_hex("0x24b9e10e078b9da09f63c2f70a936d575ff04156cfa97c7acd6a000445f2aa5b"),
+    from_hex("0x0abe07ffb4c4f3e535f5fa0d1c18a82b80aae5ef863080ee02e5bfcdd2e18dad"),
+    from_hex("0x1fc32eceed7e4fca54ba836021d9bc1189ea1865a067e4f413ad12a49e4601e8"),
+    from_hex("0x2481791d55f9c7067a960d3f90a0a5dc262e02ce8e2cd1ed8997dc00fbaad3b6"),
+    from_hex("0x00ad7cd5e1c0c21746bfead8246e1abfe9485cd7226463395a5431560e21ff95"),
+    from_hex("0x23616db8f0c8a5c50eecfc94fda667a880aef7327ea789980f1f5c0b19d4ca73"),
+    from_hex("0x2fa31539838dc226d55bcd1cb63b3e45f16e313ef0a0a227a7f515bba2841f07"),
+    from_hex("0x256c06a09f576891c38817615a369e21fce085fd6bf1905b93e1706dd533402b"),
+    from_hex("0x08673aaa6a6544b70424c1a8a75919ec7bc0edacbdcd1ff808abb5f46f780459"),
+    from_hex("0x3051a7a5bdaec3c1ab292473584a928732271e36c4a94def6522dbca898c98ee"),
+    from_hex("0x19c0de0e924b677caff8738e79e2766241a7dd33021acc7dd5b3244d48f5fd17"),
+    from_hex("0x0384774c09143510304e06a734cf3e140a35810f09d71c6fdf97df89def81497"),
+    from_hex("0x248ff2a0f3145dbcd3029045acddd2f085263bfb7809f4f90a4368a42a457423"),
+    from_hex("0x1aba903841d0bffd0bfad49ebfd9edc0edbcef3dee527380fcab7619c9ac8fb7"),
+    from_hex("0x21af32f60372e23ffd83257ed8e7f45b7a5875b3822afa3321224485a173670c"),
+    from_hex("0x211cf2e14bc34d14b28ec0de55fe0bf45b5512ea98cafb5a5d19ce6864486d07"),
+    from_hex("0x170100319e6b950ad668692bbb2e47fc3a511cb3b9714c180075a6dca0e9a857"),
+    from_hex("0x18246e5dee12ff51315009920f98e504c9df25f8f58fd44d92ba8cdb9cb37ad3"),
+    from_hex("0x1033fc8770f61e530620f858e196010731d20141550de849034ceb25957dd06f"),
+    from_hex("0x10d8e558a8ac0c48582b1a059ec12fff5f9708c9065f0e053d75e264616d0951"),
+    from_hex("0x1f0987917a306593c6f40ecfb694a0465e8060a1589f6cddb7dd20f5f8017a79"),
+    from_hex("0x0aeaa2fcd9385f3b97dcad7d935fe153f7858a1039527635539451ea4af94f9f"),
+    from_hex("0x11431dad29691fc3814ce43099f6f8597ae60fe78e4c29fb7c9a23d223b047ef"),
+    from_hex("0x1b78de73f40c4f5e989156eb7ad651826e20fa2c555b5793385a7835a8f6da4c"),
+    from_hex("0x052e7fc4a857578a6e914551e0c25fef1f1555c6d1d4771913bb7b4f299149c5"),
+    from_hex("0x1efa64077390a2ca7f1a1778998182e04d9bc407e7abc5876592f4c6b3b9d055"),
+    from_hex("0x2f574c71e784d9b59aded0bceb79e02884f4da13b6ac49b766b5da5b11be932b"),
+    from_hex("0x2ecc632a031adaee4d6598f665866691dff93992e1d749645c67a81553293d10"),
+    from_hex("0x23075c10b99eeb2443981722d6daefa1deed30284352754568198128d23b6d56"),
+    from_hex("0x243b8eef5bca80fd900ddbbedbda61be41d2ed4a4b2abc3cf5035f9b875c8677"),
+    from_hex("0x2f12d5fdc276aa96f4d8c36bdd5c3d783aa7f39205ed3dcf757e385d1eb3793e"),
+    from_hex("0x050666e78b10d360ee25a45f19bdce89d726bf471a523d7b9d6dec93e89c7cdb"),
+    from_hex("0x276e4c9692c2d2a62d54404b95ea5ccb2ddbff3ddcc97a0f85377ab24ac0fc8b"),
+    from_hex("0x271e4ca4a2191995b26d0f35f428305d68218ebb353b4d7feae4ae951f7d799c"),
+    from_hex("0x2cc8aaa2cbd83cef2ebb58d5eb9a0491ed4e2f9e1d1b96fe2101526c35b9df47"),
+    from_hex("0x2e494ba15db301acc8c997ef1c5018078ea5d6d4d067c19053b9c8b358194a6b"),
+    from_hex("0x26f03e6b44f5415fe643ba70f06cab9d33c58e5abc3f91a7a464f6e3d9a3577d"),
+    from_hex("0x032ec8d13416745768e0bac35a45b990916b99fe521a6b8eba847d0a90c5ee7f"),
+    from_hex("0x084eb2381ee2b253c4fe24f10ca61dbe174aba45d8cd0181daf28da4b2128769"),
+    from_hex("0x0e4e4fb1e1dc9a43d65e8dd649388c75f7ae3fbc3560bfe9cbf7d14eaeeb2409"),
+    from_hex("0x061dd80bd81c1f0b71e773fab9c406cf155b4a47a0c507da03804401bf3da3d8"),
+    from_hex("0x0a111c62277004e1961741d580ea56097d525d1072cb64ccbeb4643c149e2fbc"),
+    from_hex("0x00bb6988a945e631442d0ced204c35993169f2ee11095d87ab1b89f9cbedf6e1"),
+    from_hex("0x0756fd6568c0e69c822e9f60cf2de7b1274ef3c9936dadd979a9a026882ad935"),
+    from_hex("0x2a5204d492467415a15632c9b95db71adeaea2ab5a1faa633848a701c010ddd8"),
+    from_hex("0x12dd5f3886f46c641f3cf4a7b479f16d89e5822c51e4c735d8be710b49060f9e"),
+    from_hex("0x0567eb54e084199699171fa0c81b2bcb63aa623fc2f899febe89657d1fb1addc"),
+    from_hex("0x1d2b2e25b04ffebb4d1e6db2c9b8a604aaca30591acfd283a992267e5ba0a816"),
+    from_hex("0x097e2ecc91d3e02b855a8a5ce46a69e967cff577b832f09a5084e2b59675f1f2"),
+    from_hex("0x18786d484a7f63eb705cd319d1322e20eb5b384d46749e1fa749d8ff5e557fbb"),
+    from_hex("0x2c06bdd3abf2d1fbe33740f8af0258161705a7fcc981f52648b9639ad933dcf8"),
+    from_hex("0x017c25d571813a906f8fd16284e2dcd4c8023418526fde2c72f0a386727a51df"),
+    from_hex("0x29133466267c0127288a51e144e65f87ae2b75d6c33165f9d7fff3fdfd59cf10"),
+    from_hex("0x158d7a5e45d06370af86a70654821062451c87f64707245eb9cd44235fd56f14"),
+    from_hex("0x1fa5f27b2df1d5b2b56d838c1f03094e4a13fd9b8e07cbb5f6dc2b8839139487"),
+    from_hex("0x203f434fc75e0e5f1582759baf55f52c1da186477ec2396c51b42d9f13eb735c"),
+];
+
+/// Poseidon2 over BN254 with t = 12, d = 5, R_F = 8, R_P = 57.
+pub static POSEIDON2_BN256_T12_PARAMS: Poseidon2Params = Poseidon2Params {
+    t: 12,
+    d: 5,
+    sbox_chain: Cow::Borrowed(&[(0, 0), (1, 1), (2, 0)]),
+    rounds_f_beginning: 4,
+    rounds_p: 57,
+    rounds_f_end: 4,
+    rounds: 65,
+    mat_external: Cow::Borrowed(MAT_EXTERNAL12.as_flattened()),
+    mat_internal_diag_m_1: Cow::Borrowed(&MAT_DIAG12_M_1),
+    round_constants: Cow::Borrowed(RC12.as_flattened()),
+    opt_round_constants_first: Cow::Borrowed(&OPT_RC12_FIRST),
+    opt_round_constants_partial: Cow::Borrowed(&OPT_RC12_PARTIAL),
+};
+
+pub const MAT_DIAG16_M_1: [Scalar; 16] = [
+    from_hex("0x269aaf7c0e0ae1a709c1b7cd137c366a3ef21c0ca7d9fb2b33b5a1ae235768e4"),
+    from_hex("0x30543ee04032614e317229edfaf3b27da10dd0792f35ecb2fb82a20c30eb1de3"),
+    from_hex("0x017416b13160b7d8d73ffd44efc75ce642f1d002e332ad4bd68469b8b83c5fc4"),
+    from_hex("0x09b103f438a43f1aabb6bc5d3490d3c443d773b966d902d36c81490614939eaf"),
+    from_hex("0x08f9e81ea21aa882da55bde42c830d261462c4489451ab181513614983fcdb30"),
+    from_hex("0x026d2cf77cf485777fb797f7c3bf17acafcb3679549ac98acb6e430eb53e4be5"),
+    from_hex("0x0652442bfa09590b710b3273f0d3c3de61defe08359aa8289b63f36eec1d7a7b"),
+    from_hex("0x0d6e46bf1e3725ff884f82602321db7d05c152349b4cd1117195e5f778f9c27b"),
+    from_hex("0x285754e689291a5f02e4a3c9b07359d3fc33a687a755f842cc45a037774d0542"),
+    from_hex("0x09a4884b8ce2a5dc8eee7e181526dd65567e70aa4cb62c3d128e7d94345a4dc4"),
+    from_hex("0x06af44dac4ca6cc95e692a20907607defa711623ca94934bea9d70bd555a594d"),
+    from_hex("0x0f8b7738afe6bd0d66cb58970bf7484be2c67a4519d1406f074ae165ab5d2ad5"),
+    from_hex("0x294dbe90e673accdcc6d7211bb0ac3aab902a88476ef7f7ac6fe3ba7b128c71a"),
+    from_hex("0x05c3f9cecad533b14bace3f9d7d7713ccf40c9429b4fce2cfa3aa4ee3d4ae039"),
+    from_hex("0x26cbff872ac3df2a3787878f24ee28b6ad4f1dcab41126b80f4038f40510d7e1"),
+    from_hex("0x1ba0b493c987b9c1424ede9239ba100dc005e717aa71ca6d6a605561e379bdce"),
+];
+pub const MAT_INTERNAL16: [[Scalar; 16]; 16] = [
+    [
+        from_hex("0x269aaf7c0e0ae1a709c1b7cd137c366a3ef21c0ca7d9fb2b33b5a1ae235768e5"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x30543ee04032614e317229edfaf3b27da10dd0792f35ecb2fb82a20c30eb1de4"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x017416b13160b7d8d73ffd44efc75ce642f1d002e332ad4bd68469b8b83c5fc5"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x09b103f438a43f1aabb6bc5d3490d3c443d773b966d902d36c81490614939eb0"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x08f9e81ea21aa882da55bde42c830d261462c4489451ab181513614983fcdb31"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x026d2cf77cf485777fb797f7c3bf17acafcb3679549ac98acb6e430eb53e4be6"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0652442bfa09590b710b3273f0d3c3de61defe08359aa8289b63f36eec1d7a7c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0d6e46bf1e3725ff884f82602321db7d05c152349b4cd1117195e5f778f9c27c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x285754e689291a5f02e4a3c9b07359d3fc33a687a755f842cc45a037774d0543"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x09a4884b8ce2a5dc8eee7e181526dd65567e70aa4cb62c3d128e7d94345a4dc5"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x06af44dac4ca6cc95e692a20907607defa711623ca94934bea9d70bd555a594e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0f8b7738afe6bd0d66cb58970bf7484be2c67a4519d1406f074ae165ab5d2ad6"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x294dbe90e673accdcc6d7211bb0ac3aab902a88476ef7f7ac6fe3ba7b128c71b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x05c3f9cecad533b14bace3f9d7d7713ccf40c9429b4fce2cfa3aa4ee3d4ae03a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x26cbff872ac3df2a3787878f24ee28b6ad4f1dcab41126b80f4038f40510d7e2"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x1ba0b493c987b9c1424ede9239ba100dc005e717aa71ca6d6a605561e379bdcf"),
+    ],
+];
+pub const MAT_EXTERNAL16: [[Scalar; 16]; 16] = [
+    [
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+    ],
+];
+pub static RC16: [[Scalar; 16]; 65] = [
+    [
+        from_hex("0x11a8c50ae2baf9f5e8b3c672c7326f002cdec557448fdfa263f0adfbaaceacff"),
+        from_hex("0x062af6c4373daac18754f00ee840e8920e3f715aa7d14233948e54596ac34e43"),
+        from_hex("0x067668742769c8dc002f62ad79b936464ee18b541f9d560a1df662a1de735099"),
+        from_hex("0x092566abceb5edc4fdd60f23c5c605a7b85a6282a1cf7920eaf4f34587722904"),
+        from_hex("0x1dce678af089b569d6b90eb80d3d937f98f952e8f4928a6f6d41c4ddeea8ac8a"),
+        from_hex("0x0a0f0bd983e55175db6027fc884726d4c79e1bf6e6b65eb7a6051d02caa2adc3"),
+        from_hex("0x2ab887d5bef4f2bb90472c6da5531555ab3e5b692c21b57855d4cd77c08b73a1"),
+        from_hex("0x0e29f9015a7443d2be603384abb29dd7bf930d1a28686cfdbc347e9163870b73"),
+        from_hex("0x21b3a5f0e068934a7436d1d28e8cf0bc61d712e158a8bb2c42a57125d8727ad2"),
+        from_hex("0x0e1f8ea955cc9e2efd16f26fe45422f1749a9fd2fc10ff4ed3431ba73ea1c126"),
+        from_hex("0x2130513365c023ad8997f530b8f5077c7bc5408dc8177b0379d25eb46266d06a"),
+        from_hex("0x1996a13e24a307419ef17eaa2120d6a3510e7d0daff70d3b73e1c3da47d9e81a"),
+        from_hex("0x2ae828a9edb591d66810146f3e0efe95336e919fb8924f65807d2e20b70ffe32"),
+        from_hex("0x2f617fc3ddba10cfc459bef0adcb6dcf024b50924f5a9719707ea9cfaceeba86"),
+        from_hex("0x26034af9d21fea59b35e1edd6bc6ad24e39bb2309014f2df434b01d52360ad6a"),
+        from_hex("0x0861d9a9b50eda74e99bc741bab3c8d7c36e4dc3828ef88a8822ad1d97cb2dc1"),
+    ],
+    [
+        from_hex("0x09d307c6251380f12959318cb1b6b6bc8b5f0e87c8aaa5b9a9c02a747f82230a"),
+        from_hex("0x2d7249c015893681c5b6406a5f3ed9913181fa18cf0c9cc5404d002612e5f6ee"),
+        from_hex("0x300c394ab0169e3579b604d3e4c8344ffa05164a7fc66d1d0fec5e1b0aba7c3c"),
+        from_hex("0x053246a30507749b78daca7f5e1bb40d8dd095a228d4754f587a522ae3bebc5f"),
+        from_hex("0x2bb8c89e9959b6bc4af0814110a58e9f2de278cb4ce3a3e85b5df69f20bc6c85"),
+        from_hex("0x03d087490008dce2b785898ea7d63aa073e5b01645c59c49ce136275cf5983b5"),
+        from_hex("0x1b1860486b80b33467e250e5fee700e42ea8b1010ae9720e0dba074d0a711483"),
+        from_hex("0x07c2df19e33502e0d6407204240d9ae9d53671a8acb8275c87a878c8d31ae6a9"),
+        from_hex("0x2681360e637f307e9d3dd46d8d9ac32d1eaa80fb008a164c495cdcba8f80575b"),
+        from_hex("0x26e40b31dea8fd07591fe4cd5046b8995ad2b38cfff12bb5d9556049062f8c60"),
+        from_hex("0x142db0b3205d81b91e289e8640447ce61d0a5624e7a80276b2aafc6c020bd45b"),
+        from_hex("0x11481ae8ce1f4453b29a23cdf06bb4da1344fa0858cc366eab6e2871f6820bbf"),
+        from_hex("0x14afa414edfcf985dc050516d4e6e6f61b32fdf2ec6faee7eae103be602c2d9d"),
+        from_hex("0x07fcf3ec2df0db6971c71924e8adb1f96f8d53fe7de3676a7704f621ac470f5b"),
+        from_hex("0x21daa5e36df00136f54419d5e4c09ec09bba58903173c55822e51d65b0779e6f"),
+        from_hex("0x2e324f627abadd10f08206430befea7ae4b5ed5e965ec1380af6f883eeac7d3f"),
+    ],
+    [
+        from_hex("0x0b5bb384cfccbd7b191e651901f6193f949a3c8a226cec1fde2a43d8829dbe86"),
+        from_hex("0x069ddb007faabaf73350dc4eadb907bd2d0d4a214f6654b30756e8ad18df5f18"),
+        from_hex("0x208b465f89b447783ea3fb14cbb235891ae8c4e5a5031365f470bab6cf86dd3c"),
+        from_hex("0x136297f9e831fb9640b024db98afd0a4eba714daa31944c4e7613dd78fc7e14c"),
+        from_hex("0x10a7859a7db68fae99f59a4a784cf518f4e7b9cf94ad7aadfc8b089f3b3ed9e8"),
+        from_hex("0x0862b4da27d415d0ee6ee421b3dd98ecb3188489103fc6028684d166a5212ebc"),
+        from_hex("0x233af2967f6f7740ff8feb103ff33416f7d2a2f08aeb48e6a84347789fa4404c"),
+        from_hex("0x2ae069b5948ab8f0ab759617732fabf28f734dfd5732f7f22d0797078908d592"),
+        from_hex("0x00583f3b42880263ba0069d278a8d6c633913f930510ea5e8bc4fdb6e0d654b8"),
+        from_hex("0x12e2f29632a6e47b536e42912519b2f5f6086fd92ba211af7776be12b342730a"),
+        from_hex("0x06dbb6c7b8448c820ae128612b7fc4698a74cfa8bb058a90742d4188b3063bf1"),
+        from_hex("0x18509380d353243decd53f8b29e3e830dce894b5ed590eeeab18b1b92f3a6da7"),
+        from_hex("0x0c6fc99e5d3a8ba836d2a51632a7efa4a2732c5be32ff1bb8988e97357cfe561"),
+        from_hex("0x042196cef447997203de5aa95cd5a8158ddfb11a8ae83c733cb3ce942f1e3952"),
+        from_hex("0x2d741b558a9a39fc48442b13f7a09394d878d41b7963105175b5eacc0762d341"),
+        from_hex("0x28561383e49fc55b465f9a55c56872a9da39cc1a8601c984fc142f519e01107a"),
+    ],
+    [
+        from_hex("0x283c11ef394149faf2dabef893843ad8afc42d8322002648b15c7a6b2f8e36da"),
+        from_hex("0x16e5502b7577231018c8b8a59b3d9d60952d4e169fa38a080ce1860cd771ea5d"),
+        from_hex("0x1dc343ebb1999bdd849b519b6c88c25ed40fa673b44a37ae895f1ee2efa97458"),
+        from_hex("0x192b8afb63d8d9357b5d74136d341c2811f611336acfeb20a0e4a83fc726cb1e"),
+        from_hex("0x1a30b60940afd0871265b329ec9ae86a61df686cbb09b6b57adcd2eefbe243b8"),
+        from_hex("0x20a00f04fb239f151d607d4a38fda2ef13fcfdf2cb7290c86b19bdedae4048fc"),
+        from_hex("0x0293b42083e8d2ae737e112e012df4927fa61a56753e4cd9ada1690e5ed529da"),
+        from_hex("0x2db103a89e5cb3b42ac01913fe5c8de3cfe959ea6033bfc3a17451906560f807"),
+        from_hex("0x1cb81d2dfa938c9397a2da95a68184983ce0fff321071470250b781cdd80c73c"),
+        from_hex("0x03f1355c9f18f37d7837813b19946787301f68b7b3720f02cd358bc50c9ad216"),
+        from_hex("0x13af73a19617a92625a1c30743bc9775a4e8b0b9f3cef088f39bbccf12caa3f5"),
+        from_hex("0x114938f09f618d6176cff87ec5d1532a265ac4a87e21d68ee61dae8d9a554924"),
+        from_hex("0x0acb455d2f8dd661528e732ea07ef93c9e03630f51903b86bc6835afcc03a98a"),
+        from_hex("0x151adb5dd2d8fa77437afe73aed319d84dd546839f9d7b687e825d9b23dc1744"),
+        from_hex("0x25548523d039ac346af19b64d52549e24dea02ea0d5476a0395c4c438f613464"),
+        from_hex("0x09cc75c22a37ffa264db2540ae85d1ef6965111461eabc9fc652a879b4bdd140"),
+    ],
+    [
+        from_hex("0x0f1139934f4fb0ff2f4bd0b7d28544a1c8938de92dcf965e1a1781c86aa8e6c2"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2b6f9a7fe52ad45ae8857683ea066cdba3f7c3dc7da78411c83583070ac12d47"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x18fcc896be2e9edcfd06d0ef23c523956148f3abcffcb0802e57c65dfa7eb6d8"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x13ae7493c450aec181999c3fb9c0b4d4a5a595672b2cab4181a5b52ec2fccf68"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x21376c58b83138f960981bcf86f680c79081c95f6914477fb5d6cfc7497c1526"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x142335a55e77462aaac6aebd3f92ccb12e15204546af29eed4f5e73600a4bf8c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0c5ddae16b04dc051c9f419c25b7f6408a3d653ebda349ce9332593b371fdf33"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2689b4678392a84600cf0b6fb25c9b35c065b6958aaa056ae8b099fa61e87b75"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2c57afc39ed2d8ec9b9cf1c685f7b73c0ac1d0540460ef91ee30e56b84607be5"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1b78b860308b6b50845c744c96a2237fc6ea46a05d4884dbe8a5e797432c269a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x13cc94f496327f946bd5bf504ab86714bc345f836fcb9b1fe307c40d62c3be3b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x24d6de093538a86baacd50c2ba65c212bbfd46c88acf6adc911e678bfc7c1014"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2258067f017ee12ae23944f9b71c0e8f39d165a22995c8a12a76bb340c853aeb"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x14b3d102f33c05e6ea23d0159d2bf879ca8bca2ee547e25bde0d3289295542fc"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2ab89c7fffc98dc5ba3374ee0ec8c58607e45015b08cb6d13d282d13fdcd9cbb"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2b7b6c147957fe1e074237cb272f8b38fadf548ab37c7cca248c2b6e7f6ef184"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x22e4d3e1b886adcd0f5b0cf73c1a9bf1d3db30305651dc858b75c2c22c1b4b0d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0b68fa9b0d5df7dce1621bebf659a18285204c82effc3a17a52c8f869a04386f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1634373a4427e6ed6822633c676f76244b7323df66094f7dac02febbabf93ce6"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x161816eae4a5a59647769db36bd32d91e36a4d2881180921c6ae1c774da8a88c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0e8dc7cd6f5219b8f0a202d5d9142eb80144fb4945777175cc21a5de7f93734d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x125c7c9c18bfc84d8298305590ffbb6a6ec51389eb2678522ba53dc7b6a9c989"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0fa705e5fb58d754efb803b9c24ded86fdf44f0b9027858cb7468889b9b793f9"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2db7b16fa0d8cafdb13856e67e232531b6b64b181717b56e918d895d1f6de779"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x20ee21a8e99de49e0f23484346d51ca84c763fd3bc42ed49302ce517efb7c3d1"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2464170cd57c89626cd9f1c25ea604103cf5c7c6b6384d63cccc2539fd227b10"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x18d92ee6e4ad5ed8671a3a902e471b7b17423a0a123a069f5ce724d219900e6e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x06e7eb25aa2f77c9f64e6e997569593b1b1aada9d97b4fa8d5aa58eaca28ee3d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x245f3670a6e3be9104fc999f977e05dab46a0434e682272afdfe33b431489422"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2338e44c82e527c3f9b4064e9691a01b01426c264a5eab102627ca1798a96731"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x025ba964c43c4dd03b90be052c367b5988b0f613d67afe23caecb7535b02d8c2"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x227a926359ecb99dbf2fbb5bcfeb66b443b73074de47f75d5285a63d5e9bb8d0"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x080e987035c7ab8091afdab9737d67f7b29d5a055cfe3e80da76c5ed40036fcd"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x287d9727fa9787cb13d2c6c4f861fe6948ce2085e817b17f2d01c7ac5d9a71e2"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0cff9c7671547d0403a7c6dd36a818db81d84f73c6618b28b9adc117de6ea286"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x231cbd6dbd1339319bdb4549090f7f907c46f520aaea6c3e669b1b933bc978df"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x003fe17bbf0d32f5eef248969461584ff7718c1a84e79daf88c813868f09a10b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x13768255aa238077f21510e708027b2a9379b9bbbd34e832220ef0c741d7bc76"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x19f3f23131739230955fa717eda73f9da0bf596b1dff0c23ed24cb2f95044160"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2b24106b3883d29d76325e0ea82d0e34c742b992fe78f91e09c7e308e8d37ddc"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x000fe2d40c9e86c9d35e148a06ea4a3410f29e7519e394259e0f6afbfb6519fc"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x27ae2a5837cf9c79ccabe4f7f61252ec8e6bbb272005297cca83cc40b4110e89"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x15684fefda12b32ba4873564c3502a67a2076134f71ff66f4e2ee5315b02a19c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x21045f6063ec9e2e6e7040c0949a46235693805af8e75a29760dd49455e61dda"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x00ff37a0311730e3ce35c3ae5467f47401add02962daa4cebcf2cae53085eb10"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x12a8f68dad75db509547b97c08699ef05dc6e7a871553dac62f3d9bee87e14dd"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0444811de68c064af36942e4ae9059284f0dfe86650c72cacfba8c8a06e920ff"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x21c1763df1a7206705b65d0942acec0c4cf61e60782ffae540b6c41dd6a43d50"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2575340455a05474d748608031d21ea1502e27c6fd348f9992cf8881c2d81e69"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x08f501b2028eb549372061ad3423e3f0f71bc45b89870be397c5c5e01b19760e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x05b62275eb9fa36c94136efb0b3767bde46ff1d35b811759aa2b54c90dabbb54"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0519ec5dc1a9b538923f0c3354603bd0c7d7acd9b9ce817b70bb98acc4744d58"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1549a0a9856793b1fb73049189f34b2e0ed9e08c48875359aa899dcee91e1e82"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x06191e3ff7936bd97b948b095a6bb2e458b33a4f2a731b5a06c347f1f6b46d6d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2a3d690c70b4930341afbbea186b0394a3a7dd7d911f8106db6c96476395f25b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x04d39a7a0b5f372041d717d422dc3b862a8a8b6ed1a174efb24b923c9505c5b0"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x083b47892d403e287bbf80d319866f50adc5a5b50f81f1708986078e64065b26"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1c4b724162943f08ad657557a638bf3e7bec8e57d5db00b9767682c8213132d8"),
+        from_hex("0x2035614227e301894056bf197033ea74e568e22a4644da4234fad6010f9195f1"),
+        from_hex("0x2867e28d25a0560e620af7e734e3155528406285834db300d3f88a5c63158545"),
+        from_hex("0x2d9aba98839b67ae817c673f35059c574d229529650b0b718e0a89de46bc9b77"),
+        from_hex("0x2620dcb35abd4a2c1c34219eed205f8cd095a96c00cb66dbe106127615562823"),
+        from_hex("0x189693ff51f37b66cdb414147465ba7aebbed3006853a8b6695b46d088da9f9d"),
+        from_hex("0x0dcf0bb67aa309914b0aee36c52661723c0b3af0abef68d43896bab66a111d80"),
+        from_hex("0x207abac676cab81bdee421468577de3d55df734bcfc5e7b0966c7c05f6676ed5"),
+        from_hex("0x072751669cc40c66a1e56cacd6a57ebef404b8089d8bcb827bed335b3a81f68b"),
+        from_hex("0x2dd0616e2fab6505dc6a0ef4470139d33dace70f7102a179c6c5f45e197a3b31"),
+        from_hex("0x2b6d0ca50c9b10229774a059bee1fc4d090c879bdc49fd79a6e97be85de5421c"),
+        from_hex("0x1315947727a368a3713ac6b5968cb50ae30ed2f26546062eb3be935284b2d7ac"),
+        from_hex("0x2c50018a609e805dd45428015180eef905c885d10f4bdc57bf822bcdbb7af7bc"),
+        from_hex("0x1a51c15bbedba963fb108a0b5470851f0e9896f6ebb1b3b6e51ed5c6c5e26ed4"),
+        from_hex("0x221a70968ceadb2b1a3949215db03d70457982d2e5bdce6edd224837ed952757"),
+        from_hex("0x11536753adfe665c99ccb6a38cbd9d06c3c7b7122b51dc063628a669dded0b36"),
+    ],
+    [
+        from_hex("0x031897f30796e50a12b212bd146ed58fb50b443ae9a2dbfdb4f6b8fe5c61479e"),
+        from_hex("0x1f56af932c2012a38d77352c6ddb68fa13c1c9c14ba96fe7e6d170ffed0d759a"),
+        from_hex("0x1ecd95dae8cb508bcf72e6584898d2108854749c3cc686e4b02f8fe57aed95df"),
+        from_hex("0x02fabaa828c27c721f68c33d9d2b881210704a41d9c28f0a4af02e67fd345caf"),
+        from_hex("0x1da2fc3d073c37c2921af16e44330ee72caec36643d75e766c7437c59b12d6e4"),
+        from_hex("0x1ee20f40ea03cb4d5620d4422f732f547c198e40fef3839370868c44a0eb5d17"),
+        from_hex("0x1c79db7d2a94ac6cc15285a8e5de39e4e93b574c4d7274e08b5f27e98c96a9cc"),
+        from_hex("0x039323133de3519bd46223b43f8905772323e7111b398cab542b4fbdbd4b4d8d"),
+        from_hex("0x02e21c346778fed85751f95cca3da1e67f96fb5d4c4a400ec81e324f99ce88e3"),
+        from_hex("0x174199bd6b9babd9961f9076e8e09c47808ea337a2e83b4b80d7685e1f01912b"),
+        from_hex("0x29a0cc2bd15ed76a356d76fd2c33cc8df84e81068ac799e8a9eca0a8e2836d14"),
+        from_hex("0x05136a95e080ef56c23c099a7f8569667584655eb8d8cef8dc46ad90943f484a"),
+        from_hex("0x2820e3357abf5aeedf9a457603a627e9bdd807df2734bd6e81051b009f13093c"),
+        from_hex("0x205e6b631bad8731629ac0af44645716f0f9dd549d7be0aa4ecaa82fda20276c"),
+        from_hex("0x1eb9ab4dd7426e30f809c9929f84ea59d6136daa5f7022847ac8e01f04ee91e2"),
+        from_hex("0x2d89f59253f5b9a24e0188c1ffe0fe6d1585c30c019bba8b4c13fb65441d8b0c"),
+    ],
+    [
+        from_hex("0x2bba2d4afc396925d03d39ea49fd7d23174a3ee023a3625b7d937936b78496f3"),
+        from_hex("0x0e59a58b602d4ca9e2ca2a10796a2356188a39eafa39e5525f53f560d3349cbd"),
+        from_hex("0x1cc183780561724fe8db9d8f8c456c32c8c29556c12c0157ef8fbc9f00e789a7"),
+        from_hex("0x054120821f04fd778dde8968f55ba1f8621a17aac4c0dae7fd3e19b5ffe7ed32"),
+        from_hex("0x1f02893a858c61d860abd638710bc13aa6ae1ee2f3227513c92378c9d74d04a9"),
+        from_hex("0x0371fd918bcd2d93ca93864f1dfb8c851c2074ed5b06fd1339ba1eedd20a4078"),
+        from_hex("0x1f3fb61afc1ccc181b5f33139f59cdbee76778726b9a73fbc196e745afd3f10a"),
+        from_hex("0x1f6ab61feaa716f4311adca796e444e9636abebb501dd7224692fdfa0d64c9fd"),
+        from_hex("0x0bbc08a17c0f31e73a0049d3464897167566880bbc884eab974c63b37e663589"),
+        from_hex("0x2f6e6c68f97cffb2035d973014678081f8a8f0fede19b7d5702d6b5e368f265c"),
+        from_hex("0x10eae0663059eba3e2b842c9076076b5241b4977dec5912f145d891fe58e9b93"),
+        from_hex("0x0f234bec6cc14051fbb4ebaadc99aaca497c1c2d5fef51e5edb6e8174fc737b5"),
+        from_hex("0x11d7a67da5230703f013ee574653d7e02dcb7fdf07f39f21410fda7ffcb09f9a"),
+        from_hex("0x0fee562c5444f1e094b4a6bd732b720771f9ba98db7dcf8231b8d17646843432"),
+        from_hex("0x0a076467e3a9603a7763d3202616302c719e0cc05870d0a8458842e30ce047d3"),
+        from_hex("0x1c733d34815220317facf6f54ea32006e2d3986aae8c72add1b2e1196234ddf9"),
+    ],
+    [
+        from_hex("0x186d4f686994b1e7790b2ecba770c373ee1d70fd63b71073e65ecad88dd5a3a9"),
+        from_hex("0x12d1d277d3ccb4997d6cd545981b73bb48fab183bf9de1db16e0d1cbcf44c313"),
+        from_hex("0x16169740abc733a5753234257d3189d594d1ee5b2ba7ea9445707842d6805bdd"),
+        from_hex("0x2566d99b2fc31cb583aa146e9dd39ccc09436a45ade8a57c9f4c2f4daf9632b1"),
+        from_hex("0x146f2ba6b24e1f31962cbadae6b36fc4a896cc6eb99d63d9859a2a4f91aadcda"),
+        from_hex("0x2ad5aee9f6379bb5e275b6c2ac947fb0d54216c85a6c786b2b5ba133a1ce17ea"),
+        from_hex("0x2fcdf08ef110e18c877c4e7e28c4f902bface389a0b955af669065d0766d83a7"),
+        from_hex("0x2d9f57de99fac20c55f402f06d9c9d3a8f49d62fb106e4dd8cca84cbd685c06a"),
+        from_hex("0x1c039fc13e4161998ed60fc909e194ebceb1e7a4ee755d7201ff96ecf3632e03"),
+        from_hex("0x17f2ad6ecaaf3e5db04b6aea8d17bcb58cfe4a1024ecdba3ef5d57dbfd020202"),
+        from_hex("0x196ec1e27eab458b378961284b6a98a6766cd6b379b822868715e826e0a21406"),
+        from_hex("0x109afa2d34c4fbac99becfa70f20b2087f7158413a02604b386684f3f848a896"),
+        from_hex("0x148bfccbaaf2f7e951ef266b8c11a8c19bfe013749c38f5bcfe608d3282fb37c"),
+        from_hex("0x0a25d11a8d1ed5c87e03b684b7b6d34140063cb4f77e312ec5ac12da98911e40"),
+        from_hex("0x2479d850bb1a9b9143f126da89063a8a3e5d0a9d5917e2bfa5927d8eeb70c526"),
+        from_hex("0x115107e62902f6facbd8a1caa2a2f67a15301e5c034cb99c764f6402ca331781"),
+    ],
+];
+pub const OPT_RC16_FIRST: [Scalar; 16] = [
+    from_hex("0x0f1139934f4fb0ff2f4bd0b7d28544a1c8938de92dcf965e1a1781c86aa8e6c2"),
+    from_hex("0x0a8ed5ee69bc82b1a41a59ca45701ab9b630542b60197dac9235abf72b039df3"),
+    from_hex("0x09129ac37809e6614102e28444ec2e407597e84070ad4144b9e793b1a2e017b7"),
+    from_hex("0x07e154a670335463fd243386ec19885f2e2c73ded91464f8e9c608f7678b1efc"),
+    from_hex("0x03c2f168b82b213982f2eacf161a2c51462fce2d12155f9c46b1938a640c8b5e"),
+    from_hex("0x141b05dfc6e36dad161f1ae5acc3f6898a4636f9fd2db0d31f9a8bd6febedac9"),
+    from_hex("0x13c6d843b45a5726b2c9d3091891f9db698f556ec08833dc4775303f19740e54"),
+    from_hex("0x1a770f1822398939c231a8ddee99867fa66377aee18e276a58c6e523163ef5f1"),
+    from_hex("0x08a9fbc2144f31e1c9a91c3124a99dd17e9d982258a2c17fb30d353e174d155a"),
+    from_hex("0x017a6851613aad1d5316aa47abe867490f79b8d28ff831d347e7382a5ec3bea0"),
+    from_hex("0x1bcd68efbe164ffaabab087f981fcbd300814729a8578afb4bf8106485ea668f"),
+    from_hex("0x0b7969a4e2503404f187889100961080470adce87271ea98059b5f2840b8b792"),
+    from_hex("0x0478a11abc760a458113ca286b20bed168e53d2249316db1c029aeda60cdecf6"),
+    from_hex("0x276b764f039e232980bcb5961c36183ed01fcabe2763104cc0feb021470989c9"),
+    from_hex("0x1d0befd490ff0fed51775b3c241ead034c5e536fe632779048602e3f9b7e4975"),
+    from_hex("0x236f8ee2f1bb0152ac2b853fd0102df5390cdd29d341118fea56180cadaf15db"),
+];
+pub const OPT_RC16_PARTIAL: [Scalar; 56] = [
+    from_hex("0x1daf01db6e521791ec2065d07fbb1e466f4cd9ce1dd71d3bed5f5b57d27baffc"),
+    from_hex("0x2a26f3dd30933411dccb84f022b98fde4dda84510ee25210e5aa594b162a753e"),
+    from_hex("0x0edaf7d40e6c587fb7d63577b9c2f18384e5231ff01fc92c477528c02beb0066"),
+    from_hex("0x0e70fcac46b8b56f2b23ca0a7d879bff941c234bcbe17cd21df2f7730dec8012"),
+    from_hex("0x1939ebf4716ab96a8b0aef3f5f8524de22248c639503cf7254fbcf24e1dc047b"),
+    from_hex("0x249426adaa2f924157f21a7d04b306043e7bcb9dd50054c08143527a842185f1"),
+    from_hex("0x244ea331a729fc5aa994a6537eacb26fb6912d05cb0ef192149b8879885aa47d"),
+    from_hex("0x17cf2833680b27e300f24b0080546f566ee0b771209db1e383ff7a4cc6e46223"),
+    from_hex("0x27993c1dc1429be1a0e91d1359c0db3f25e2765a0c7ae71553510c10f0e3f2e9"),
+    from_hex("0x09b9b4efd887a90dd84016deb0411a71dea1038d3a73eddc149f6a4b1bc890db"),
+    from_hex("0x2eb0a25c2e4dd4033fc52bced6a7129d14ccb8ca93b0e0e089e6ce7e974fa2aa"),
+    from_hex("0x18324f6a2fa37920f72347998af6eb01a0237fab3d30d3af0348d8089f19e6b0"),
+    from_hex("0x23334e5535434216aeb4bd6a3d79eb329e1c3b10af0591430ea669f3b5c274f9"),
+    from_hex("0x052d0195e141f65d67a9a2c958c30f821951ea68d651ed7662defdcb38e441ea"),
+    from_hex("0x23105b28f5f20e5b72f9f3ef62c50922e401d30393a007b39d37e66aad53077d"),
+    from_hex("0x2ebcb1541c74731f5c4dd2cdcc97446fd016e207edd665bd7a41d9da81686ad6"),
+    from_hex("0x15ac2d819f1f3ab31df018cf4307000a3071d6c31f8a79bde1f6fdd0fc32ca38"),
+    from_hex("0x07e4824543b2d788c9e30c6b21a8035b04152a2431be905269f576bf12036a41"),
+    from_hex("0x141b0ab9ec38ac88e2b80651a230c4041200d669df7a39f5b670e83b745802a1"),
+    from_hex("0x1c7908069da37f10346c3385e7b96d8baa41a78bc9df52f2faf5c176acbce4fb"),
+    from_hex("0x1a41e464d60566b30211796c8c3ff3829a39544fba6c749958be9316acc08861"),
+    from_hex("0x21b6801e746419115e029e30cf7f16b315ca50c790777aec936f6a77972d163f"),
+    from_hex("0x0c41d296bcb0122d73b01fc3e5a22ad313bcae69294360ade29c35360c6f593f"),
+    from_hex("0x0069b76945e369a8fafd38b5bf963cbbbae5cbd291abeec6bd26efd80e3298f8"),
+    from_hex("0x0b907745cac9806c59f3e7b6b4539b7f363ce736812db1a567d134bf1cabd0ea"),
+    from_hex("0x1b0c0c22b7870a642b98687c59850cefd32e2f700f61feb93b1b071df23513ec"),
+    from_hex("0x2b11beb08057bc1357468bd60cf6b604ae7440bf5a01679e21e533925e18356b"),
+    from_hex("0x035925fe1deab0c6eb8fe82b1864bd46c72acf82adc878fb69ad7ba907cbe3e7"),
+    from_hex("0x0d9e875e5c2b3d45239a179f26e8ab3fd063a54431ca44598558507038bf9270"),
+    from_hex("0x1cafd0f6912ae27a2ea3cb383e93680df24b602665da9c8418fb355d89c0793d"),
+    from_hex("0x010bd373b6cbfddeea8bf1caf4b1c38a09411d2068d108a336db9ef759bd11aa"),
+    from_hex("0x2b08e21919c02fdd295e50be2f98ffdc264725d80914e20543e2f083d50e4ffa"),
+    from_hex("0x302d056b620f74dced2cf4650fe8efb21f80f454fe92d1d38761387b84b2f8ca"),
+    from_hex("0x0ef884ab523299d22d1fc5b32e25b31106cc1a62479af36dced374f682ac3bb0"),
+    from_hex("0x0152a94a3904ac6e3060506c229689eb832c1af290a396cc467fe61ac2bc0b2e"),
+    from_hex("0x2db8dd09fa357cdfb5532dfd7ebfe7a6d3668d45c299e606b9ac2891736ca64d"),
+    from_hex("0x165b660a1b98f154949d827bdef3ef7f20417d0d674ad72133e2fc8707fd2ff9"),
+    from_hex("0x20b445184b7cd5754007e78a2ea7d4727d6771d8518edc36682e50465f5fc882"),
+    from_hex("0x02b87d66cac1379c902ad799a6ef7d3c8102dc85c0f54dfd7eb37815180fc4b4"),
+    from_hex("0x19959d78f39f52d7bae92c1ba0be0b051b3da5783fb51a96e92d96cf86384a1c"),
+    from_hex("0x2d9b932d829dad06292f8e4cc8e0671928988cd56037ce46e29f55aaf21d3ebd"),
+    from_hex("0x2c756a49233d974403366d7bac08251063a2b6b935a8214f77517058e2eb208f"),
+    from_hex("0x1fa6dede97aeafc488fd1cb4e7c6e8dbba63f2e55c672568500e2a36ab7fb608"),
+    from_hex("0x024d11a7b35722b64af0b68772152f3bfb77d62c66c5cd04befc2613de9f3449"),
+    from_hex("0x2ca33f4f1564663d5dc20d0f4e21afa326958ba7ed24f38e762922c66db13e2a"),
+    from_hex("0x2653cf6eefcb20e359ded2314dd054633d9f5e197ed332c6707e215f93cad4c5"),
+    from_hex("0x1aba9e3cf896fefecf26b7044706a1990ac0cd364d873f25de02cb92adeae973"),
+    from_hex("0x2a3c211cc02662f4e9f79ef2f684d7d39b25762a4779281c6335b0f535495375"),
+    from_hex("0x1a9f5b5eea3c269c44d7936ac1e3440e5fb52a9224a9c8bf1cfb095b78f989a5"),
+    from_hex("0x275f06844eae522193f850743f3db863a5d6c9fb7c8438c2f8ca999802ba8dd1"),
+    from_hex("0x2e16b116e860c54768abff3720bf3faafc25474a92bde775c0fba99814a3c457"),
+    from_hex("0x1000887a91e2cec13152ea83abc4d8eb98cd3919d69780487666ce6bc8f7a482"),
+    from_hex("0x263b54f36e8d79b7bcd4689b481b594d334e1f6aefb2d1746b33b80e84cf715d"),
+    from_hex("0x1a53ab6f2729bd5fa8fc1622e12f7b63d43e1f5fb03ba61d440fc92d6a546621"),
+    from_hex("0x0940266e8e594614552851dbf63081f2f35e006bd5f0d76ce1ff7e6946187fb4"),
+    from_hex("0x1696a619de4ce80298bd9d6b13e72d5a2e7da13b96c38228bc9f5a981b91ebd4"),
+];
+
+/// Poseidon2 over BN254 with t = 16, d = 5, R_F = 8, R_P = 57.
+pub static POSEIDON2_BN256_T16_PARAMS: Poseidon2Params = Poseidon2Params {
+    t: 16,
+    d: 5,
+    sbox_chain: Cow::Borrowed(&[(0, 0), (1, 1), (2, 0)]),
+    rounds_f_beginning: 4,
+    rounds_p: 57,
+    rounds_f_end: 4,
+    rounds: 65,
+    mat_external: Cow::Borrowed(MAT_EXTERNAL16.as_flattened()),
+    mat_internal_diag_m_1: Cow::Borrowed(&MAT_DIAG16_M_1),
+    round_constants: Cow::Borrowed(RC16.as_flattened()),
+    opt_round_constants_first: Cow::Borrowed(&OPT_RC16_FIRST),
+    opt_round_constants_partial: Cow::Borrowed(&OPT_RC16_PARTIAL),
+};
+
+pub const MAT_DIAG20_M_1: [Scalar; 20] = [
+    from_hex("0x1247165cd01dd4862218b1d5fc2763ccef99c8862622d3ad701e941ee2f69495"),
+    from_hex("0x0550cc46e3b0f134f205fba05ee78486f32912d09ebedfd208f6c6b7f2c20e6c"),
+    from_hex("0x0e64f1a01c39f39cc3f36f650cc8fb1cd2953a70d42f7e77350b09b5fdcb3ca8"),
+    from_hex("0x1897574c744c4d0f8993eeb6d9e84186d52e9f104349ea8b4fcf032057dd211d"),
+    from_hex("0x13b1b64b9c8bf47332ffee1d277750f2c93b88768b704d8e50147494be36ed0e"),
+    from_hex("0x08f51872038cc8a9f45bada1a4780c769e0d9792ef9f1c58ea61d098fbd186bf"),
+    from_hex("0x05864e3d68e35e75f4ea4acbf59ce96dce5f6f8db1b2c5851ca773bf99e9a9e7"),
+    from_hex("0x10998042ee91ba72bfeb0c67fd5ef40858e236f08c48775195ab0493af7ffa19"),
+    from_hex("0x0177da84bddc5376c05c3e996b948fba0ca8d28998c0a95b766390409c6f6345"),
+    from_hex("0x01629675560e6992da4033a3cef2b2d6128632a1ac797b5413410df71286f8d1"),
+    from_hex("0x2b6fdb01117062f94f552bc106aedf2b19a22da527faddfbb68ad34ac0f1bd8f"),
+    from_hex("0x0ec779c16957dadf4522111a89b4a59522cb90e34f71479ae4611305d6b50fd2"),
+    from_hex("0x1451a61800581a1671c28df35ad7bc3529ec4b87ed077bab89750c54e1ba0d22"),
+    from_hex("0x0318bc2e377c4101026a5999d231c96bb9e0ff0cb5683a852884616bc65fd483"),
+    from_hex("0x0f928373da306782729cddf7d129adfd99683d4cb30bcac2c1e30aa5dfb9a9e2"),
+    from_hex("0x07dbae6b2f71af6ab8f83180f83c03f2852b9c7792fd4b379c9d3a08cf2fa153"),
+    from_hex("0x0fe65251190e461054495e0e5efa66ae00ae94a88318091073a4646333f79791"),
+    from_hex("0x305490d0030fbbfa1875fa2e277aea28f9168ae0b64f11328a34e4f8109e4b1a"),
+    from_hex("0x02675b375e29a0b55a50b2723986b9456b00a06d94db08dffa5cc6f9f2a5d510"),
+    from_hex("0x2d8b54a1e6718649d4d8eafa4ba0dc65b71c3fc4cb7f1c266b01307ae10f44cd"),
+];
+pub const MAT_INTERNAL20: [[Scalar; 20]; 20] = [
+    [
+        from_hex("0x1247165cd01dd4862218b1d5fc2763ccef99c8862622d3ad701e941ee2f69496"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0550cc46e3b0f134f205fba05ee78486f32912d09ebedfd208f6c6b7f2c20e6d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0e64f1a01c39f39cc3f36f650cc8fb1cd2953a70d42f7e77350b09b5fdcb3ca9"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x1897574c744c4d0f8993eeb6d9e84186d52e9f104349ea8b4fcf032057dd211e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x13b1b64b9c8bf47332ffee1d277750f2c93b88768b704d8e50147494be36ed0f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x08f51872038cc8a9f45bada1a4780c769e0d9792ef9f1c58ea61d098fbd186c0"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x05864e3d68e35e75f4ea4acbf59ce96dce5f6f8db1b2c5851ca773bf99e9a9e8"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x10998042ee91ba72bfeb0c67fd5ef40858e236f08c48775195ab0493af7ffa1a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0177da84bddc5376c05c3e996b948fba0ca8d28998c0a95b766390409c6f6346"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x01629675560e6992da4033a3cef2b2d6128632a1ac797b5413410df71286f8d2"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x2b6fdb01117062f94f552bc106aedf2b19a22da527faddfbb68ad34ac0f1bd90"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0ec779c16957dadf4522111a89b4a59522cb90e34f71479ae4611305d6b50fd3"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x1451a61800581a1671c28df35ad7bc3529ec4b87ed077bab89750c54e1ba0d23"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0318bc2e377c4101026a5999d231c96bb9e0ff0cb5683a852884616bc65fd484"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0f928373da306782729cddf7d129adfd99683d4cb30bcac2c1e30aa5dfb9a9e3"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x07dbae6b2f71af6ab8f83180f83c03f2852b9c7792fd4b379c9d3a08cf2fa154"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0fe65251190e461054495e0e5efa66ae00ae94a88318091073a4646333f79792"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x305490d0030fbbfa1875fa2e277aea28f9168ae0b64f11328a34e4f8109e4b1b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x02675b375e29a0b55a50b2723986b9456b00a06d94db08dffa5cc6f9f2a5d511"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x2d8b54a1e6718649d4d8eafa4ba0dc65b71c3fc4cb7f1c266b01307ae10f44ce"),
+    ],
+];
+pub const MAT_EXTERNAL20: [[Scalar; 20]; 20] = [
+    [
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+    ],
+];
+pub static RC20: [[Scalar; 20]; 65] = [
+    [
+        from_hex("0x2865f31e80862958fe768b47097cc5d43213d82336d01c99533626d2858b9013"),
+        from_hex("0x046458208441256f89facbada17488e7eafd99c78bcf7d21df78bbe2adb0e102"),
+        from_hex("0x2ef44525ed64fe65bf9c4fe0c1c0e65f8e5fc097dda1f2c2af2788a84725c6e2"),
+        from_hex("0x13e06d2fcbf7c1902a5860b943407534cb2947fe4d1a62eec175dbf3d48b8650"),
+        from_hex("0x07ec50535ce66761af5a3b82447b734a19c27f19eba89009461fdfffe5ec3a7c"),
+        from_hex("0x23d3e49d57801fb9f3e5605b8015b7dee5a67a54459208f55ac7d2647ec75fd9"),
+        from_hex("0x0ab53db9ac68a6c883f2f2a0b57b3609a43d98f9e9a2ea2a51af367e591b6332"),
+        from_hex("0x166d7993e34e2cf1291296400eaf45f568dad0b170623b1f57c7a8c730093eef"),
+        from_hex("0x08a0d9f6bde4c1474ba5e8779d16c276ce104fafe5fbb50961a2fcfaa6f36c7d"),
+        from_hex("0x193e31952f1f9ade90327e3b917c0154aeb6194bf847676810f7cbded5cbc9f4"),
+        from_hex("0x0b78273465b6426d780c15846abce5d6fba2faf2dfe4e5d01cba2e688b26f4b1"),
+        from_hex("0x1ac144a11d6113aa4b5e92552f13b9f303d207a0e4c7201c794af5591728043e"),
+        from_hex("0x03b0f4606f78b34170d35ab0556247d6d9623a82d0c0205cf6a3f3a72f825f3e"),
+        from_hex("0x084f52ff97100cfa54a9f25ebcd28ef520b36b7c37935b35b2173d149f27baa7"),
+        from_hex("0x2e450abaccebc05cf41e68259d394e3712c4147167434df853420ef940c4b19b"),
+        from_hex("0x071915f51b0b45d4eae53f4e1ecfb4a44c8e8e8f4f8265b7917b1b87d033bbea"),
+        from_hex("0x2dd16d6a5e03b890d6f96a88f1b52878562a9637e2d42a0d20ad686af9f8826c"),
+        from_hex("0x078f942e607fc40db36300500fef23b29781a2c0aff55a863622b1951efa229d"),
+        from_hex("0x18afe573f8506b83dcc849d77f9bad8a8e14b5813be04252640c59a188dac44f"),
+        from_hex("0x07add15076a76a7a27e11ddb50f0d57e8a541f2a7f54f460f374256330aaae20"),
+    ],
+    [
+        from_hex("0x17b26a31b294c6b5f4ff8fdd84bed935d74a28516ea4048e7c7492446b1d546b"),
+        from_hex("0x152b7c0453ff3f202ed685bb217fb417d50b8f2aafe61650cd8d74a1b7066576"),
+        from_hex("0x2d97bf4c43c84d20d888573646b98e76bcd68008ccb2a3e872df54006ead964a"),
+        from_hex("0x28118ed4c5592cd7400661e986b02a2120c54e0a0e7b3292c333452264da8491"),
+        from_hex("0x246c58f3e584f5be564897b56347ce1dfe6efa2a7fa432d7f4fe93023f0593c6"),
+        from_hex("0x04e2b6617ad073bc073e000dbdc849c7ee534a8a68a5af6701ac258c3813b11f"),
+        from_hex("0x13a5cffe80dc8c8817b3c08fa4a11390d09ca80a19fbc96bb7bfd913a4e344c4"),
+        from_hex("0x2ec08cde64c98e139571c8b7728ed57bc91535cb639a3be1a7a96625feb5486a"),
+        from_hex("0x0f65fb6407749bf7df13d72ecc42a53bf2d41768521ef31da8b7d6f7bf6c651b"),
+        from_hex("0x21315617acc090aab20b065c6b11d20761fbb2e9b6e70d669b1e838e9e147e0a"),
+        from_hex("0x1c7623b8d66b065c3e62f071e44762dba4800156d87c6eb726356d9166cfbfb0"),
+        from_hex("0x12040083fd52a6d3f608da98b3d70452e95fe27c90c719bd5026be5bdbb0e9c0"),
+        from_hex("0x19c31d6468837734e5cd24d283667b149cd1c0242cc18b1b40270d7b0ac163ac"),
+        from_hex("0x1be828a52957a69e5421b780bd0666ee1ea669f84713ac9ab310901860bb9b81"),
+        from_hex("0x1594601aaaf20af054f9f91a123fc1798e213dac837098de81751501383f929d"),
+        from_hex("0x1069fd8218d89e5c22a86495365c890e1f7a962a7453105e57b5228997eab681"),
+        from_hex("0x17ce8e4e0bde783b5afd5fe8e1a2b75ddd5e0532bd2338949291f15a3451dccb"),
+        from_hex("0x0e17114c0adc9867a953890d923eaea0e8cd6cb40c2f54c9a1af4ef3e5ce5069"),
+        from_hex("0x02f722b8cd7924618e728a1fdec578770c776df7b2ce2842548e6951298da0e6"),
+        from_hex("0x169ef3dace884f32201f833c76d2523502cc14f6a257da5018947abc6e1a35bc"),
+    ],
+    [
+        from_hex("0x28f413441d5c4171d80330a8c812dc376a444fb421795b4d38036005ba8ad4a0"),
+        from_hex("0x293f8dc5585a43adb76081ed02e6c7080d65c927b56ce4ed58a35659ef269b29"),
+        from_hex("0x0e079c4c4095e611196a01a9e456495e94b99cf5c7df8c01164e4e21d1ada2bd"),
+        from_hex("0x174041840f47e3db2b91b4f11c3a49bcc2bd80383a90b4c8ed0336d239bc542a"),
+        from_hex("0x05006f7772cb6b51afbc3efd468ab72c3fea5fab94ce00371eac448462deb6db"),
+        from_hex("0x0acc162a50bf3a046c08a53095305fd456071fec34d729192e9567e1a3b959cb"),
+        from_hex("0x1748aa443aaf4bb6a20a673821b6f60000d480e7462fffeaa184c59839357ec5"),
+        from_hex("0x06b9cb819d7e202ffbecc3ce1a0df391bcc78fa8525bfa1d3cfcd6e404840d2a"),
+        from_hex("0x189d4766b38bbfe1ade8265fbfbc0d5437ef182de5b7bae89fee94f33ced3ef4"),
+        from_hex("0x15cb94fbc5d5a6578354911b21a9f9fb6c063cd3bf19269f747685693b5c3e3c"),
+        from_hex("0x06be1aba6dff44ae5351a2c4f5f087a17c85afdd9131b4f083952937b2156478"),
+        from_hex("0x0b73a0ca43a6d853d5b6240c88196ec5dafe4cc60e62b989c1b2e88a6162ea53"),
+        from_hex("0x0c400508e183b883029f83f9fb14e2ff31b46e6a2a00b2bacfbe2e2c253903a3"),
+        from_hex("0x187dea8d58a6f9b2cc3831152f6ffed03c4a8e5988f8eca85f702010fd452cec"),
+        from_hex("0x20f85f6e93a109ac484a94ff81a4c8aaf616a3905829cdd6a9a87c503ca3603c"),
+        from_hex("0x25c7d8ab168a2999b1647eab3039cf0fdf9cb505571109ef8d2fc247908002d1"),
+        from_hex("0x115cdff3ad1e01016b1ef6613254f5042030106eb02221bd41af1a02070c26b7"),
+        from_hex("0x1cefdb6e69d2c5c6614ae4041e9ecf3eacb9fab591daf140dd6d1d99e94d2f14"),
+        from_hex("0x2fab1afbcbe9e39e63c0dca1f9b29bc8fe7cb58113f0f896bb08348097f0e086"),
+        from_hex("0x07a2c13f1116950474c816e3c1c85b9c567bfec6a3b8fc456df0d58e63fbac2a"),
+    ],
+    [
+        from_hex("0x2b864a553f7300a9ec2d7996e62782afcc3cb9f38a3ee4bb88b9f894a75a271c"),
+        from_hex("0x2ad83e08d03a9d4a92cc040841fc71df4c1840ef5466d1328f0b276944ddabed"),
+        from_hex("0x189a41e4a8119d6e9904f8a0aa03745a9b8e5c1883ac209650efe298c80a6307"),
+        from_hex("0x25cb1b1b97dc41a0c1ae1428e7ea14f95b65715c8adc6470073c1dd6b758e704"),
+        from_hex("0x0f99b8cafad64cc032af3769c83444d096fd196f019144e9a8932b75a28d7f9b"),
+        from_hex("0x2ea85232f83af507f90dbeb5059d234be309605a051ad50e5a32fb658227605d"),
+        from_hex("0x24e260196da6585eb3ddece36f62e582451eee63831eae0fc08669465d720e0d"),
+        from_hex("0x01a9ba12cda83b6032504953b9d7a648b1e6f9efd77cd8659a8014310ffe4c21"),
+        from_hex("0x2b0c8de30451434db3501262c3929ab0673ebb8f4db80bd37cae766c954c6856"),
+        from_hex("0x0ef49b93220dc21e2a00c4cb1dcd116cc8edcf5dedf024b939520a2f580ff937"),
+        from_hex("0x17572cbd100dd240b88dfdd11f459357866a67a0510bfd54dd4108ae9aed11ac"),
+        from_hex("0x2a641e806f76f592bb48955f4d02ba6ecbd51200f8abebb6b52a085af694d4e6"),
+        from_hex("0x06825299feac54b1090e15c5c72cee5b7476b9c7cdc3f1d40369faf1e4423fec"),
+        from_hex("0x051d132e6fade14bb3d594bf3805f98ec3b4f7295904f7924323ba311a1d8071"),
+        from_hex("0x220adefa85bf30aa23128b3d42a11520b12e0cb4b264ee092f47d7714fec3c3a"),
+        from_hex("0x221e1ddefdd7220279d4e6c44c3143daf943a32e81e73336263a6c9c46676fd4"),
+        from_hex("0x1f7f7465652891b34cf6980998506210fb4eef75a859c404b76f167fbd1202a6"),
+        from_hex("0x018e9fa3a9c259a206b454bce32661d4c93f6d95aafc9812303675b37d9d9ea4"),
+        from_hex("0x1c94ad4bfaf6b31b20ba44649492d9cf3d7a5edd6054e67d028cf039cfdf3c78"),
+        from_hex("0x179476a04e2ae647b2600aa7589796bedf2ddcc18d28dde52ba694eceb80406b"),
+    ],
+    [
+        from_hex("0x232787a0adceced96912b6758198445a576df2343d9e1a4a12fbd4dbd9f3b746"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x18f4cf69f0a097ac8958c0cf36c8ab8903065c782ee1646f5f6f2135d798c493"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1f4cf9ca78ba85b8fc4f911d3715e03fda773e51d86c5119c4d06def2257188a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2bf6fb475c6627f8a105e8f2f7421b9842b459a8320a7fa1264a87ef96bb84b6"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2c38519da560e7df624870b282e9b0f0d66f955043ce1c55e6d844e8a024c89b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1b018b8410dcd745be18107310ec2245fc492a99340f4591398dac4f5835b0b4"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0aa441bcf0d73b8ef6727d430e648e5aa01a0a3e4a132c2fc9eca0cc88bfb563"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x15b78777cbc18501bd269107a0f1668293b1bf3921328c0cffed14f55eefddb3"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x219a67f8e4aeb05fe7b9be6f8ac4e7219b62773866f3a305eabea46c725f4107"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x287cb1b94f4b3f93880d7a0823e93aed81187c6ef41268d52cc6a13bf5bb85ae"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x09671f3a3d4d4d3fc72a5bebd7e3c60cbb090df697a3e3277a1a5b76fde9dc20"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0c06a3837a94ccb5f74ee14b82ed2e599bfc64f91f829e3b0e359e2b1a8f3a6a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x175f0ba5595e1712a7d022def5f9f9aa2417dc890a2c7e60bebcb90be52a0718"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x25bafbf4f16ce6356f1117e09e7249fae3dc76b4788a8a84679b5cdce1489529"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x14e1493b31747c65bd3eaa93d555790d9254fb644b076dc102701f55f2309392"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1f484f7144a7eeb6fc16aee5d33f2dc0b0648c3bf51aaada515bf577f440dbd4"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x12744dcf36f314ab76c52c78343bb2b7b64f967263dcfe4b7c23eeefc1eef1fc"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x199b8c05a96642241967c5c8331163723f2ac026a3f6ccbb98c04839f9742435"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2367a2d3ef762ca7732edd0172ef3312d4d5a42b9d5a6b604f61195d501ebb7b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x045eb839b969e409a7534d50ac0535b7f8502bdb8b3e76b9a0f57b0b5d768705"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1618a70e73cc281910f91c675acd6aaeac6291994ec240334856571cc80fa7f5"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x13a6e645762959dc8e713443cf0299c62e85046df6b43961cdf0c829d8be437a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x26aa252f3603a368a3cee60b686654876343d792bb91f976e72d7f2001ca96c8"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x252c44d7ea69fadb20535f836990c0ac718f6c69df5b7bf08490d60f32ca97e9"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0f0f9f834f3b0453dc86ae61cadb88794dd8a72880a7099e9395a3d31a0c7a2a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x15176c9853f597f4139102fab648ac2eea10b3199bf8600e1cd90a2aa61ce138"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x267b15ef27ec6fcdac1f304d7d34006493874aa1fbb041809b71a4f143b5ca72"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2e37ea1fe68952c17068c1ed8262c970c746d00333ac3e31d3ab5ffb5becb85b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2b227eb6f65316447592281351d54dde21e209b762737514d5a1c8dae0fa84cc"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x24faa75fd0ab545b7e3db09156a16dc9fa87ca8490847d9578b06fc11dc1d4f6"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x164e26e3f61436e925eacf9e634b1ed7011e9e6ac4555e2465b9af152cf9ae05"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2f8de29a5f011ba459b170cba1270c3d7d4dacc50efa727e5ae6cf9c2aff8289"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x25137863fd5729a8aec27bc7070e77bff8704243fd97b15a5195a2468690ebf1"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x11ad673fc2230606c58cae864f1c4319e0347fa6a21b21f35181fc9b903e6be8"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2b794864eb5acae1b96d00604f875c3f35a92ab52115e7441c5eebec74a94907"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1bc6753f77697ebe56baa2d12882655cc14b033c3ff68a3badbaae31ebf1e23d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x09c06dd4725d20158659b85c1b3481674700f1beb54fc81b4da88fa8cad8db3b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2426cc594bc4d099efa41b2f8d6b311b649af0fdb90cd4812ca9c40ffb93634c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1098f3e45d1f0cf74545d2b5b4f056f17c00ff8e91fce4d5fd14e273975121e3"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0cc8d7e14b300dcb21d10b5b3312dbadc13b43dd367db2ecab1e6bed3455a2e1"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0ecc9f762fc7894ad88e3a9dd6d57b1abaa44a5b0e6701709f5f4ab512cd49e5"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0cd28c384d1ea94bc928df60acbf46c71505150d5b709300e40d5c7bc44149f4"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0cfe5cf5e2ee6f05353ff41b65483eaf8b50fa888e81d9591f27b9e7162e9ceb"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0ed7482c344ea796ee31691bc8bea8f2568c636d379d9fa848c52db43b4eb146"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x26418f7ddc02893db16ab590bbe621c71e201f6bed8f33b60dc48bd1d9c8fcb6"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x15cf7616b7c91ad804a69c128aab4bc84133108dd3ef57be5a32f4d0ac3b6610"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x11af0a0822f1abb897c41d63e2f81ad1a2ad29d1d615e9c8917e7997107995ef"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x295ac0e89e171924a9ac2ed0062cb7aca67be27249a78eb4e5bf897815b16e6a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0c444c69a4e1bf0adb3045d4086acea02841f8b98ee5e8f6b0ec502bf72ebfcd"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0d9647016cbf2a10e66979eac1f8a15d93dbe9a561da9c38740ed3bfed651db7"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x05ff4774e773c86dc13bce529be93b2706341b7f3671e8f9ee0d1491afd9f3d2"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2efdc62ed6161522fb044ba960c8245b35f6d0a365044c739f8050fb9ff4b465"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x259a3f50bd289b35dc0bcf14975601b7b1e2671ac96be149040f922659dcd292"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2365765c8d84ad988857d02f4b21f62289da6c24a4bd993a2dd795d9ded3c365"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0635dbf921f188c8d8c4c861eed9c1c27b15cf9233af8ca097568c92caac9f30"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x070fa931adf0cffafb64479d6f056d4be0488ccf08f09baa19a0d063de423a8b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0ce0783ce9062bdec84129ea0557cca304c8cd1b63178be7efc7abc9eebb510b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x253fc5d8a05b00d76c045c79189cb9d8be947cebf79c8dd705419c8fd9debf86"),
+        from_hex("0x2bee63c8b9c5c8367d205f6dd47b631963499ebfad90af5963e08be8561c53e5"),
+        from_hex("0x0e1ba944f48c24777ca76700206dbe1082e001abef314e23b0e442839e1ae10d"),
+        from_hex("0x0ae7b1d4e61b970212c2fb05211d357675b3f7d14c7ea6fbfdf68a9d6c484cdb"),
+        from_hex("0x15778e477bb3626d3418c91217be48294cfb5754a4e03fc7d0ad16a03ff6340d"),
+        from_hex("0x1dc3e8e8cd8e6fc954bd2554c3e459ae500e362cc856de63cd69273fc4348b39"),
+        from_hex("0x2c9907407f83dff5d898c15f9a3985570f6135d021260df099d3dc678a6b516a"),
+        from_hex("0x3048016e19e0953a57f508a63183d5e995097cd04c5a6fa7d4e92a532f65fb8f"),
+        from_hex("0x15e9152eba7308666c787b8a5866825342bb4dd44b50cabb51e73700fd37a216"),
+        from_hex("0x291c8855fbdb91bfce77564bc88a36f01d9774acc9090aacf184eea499bd61b0"),
+        from_hex("0x00a02d32a1899e02710b2277a38fc45f57e0532438990625f6581f1f7c4cd48c"),
+        from_hex("0x2146e2511cd4153a770dc51fdbddd5f200c7722aaa6d3edd102d1ac3ffadda03"),
+        from_hex("0x11a1daf89b082d7c3475e7edfe4252fd97770f8c721327d4fcd2bb0a881586a8"),
+        from_hex("0x2a7573e779f6b66a4519c9ffa17f493b3439cb1d8ca97875d973c98bd25785e2"),
+        from_hex("0x1cab851579d589ce5714576f129af1748990a441174aae91ea37e93184cd04f9"),
+        from_hex("0x0d7aacd8e2c6c3ee3123ad361cffd1018a5197d41f62c9b32ae3b5e979a89adf"),
+        from_hex("0x22dda6710575f9d5b50ed00f374c283f2993b1910b4081c7ec787e44971be62b"),
+        from_hex("0x305f917f42352439ae89dd5f06afc4dd4f1c6ebe8ded0590f2acaa1f7fb1cd28"),
+        from_hex("0x22725b59261e3c2c21522a13c9fff1ff74537abd5e9c73d93f7f5ee3bb88722e"),
+        from_hex("0x1331049c3f39d48914c5bcb40e67793ee8784d59850e903b5135b00d1ed9f858"),
+    ],
+    [
+        from_hex("0x0602cf6661e2f9e8a8779ea1e8f43852061952b7019107f43ed9e47a19143936"),
+        from_hex("0x253fc14476c69af9733d1945764e125aed6f80aefd0a226af4b9714f0ae5ed99"),
+        from_hex("0x03e858db9d892496fa7eb6e36586a7067c96669bad4379265f854ab690a7a802"),
+        from_hex("0x0d31da11301d20c642c5e0c909b2465e5f6cf02e4dce749110ba97e7842a61a8"),
+        from_hex("0x25e837bb682c8a05cd157b6bf74a58118de51a10911a21da3131155f95bc8c7c"),
+        from_hex("0x2b89672e252d3295c1c94cfe443e4eeddcbadf774a40575b0216234be49194f1"),
+        from_hex("0x01af1764693c33a6a13198b02732c1d309ee01f6014d1287315849a787c95cb4"),
+        from_hex("0x08345ec7cf4c1899d5b577132e3b1ba6db45dd60d65ded9c53f0801f7a705708"),
+        from_hex("0x2d56f5533139648a8fe60c265fb798b82d41918e557d2264852819d4f0be9e52"),
+        from_hex("0x16da7db2cc4262c8d5a4364250ed69328a8c59c02343a0a10aa11982c6ba4467"),
+        from_hex("0x13b4531070b99ebd00a0d8426b28b15d50dbc70d600701d0666deb03430543d2"),
+        from_hex("0x1eaa3b7f4f23aa1966a4ff11e3c2337f815edbc140cc8e1955690fb902ca18b4"),
+        from_hex("0x121538eadcde646b7bc5960238a7a6066a1282d0ff5b45bd97d8e82fedfe4132"),
+        from_hex("0x21536301c9f1386a7629eb614b31067c87c6d922c47f611bbe1e0f39de3a929b"),
+        from_hex("0x28603503a8ab85ff04717f1efc9ef5972461ca6350a14d61e5bfd7cd4900b172"),
+        from_hex("0x29c736b30138476d75bb1b3484a2f500c590a8500ee4b362b807bcbd8b37df10"),
+        from_hex("0x1754432a1e666efb7a9bfdde0a35b08ae0cd64e3e756e4ddaa53a05dfd4e8ea1"),
+        from_hex("0x0546f9d286c3b45742fcfaefe0aa88313cfb6b148b35d98bf3d68d8c2c9c1fc9"),
+        from_hex("0x16367512bc9c821bfe3a4ca1d832c1cb7ce227bd8b92f4c3d53fe34f86fbc570"),
+        from_hex("0x096d1ee68ad55f02aa171c1337d45dd7ab7cdb73a06c310d57ba7584511bfc95"),
+    ],
+    [
+        from_hex("0x0c760bd0ff7a63b9cc212597d946dac28e6b7de88761102dcd4ff7870ec1d526"),
+        from_hex("0x2c9672a7f2b4e6c68c5baa0355782322e8d3bcf5fad9f47df9e036b2f05457f4"),
+        from_hex("0x21d14b1c5a92f703d534261cea667168aa180c9550acd1804e5e9e93fed435c5"),
+        from_hex("0x0902df51b124d81ecf05f8797b00a94c37933f2f92248f44e7dbea76ab482f4a"),
+        from_hex("0x0c2fc5641b986c3b3a3b6f2304a60695ed7897ca76e5198fb075f39ea00af702"),
+        from_hex("0x2f2f64252bac37ed998483b36ca036ed81668578e36bec42fb402084a45c6452"),
+        from_hex("0x2d4d167072b7273a9190d36a22f1040553b5f809ad34123e0b7b099515ef1961"),
+        from_hex("0x0e77a08546b8bb92c1957991398a27ce261bea3acac8262867e3af5af3155e2d"),
+        from_hex("0x2a9d6b07f73a1d3afcf456aa8e83e5c627697f7a1755e9b9f2584caaceb2af99"),
+        from_hex("0x2eac61095ae5137e23ed8e40d90d3c40b260a88398f68faeed321e6e60d636e2"),
+        from_hex("0x229652a75258c4d395c8b5fc6b5deb4c1b67691ebc29a0562a2b09f59e8679c2"),
+        from_hex("0x115dbe21589c155a00cfe2f2e718bccb3d7850640b4dd0ae6243cf6febb308bb"),
+        from_hex("0x29655c0be08fb8723b4b12213cf947574a75a41d2b8ce2f066eac7fdf8070737"),
+        from_hex("0x10d4ddb49698302a5a883882ce333c93b5c90335d2082e81bd93489a91270297"),
+        from_hex("0x0890362b2947753b7046abd1692160fab1e319f3c0d89aab9fef0f1f459303cb"),
+        from_hex("0x2f7a5872236e040ebee02cf0731ed47bab73e26ad795b393e7b58d4b18d80973"),
+        from_hex("0x2f975f23ebda879b26f19b18ff9ab548f74bc8719055007bc811e8a46d5536a3"),
+        from_hex("0x11edb725e35ccaac4b06e907c2aad51937fc3af4be93ff7dc1fb803ff2d5f41e"),
+        from_hex("0x128ca4f7229d8904a36753a9ab8a8bb5065b978749cf310fea4ccb83d211ca7e"),
+        from_hex("0x28c11b590e459d1a09af13e4ab27ba25a062eb74791235651b2e5ae99f443649"),
+    ],
+    [
+        from_hex("0x1c64aec03c5fe4b644d257fc95710db32069886c9b72cb61d0a92e6a2ca0890f"),
+        from_hex("0x0e89238d2893f41a36bad487b79fd90968b3dddb0e03b8685cf78c6fa8d074e5"),
+        from_hex("0x17d3ea31d17e45d64f173d9b0656219ee9435a5abfd0cd763109138ec8a568c6"),
+        from_hex("0x0cb0a7f674ae680149a0c69b2e52fc07ef1d54749e23ca575737eecbb181f000"),
+        from_hex("0x2777f73f617b5a2620b63cbcac3dd426050297e8089025c84315ecb56c846a21"),
+        from_hex("0x04880c577838508490849045c28f991a8d2b341450652b35035b4059fba63eae"),
+        from_hex("0x16da0acc219b8e90c9ccade4f6140c76b3b8985fa6808a528b58cfd1197995d0"),
+        from_hex("0x002654158a234c60e6db8db4786c3f007ddef9adc08c3b6fb3fc7dc6a7b98947"),
+        from_hex("0x0a44776de6c64912e8fb66b4885b5c1f49240b264dfb4bb0143887f3b5f6c924"),
+        from_hex("0x291239a42ff216383bb60600cac449afbe2b5eda3a20edc7a03bddfea1652f84"),
+        from_hex("0x2839d20a05ebc1064e36f7f518951dfd235a126678b751a0e1b80c690b779478"),
+        from_hex("0x1d2175bfb9048173004183869fb86d997fa739638075c34314b436af582bbf27"),
+        from_hex("0x2d7935dff357aca710e2ec70c9f83975f36321f7965bd30ad8a202e28272fa6c"),
+        from_hex("0x03b740540d6b685cc18560e630e448630e6f06917ceec5b3a9288f1ea30636d3"),
+        from_hex("0x2e803cc8dc0e67d5eb95a59561f5e7f5e475ee712fca9a0a8df969d0d7acbeaa"),
+        from_hex("0x2b6814947dfdcf5b40343effc2df1f8f4cf6012ea857ec36cdfe6b4de434380a"),
+        from_hex("0x2c6445544dcd657b71461c10ad3c81dc8f3c0921e8cc39ff5271b9bf5db7de46"),
+        from_hex("0x1f1cc77f00450a68aff128b0461ed74c51100da366281204e22101bf7223a1ec"),
+        from_hex("0x3012b3a421f6c90d3723bd6af6130e85a881e12554ed4d84e9b8f2562a6a5415"),
+        from_hex("0x206afa0f3fb2517b6db11d445f73aac4aa33f69120397dfb3ee675013e32c8e3"),
+    ],
+];
+pub const OPT_RC20_FIRST: [Scalar; 20] = [
+    from_hex("0x232787a0adceced96912b6758198445a576df2343d9e1a4a12fbd4dbd9f3b746"),
+    from_hex("0x244bd4fefdbb714c4552c1321178d3a1b8fda6623c51012ae78d31cba370fb3b"),
+    from_hex("0x1034b1672cf30b2a60f28bd8e5a45a4c3c24f355c71b2dbe7a5808d3243a2eea"),
+    from_hex("0x01e5b49e0be9575fb51280f1e145ef11d7f7efd2b598110f9cadd01a31d3285a"),
+    from_hex("0x0c7b9c8b65baa7619cd07c54f2925a6982dc75be458d52ba4a596e0812e8917a"),
+    from_hex("0x07e06553a1e199e22da7f72d1b13f5c6db772cd0d26f21277cc52bc8b4b24c80"),
+    from_hex("0x2bc71d2f3fa743b5bd47f0e6915c76f96feda8a18f77ded09ced1aec195983a5"),
+    from_hex("0x2e0782d415741b8f0698d8f26e831588acfc6de90d09d0f867400c91cb5ca729"),
+    from_hex("0x0f6c48742d26386674e127b02d88e0cfb918423e10d230f1298b4d648343e02d"),
+    from_hex("0x16f320a5578005a3260b61985a5377a9c1edd27d4d32b86b00caf9aa1f011c6a"),
+    from_hex("0x018f8010b26f25fb713a6e779084212d852322fada787a44657a2f68fb032f7f"),
+    from_hex("0x1b16c1d13de528b10dc3df9a60d9df6feeb34103e54a816ad5b4710837da0bd6"),
+    from_hex("0x0e87c74035539644babb1dde94ace92a00e5a6e3ba2ae2e4a02b68e8b5aac3df"),
+    from_hex("0x06225ed23c16de53ffa0316b13ac312f032eee6d0088d80ea58f21bbd5531161"),
+    from_hex("0x1353b0ec898ac4ad064eed998b0552bb4f71c8eb8f5becf15402a9555e7cba20"),
+    from_hex("0x2aa0603bdb6c7335c1875b2aa0ba55c54fa03fbbc0a8bcda26e8bc7101823535"),
+    from_hex("0x018d2345c06f9c67ccc000cc6afc768b051b39109fa76f667b9eb0ad33e2c6c0"),
+    from_hex("0x1ffd5564a96f1a14e4cbaf9f8913d8ceb4d5674c6581de69c051f1fec082ff7f"),
+    from_hex("0x2af660f794ec76bdf738d4bd2db02d0100f00810cdc52bde38973fcc3c83d1ea"),
+    from_hex("0x124b1d522a333e4cdcb9be5d1a0c43fb396f9d0118fcae4e76ba1a04e129fce8"),
+];
+pub const OPT_RC20_PARTIAL: [Scalar; 56] = [
+    from_hex("0x0e1e6559d7c48ad0cf0a9aedb38a8e8284b0473ae8c568f47222cde722ae0131"),
+    from_hex("0x199fbfa4091e897f4638991a4b98cd12b952d4cb5a724282c4791aa94ac6b145"),
+    from_hex("0x1af044df57791bff8073157a09fbc5da5ef50828e31c0d74a46373724389aa9d"),
+    from_hex("0x2a3dda4375e18ceca11a54607dc72541b29451f900a9fdcbac7345db8d03d24a"),
+    from_hex("0x138991bd200fa232031171c2487786acd2c471c05280394ed24c7a9d92ef1b33"),
+    from_hex("0x1a92e958b3088fa85f6098b0a16da4020e0604a24791554e9bfd9b39bc9db858"),
+    from_hex("0x2fd7d9df5648cf4877ffd4883ffddb6a41e56149776cc35bdf8ae17a85c21197"),
+    from_hex("0x162b46c85b629afddf61ec2e18ceb9b9f49fa146ea846bf3d9dc2611e0d5b2de"),
+    from_hex("0x2de954c589229124336e71004b5d80b354daa52a07f43876fc0d4dee8e72ded3"),
+    from_hex("0x1c84f04fe4ed45f4624c41ad3d2cfe7f7cc28bd2d39a37507354527a8ea8878e"),
+    from_hex("0x214e68e05fd82f9838ec3297b017bc6f1a6e6c7900c6309d5c188bc70ede9e6c"),
+    from_hex("0x1234fc1fb68dd2176354bb4b984d5f8670e87f667e3cbf86e3398384da02c37c"),
+    from_hex("0x275b86bab9b9b38487ac931eea97595b919b76dc4f14dc7a1011a7555e0ad4d8"),
+    from_hex("0x12f1f4febf8bde12c6710d70792aacb6a7bcde85665142304d49a1640b4778df"),
+    from_hex("0x189521916b611758bf2832fc4ee0422dfddfc7cb57f80d801940030e4b62bb2e"),
+    from_hex("0x13ee1e00cf205a1caf712a8953979440ffad33805cb139c7c8f14f5d5d023f10"),
+    from_hex("0x1803f7f3fde47e4c09202ce15cfbbebfcc4c9ada495d1cbd470eefafaaf93fab"),
+    from_hex("0x131f278dd0af5ab86ad6a28f57591ad38bbee61f219f7896408c94fab7e92a52"),
+    from_hex("0x091bde5635f9176c00548e3974ef128a56e190f9b28a65d364e5a8af457a246a"),
+    from_hex("0x00899e964369ad3e691a7ac7a7362aeac19d06e8941c08d928dce2694631fde0"),
+    from_hex("0x2e109723a1f29f2d54d495a182a60b247f91c6fec1aaf7597a5939749d88a703"),
+    from_hex("0x0205e421ceba43fde1d68252baeca1fdbfca2afaaac7d77b7946ff2e7d04aa8f"),
+    from_hex("0x03a7177ecaf6db045f8345cce0e074f47ae04d59b03aea94f1c762b73481652d"),
+    from_hex("0x2b30e6fd7cdc411e459a4e29e62692458bce56725973169b7a438aca461976c7"),
+    from_hex("0x1495a9d53da43e4a20eab5b5eee7e097e9a2d9970e786bcd75b2e0c7f98991f0"),
+    from_hex("0x289e2eeec5186bef802a528b07a59ee1000a864939f6f9ad90f08b0ac635a024"),
+    from_hex("0x1c379d7c9e27f032afe584632c1c8960d7d960ee593c405f5a667e7c1e564ef0"),
+    from_hex("0x08201e78255d7d2dd4970664c53934c11bd1beef31da8ce201556bdc37010685"),
+    from_hex("0x2a8e717658b1894990fbdd76b5fe004c374ac84bb8db1a81327c30aaba25f36e"),
+    from_hex("0x2982cbbad94e5774ef94a5c17dcae3b0c70ecb024d843d27fb4eb141e4878e47"),
+    from_hex("0x00bc295b8c7834cd3667e106c7832ce79772f90845ec6124deb45360256b0ef1"),
+    from_hex("0x1738a5f5ba3a8aff1843af75c7af32c21fa2614c5d3cb3667a80d469f7904099"),
+    from_hex("0x298b16d9198d84e8e3425118811743f183a205fef6fece123ff5e5b0f2ace9fd"),
+    from_hex("0x2a80b5ff51676fa320ca8fee042c2b4b67edac77883d79b554bf037865295422"),
+    from_hex("0x289f6b323a876b0d45603eacff3a29ef45e110db0802b3d65edc818ee4f682d5"),
+    from_hex("0x1220715b81c8b517c85cb57413cc6159ede895140ca4708fd578579e11678d41"),
+    from_hex("0x03c92ab80a2b9667d01d1110fe339972142575f8218e4ce113d135e1f4939f8d"),
+    from_hex("0x0201caff7bd6f58290c41a361f3deafb9035579a9a778b8ebac67a525b5c6135"),
+    from_hex("0x28f4fce6e5293bc2889ee3c33644f050a13f527279165e2a2af448217a9a25eb"),
+    from_hex("0x220c76c60cbdc072eb5b63e6d7b3e2b4827ad49380e025285d843875c49a37e3"),
+    from_hex("0x110c9b274bc705304baa608bbd48515a148ae3d4c5dc49cb55140d8aa38b60e3"),
+    from_hex("0x158ba3d951ae3aae49ba08e1588c46cb45e582ada9a80737c7ecef789e4fbe93"),
+    from_hex("0x0759fd1518f318cb412e67f21d7b5b59d7a7593c35d139aa1cf34fb9889d17d3"),
+    from_hex("0x14ef0edc7683e9ca3ad38820457ac81d22064d7fe4997530a1c6ed167fef5f45"),
+    from_hex("0x01d61a23a591bd1581cf459a4f360477c147a1c8756b5dfdbbe49d2db6fb2916"),
+    from_hex("0x20459eb0f031419227feb90526b86d2689d1764876c7dd67fb9c009ec1dca2c7"),
+    from_hex("0x0ad9ba17bde12fa53fb789b30c4db1e3e6449f051ab898308f2ea16a1e5c4c3f"),
+    from_hex("0x2954bcc552a1a0e9de7d1cf6a3718f17cafc65f5c8df206f4dece3a483281df7"),
+    from_hex("0x0b650f3ddc5331b9cdac7c0a5c10764424b9cdd93107fbbc2ac35aa28b2cfabf"),
+    from_hex("0x1631b002c0b5e97deff547dbfdeeea23610f79b391ba132e894ce88a06fb0cda"),
+    from_hex("0x270de4ad450eb51b2e76cdd16194c04063e89abb10313193142f0eedcd9849a7"),
+    from_hex("0x28ebc5ee5940c327c992989c87b7370999756d9c19ab1de59d5ebe9322a5b7e3"),
+    from_hex("0x0dee00f3d4ccf4fb2472e8ed9d5ba866fb5407f4a8597b04a1f247b34ca9b996"),
+    from_hex("0x0f6a63dbf65c9674e9efd93ca02ac09e9a128ac245280cc05885d1230196a30b"),
+    from_hex("0x0374454396277d49a445a2ae0c1fa83b007fa5ae6598bbfb35ca3fc059c9035d"),
+    from_hex("0x1ced8f11333739c013ce4a85cad794b835618ef61c3254ee479222b4200d2d5c"),
+];
+
+/// Poseidon2 over BN254 with t = 20, d = 5, R_F = 8, R_P = 57.
+pub static POSEIDON2_BN256_T20_PARAMS: Poseidon2Params = Poseidon2Params {
+    t: 20,
+    d: 5,
+    sbox_chain: Cow::Borrowed(&[(0, 0), (1, 1), (2, 0)]),
+    rounds_f_beginning: 4,
+    rounds_p: 57,
+    rounds_f_end: 4,
+    rounds: 65,
+    mat_external: Cow::Borrowed(MAT_EXTERNAL20.as_flattened()),
+    mat_internal_diag_m_1: Cow::Borrowed(&MAT_DIAG20_M_1),
+    round_constants: Cow::Borrowed(RC20.as_flattened()),
+    opt_round_constants_first: Cow::Borrowed(&OPT_RC20_FIRST),
+    opt_round_constants_partial: Cow::Borrowed(&OPT_RC20_PARTIAL),
+};
+
+pub const MAT_DIAG24_M_1: [Scalar; 24] = [
+    from_hex("0x071d505e5270c2a08846b5d80ac07c6b67b5c0b37b27ac85a96208aaca672cf9"),
+    from_hex("0x16e8f73659f35ab9c4e20bab325721734f56539a9e91f829456a4523cd39afbe"),
+    from_hex("0x0033eaeecfbdc70554a98655afb138da811f0779a791cff215e114c5b77d5ec6"),
+    from_hex("0x0163e939cf8a3c05cde1fa78934cb0b72a4438a50df7317ed8c4e79feb4b8c8a"),
+    from_hex("0x00e3bc2117757c58622541aa4f308cb53f46175e7bef341552ba6fedab045167"),
+    from_hex("0x0f66174ac5609b34c8d73c9408b9fe5ecc0a1c782f498ecf5b2ab753533ed85a"),
+    from_hex("0x0011a4188543f8c0316888219237841ecac77bc3531fe289a1d9e0c27f511730"),
+    from_hex("0x2a79cda35b204a654641ee0634d296f9af80da31ee3fec980f15d6be705cf784"),
+    from_hex("0x085539c400acaf1a1f9494f3400e1d8f545a5923e74569fd2c23293a896527c0"),
+    from_hex("0x01fe8416c32fc539395222b3ffdbddf8699d691170d2e2b8a2ef2f389cf1770c"),
+    from_hex("0x1c11f5ac96126ba1175d97bd71bff7e6ab2204947b0746a6a2ae792b162415cf"),
+    from_hex("0x2b12ee450e7c84195fcb8012abe55bba6704890124ace89ab2572ae0e35e3f2e"),
+    from_hex("0x0165df51906ba6b9fca128d3717151788c8966a26486667759e0dabda0535d42"),
+    from_hex("0x10999088d952bcd9516c63d6fbad2083ae4ffd7577d38eaa50d49533aa240cc7"),
+    from_hex("0x16abe4445323c3697ab8dbe87f0454731f2d7f41e194d6a943b6b3aa5a287e8c"),
+    from_hex("0x0a47349847a5b7579b427b758b9cef6a30d47854a03aaf93f09f65a327b41bcf"),
+    from_hex("0x2c8c71487d9d87b070f1632a60239b55213cbfffc96af41e171b746d29ec6257"),
+    from_hex("0x0172eb1723c92ac3ac15c10c6fd3fc705bd47053eb52a30a3c132f5c17daa91b"),
+    from_hex("0x008a03980c6ecd1dfedfe5d79b3bcc02ae25e48e38f33dbbd048af3f217e0583"),
+    from_hex("0x005292843d83d80e1da8d88640d187d0bc8c86ae6defbcd458c5e2e0ce51c642"),
+    from_hex("0x14ad3b9d12eb56c44814eeffc29c444b6e106a2ebbe37627bbac1c69f699402e"),
+    from_hex("0x2a74083b14a3f1f75de481b9f508dc17bb11f82b18664c2e000585d5c081da49"),
+    from_hex("0x0b7a45deef342808e91b614d68df785b048a56f08f32235029b240b9f288c6ff"),
+    from_hex("0x000bfb6890e5d54b9c1c63b208662b34b358608e8a67aad42c9789cff0048f00"),
+];
+pub static MAT_INTERNAL24: [[Scalar; 24]; 24] = [
+    [
+        from_hex("0x071d505e5270c2a08846b5d80ac07c6b67b5c0b37b27ac85a96208aaca672cfa"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x16e8f73659f35ab9c4e20bab325721734f56539a9e91f829456a4523cd39afbf"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0033eaeecfbdc70554a98655afb138da811f0779a791cff215e114c5b77d5ec7"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0163e939cf8a3c05cde1fa78934cb0b72a4438a50df7317ed8c4e79feb4b8c8b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x00e3bc2117757c58622541aa4f308cb53f46175e7bef341552ba6fedab045168"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0f66174ac5609b34c8d73c9408b9fe5ecc0a1c782f498ecf5b2ab753533ed85b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0011a4188543f8c0316888219237841ecac77bc3531fe289a1d9e0c27f511731"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x2a79cda35b204a654641ee0634d296f9af80da31ee3fec980f15d6be705cf785"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x085539c400acaf1a1f9494f3400e1d8f545a5923e74569fd2c23293a896527c1"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x01fe8416c32fc539395222b3ffdbddf8699d691170d2e2b8a2ef2f389cf1770d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x1c11f5ac96126ba1175d97bd71bff7e6ab2204947b0746a6a2ae792b162415d0"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x2b12ee450e7c84195fcb8012abe55bba6704890124ace89ab2572ae0e35e3f2f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0165df51906ba6b9fca128d3717151788c8966a26486667759e0dabda0535d43"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x10999088d952bcd9516c63d6fbad2083ae4ffd7577d38eaa50d49533aa240cc8"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x16abe4445323c3697ab8dbe87f0454731f2d7f41e194d6a943b6b3aa5a287e8d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0a47349847a5b7579b427b758b9cef6a30d47854a03aaf93f09f65a327b41bd0"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x2c8c71487d9d87b070f1632a60239b55213cbfffc96af41e171b746d29ec6258"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0172eb1723c92ac3ac15c10c6fd3fc705bd47053eb52a30a3c132f5c17daa91c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x008a03980c6ecd1dfedfe5d79b3bcc02ae25e48e38f33dbbd048af3f217e0584"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x005292843d83d80e1da8d88640d187d0bc8c86ae6defbcd458c5e2e0ce51c643"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x14ad3b9d12eb56c44814eeffc29c444b6e106a2ebbe37627bbac1c69f699402f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x2a74083b14a3f1f75de481b9f508dc17bb11f82b18664c2e000585d5c081da4a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0b7a45deef342808e91b614d68df785b048a56f08f32235029b240b9f288c700"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x000bfb6890e5d54b9c1c63b208662b34b358608e8a67aad42c9789cff0048f01"),
+    ],
+];
+pub static MAT_EXTERNAL24: [[Scalar; 24]; 24] = [
+    [
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000003"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000005"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000007"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000a"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000e"),
+    ],
+    [
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000001"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000004"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000008"),
+        from_hex("0x000000000000000000000000000000000000000000000000000000000000000c"),
+    ],
+];
+pub static RC24: [[Scalar; 24]; 65] = [
+    [
+        from_hex("0x066d45436baf154878f59a42f18014d58d1e1b5ff2e0906b5b526df53d994c98"),
+        from_hex("0x12ba2b881a33cbf51af7aa04d8faac274434b0f849f4600d0d77ceb6f29c2d9f"),
+        from_hex("0x1349f2a6a09ea994ca75dc10bacca2b0670f92850d2ec8b4ac2034186e9a7efe"),
+        from_hex("0x17ce292d895f59f9e4d5b6f1df76114ecab19c32014010081d7f4da4d9d26d2f"),
+        from_hex("0x10ca6fa011bd4f11bca39520296622e68d1b13b78496cbca0b05471171d0292e"),
+        from_hex("0x2f67cdde5919e24a50cc5798fa7b35bc948f87ff27fa5ff84b8a2d87cc738124"),
+        from_hex("0x2825fdf866e6f64cf506965d1e96fa0c5607d1595628bce7d627bee644fdef81"),
+        from_hex("0x02f0db66579fb01653127d0b4e5f97c0b984d5665c3b89fd16c409cc797c2e56"),
+        from_hex("0x0f835abdc645457e3bffc0778db2ff1a505da8890b1e6bb4b231fa0e35bea5ca"),
+        from_hex("0x062d41f9b208459c8e405e9086966bfce09e9375c203ff102a77542ff878e153"),
+        from_hex("0x28fc5fa924fd5eccb3d1c5586be949ef71c26a976450ca6ff47cadfc4cc5080a"),
+        from_hex("0x0e1237f6c020239b3b3309094146d095147fdf69684bf05e844e4325e7533e1e"),
+        from_hex("0x2482419ab4bcd40f412137fb34abf68a94ad00891d39243d18007d61b79b959f"),
+        from_hex("0x0071394046c9de95be2da983c310f05fa916185dc375b5f1a0cfb07a009c4f15"),
+        from_hex("0x1491232449e971b77bb2f145b0990efe85e815d9de829b0f881f97c360cc14f7"),
+        from_hex("0x16b9b6f8725c0377cb3698ea3c6e267045189adc11d44bebffbd875e8002be08"),
+        from_hex("0x1c2f67173a9be5a4b477edf9d1331fa175730fe0d8d7b3e37c1c33a30d7b4488"),
+        from_hex("0x0d76bf8b4daefedf90d5241e626abe5275add4b1bc66c064990fa42051dbd3e9"),
+        from_hex("0x2a35de4f4ad7819362652fcca5c13833d855f191b3125db496d323e611833ddd"),
+        from_hex("0x101f813e0258ea2a6cabce9f9591a0338df28e1eb596e0cf36f06b06c6fea9cf"),
+        from_hex("0x2c0a7948b177b42885f3bb21e9875ee21bf519d2f3adb873fafcce1e88949a5b"),
+        from_hex("0x18540e9ecd440f75769b203940fcf722f09407d63205a78ab60d5be0e86c83c3"),
+        from_hex("0x087600a953c2dd053d48d95f5b2eb2fac1608291701706da6cd595e4485a4167"),
+        from_hex("0x001ba8a87ee8cd1682b4180bf317fb490c134afb157e0485b44726d30c1936e0"),
+    ],
+    [
+        from_hex("0x0494cc73f759df25d6c1c284fda4845fe33cd143d78d494619b8ead1116b074a"),
+        from_hex("0x1c10bb1cf425f04de42d93af2512e45e75d127dc207c93cff6174a4251024a64"),
+        from_hex("0x0892ca7c11a85035580f58970ce9dd5aa8d865aaed38e083cf6e0d0ba2d8fca7"),
+        from_hex("0x080b4293adcda3e55d4b754bf9aa63006da0085e5469faa31d84cd046c6f8d75"),
+        from_hex("0x08a6f6a592aa12dd2a7b6bedca5b030840b7e3d7b100e86660e17a577a666d57"),
+        from_hex("0x0b8265c4cef945cbf217c17583ececb30055c0fc2b6165028c35f9ba70118f47"),
+        from_hex("0x0ca98b4f1cfae75c621dcfbcfcae2a3675298b1a85fa8c51779d31b8f95cbe5c"),
+        from_hex("0x2e6affba305771f4c8527a850ef1e53ba84d8833f6c58e0e68ab47d8ea121fc1"),
+        from_hex("0x27a744f15b0588046e4d8d901c717b7f8e095b5c56dd480b4d8368a291bf5ae3"),
+        from_hex("0x1a5ba7d2d405f9d5306f95ac54f675247d65bf2bd65962bfa2c01b0694122684"),
+        from_hex("0x1f1ebbe45bbf25ca8076fee22412d8d5e2eae121326918a86d7dfbe2203e88bc"),
+        from_hex("0x1e5c5abb8921833ad0611369b68523640abc4e59bb86cd9cf78dfcac93728fd5"),
+        from_hex("0x206b20f14857fb331c24288b0608cbefbe332a979fc4beae33d1013e115554f0"),
+        from_hex("0x180d45408a474f74ea3cfd20e156ec2de2141856363280b082c4234282ef3b19"),
+        from_hex("0x0a328b5b51654e584b184ab780cc3c344548273885b66316e71a8c9060f5be1e"),
+        from_hex("0x0dc8436ea4c3911c1bbb2f740e17ee4e1faf04df89d3bfbf45ae508c3c57a57c"),
+        from_hex("0x0710becca9a79e4b23565a6b7dee63c939c0e63676c7fc9b91bbedd18cd5b083"),
+        from_hex("0x0b8300ffa958f733452f7604d024c6c6082de668ee52feae025f6b1ad673af8a"),
+        from_hex("0x1d23b4cf22b1d4e65f7d6c7a44993f374dc81d8c26224c9194e9eb8cb043d985"),
+        from_hex("0x1f74285cf427645a0013b08341d8fa46605d24cde36851f8d1e9671aacc43462"),
+        from_hex("0x0032fc8bd3affd824e0b3e5f04835935f6a7f1ce8efe7fadb6ae95a446497219"),
+        from_hex("0x2b22a9c4f16c5c7e97699b263cf7352e52282ad9f4ab064f012f3f8e4a7b61fd"),
+        from_hex("0x2fba996f633917b8024e0cc444cb9355ad7830b04d419c88dca4ff6adf616bde"),
+        from_hex("0x17b6ab5fdda8e202ea467e720783a2981bc2a30910df27a104e5a02830cb4f18"),
+    ],
+    [
+        from_hex("0x16ab5bd2c398bf21f5657470751fe15d9179a4ddd13c15aabf6c77c50b23d64f"),
+        from_hex("0x1763d6a091a5e64b51463ea925fd24d8205f296ef777c5aca0c34b9e93c91b58"),
+        from_hex("0x1a07e2f29a1b069fcdc2e2de1f65891f55cce060e6431a166758904ebe32458d"),
+        from_hex("0x126062cbbe9f8a49e367a6dcb5f6461a35313b585988f83e0bf9e40e525ee8df"),
+        from_hex("0x18296d0f6adf6fad581d31b1e3e52da530298f42b21332b6a19ba388eda4a870"),
+        from_hex("0x1ae1f274416a7ff00cc0e0b02471272a8792eb181bcc49b9999978ecf516d8ff"),
+        from_hex("0x181dd8d9e03469264d46b3f23356041c10bf0f6d188e6dda36b0c7e95ebf3475"),
+        from_hex("0x070fa9a6a5d342809c4675e206a051783f1c507a9d9c08842068c5b030a9ad13"),
+        from_hex("0x254793d3f0a72e8e1ebd9a09a0c2ade49487e08c9ef5f77203bdebea36f39104"),
+        from_hex("0x1c8a640b50926bfa1c3940303fde0acb8b6c23b80ce8d26320765b2dd26b1548"),
+        from_hex("0x1310d54cd60af79531500e303b1a3c8f8b81c96a4cfc68392bef9666a2a3a72c"),
+        from_hex("0x2ee13fbae742e0c769436dca0b2af66b8c6ab14bb9c644476d4d1633f82fbf5d"),
+        from_hex("0x27d523373bc298cfd9956f0137015ce49ce16adea00c671e81d5f74b937bc713"),
+        from_hex("0x24de8f3841694728df4aa27988a126224cfefb84b13d152d212b210a06ff1f9e"),
+        from_hex("0x15322dc09462531ae1b512451361ee2cd980d8a6a0aea995dbcb8e027a2305e2"),
+        from_hex("0x0c33779776d1757c4625683e98cc5ae9029d721a5291f43959d64566afea9a38"),
+        from_hex("0x13713a48c0ebcd0582398b769599dda7b7ef831dee192b5eaf02fd44a037e710"),
+        from_hex("0x02f97b68a729bc2808e6ec66992641ad55c738a346893f24459f4ce497ad4810"),
+        from_hex("0x0052cfb18be3110e78f72308459d2ebdbb946279b6a97c7263f0a935ffa17871"),
+        from_hex("0x1124352c9af33beba33f1843768a7c287a23a2f059a4458486cd832f84452cc8"),
+        from_hex("0x22baa079aef345c745c74c33e5afe3b8fc8b03dfec5b64065e20137eeba8a676"),
+        from_hex("0x2ad129ba09b98b1430a89385b565ebc6f1e44a69a6a00fec6a0b383ae2015661"),
+        from_hex("0x1d196792a0c4d0b485157b05fb56db23e40a5d45c8fb6a91dc0d68544b2bda86"),
+        from_hex("0x1395fa601c8cd347254a6549fad74c6ccc8b0d92848157dc4876233bae39021a"),
+    ],
+    [
+        from_hex("0x234360e15ff93c9ec22d6bdae60ae1fa4c6dec09851efbf2f187a9a232790fa0"),
+        from_hex("0x25135ce533757ed844c1b8ade0bec71552ee7cd3cfa3c2b862f3fd5db3518bb9"),
+        from_hex("0x097ca863c12e5199ff5bf8d41936880e99e4ce998043c8b61f8f4897d5a8f593"),
+        from_hex("0x069968e6be89955e80154819b7466460f7ecc92186743da8cddd0a844c4f4628"),
+        from_hex("0x02fb01ecc3a24e0353e2c48503459f82663e9a5dc77a32935d1ed6df85f3623b"),
+        from_hex("0x2986c7614d3470a1af47a9c07a4d589a4d3f06668b2ed6e0ac681d05faca6dd7"),
+        from_hex("0x217bd90a4f83c5a0ef3df900b57be21a1c8791f07728835b776956b292d51b34"),
+        from_hex("0x041c123fd65ba012f3094d2da95eb5fa5c0790af076282893a07d8625fbb2493"),
+        from_hex("0x20e1352d7366fe2bdcfb4aca22f43f30957298f3d41eafda1cd883b33fbc7605"),
+        from_hex("0x2c4ec587c68e2d8f95a473db073a95a43ebef5ce389e598f7b6b3b2287d43b00"),
+        from_hex("0x17d3a7637e027aa6113da04a51f3504348719efd06e4b8946ef3aa8bf7bafb9f"),
+        from_hex("0x1f4f165ef2accb0d9635fc4256a10b883c8b715fdd08296be5be7905a714c21b"),
+        from_hex("0x1103053322b5d217c246103bef44b4fca9eb0bbee45d8fe294e5f28caaf2c323"),
+        from_hex("0x126efa2389a951fc5cbf6c1479a68f1221e131f4fb1479b8ce4d43d93f991fad"),
+        from_hex("0x26436a1b9034710627be50a508f8ce601c1336e86fe36d002afc9a84ade96c14"),
+        from_hex("0x03afd9ddad45bdd9b35a48a290f0b2e59aa5f6aba6d93fcc1e661d62c3bea976"),
+        from_hex("0x097a6390bf91d72c636cc8065700a42b8d0b6bf20ffb59ff425122dd52367ba0"),
+        from_hex("0x06b56a2649196626b4a19c684475558b78c52d2280192401c6da19cd4bf90752"),
+        from_hex("0x037a3a0b42813e1fadfbf31d82729e235c040813e8e17a23a474f3bf0118dbde"),
+        from_hex("0x14dac4095638d870ade149bfdd6674f710eb488e9a6e9761883d9c2991743d07"),
+        from_hex("0x1772d4928461f2db5341a8230043d032cb5fa41673267fd2ae0c3d9686f2b727"),
+        from_hex("0x087cddc076749c09ace0d589452696c4b5fb667cf277b596ce0e39627d7c3ad6"),
+        from_hex("0x0ba4519ef48bb04c25ad54d3135d6b6de3df87ace48dbf7338404141a1c6172f"),
+        from_hex("0x20272c5b36bd9170d1b9337d6776a2442eec275072ed1b9d7a2b6a226ff0cc28"),
+    ],
+    [
+        from_hex("0x151b52cf90c9bc1ae0b46ddb9d1d3eef8dc6047d6b1dcb587731855c8fe01f19"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x235300f49b853759868fb11eda064ec14ed8d183073ff92b0e58052d548e8c08"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0eb85a97ecc8cb9bf6b086400dadbf50b0c396a40963b6c3f0bdddadfbe07173"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x00fa9059e377ac0c53ead965b4608c01949d74f502d7db31ed1244d7900f4aec"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x26a93af97e518c8126a8a7386d95b47ad8da9b9aff319b6b50523c51518be1ea"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0275444823417d834efd79e45c8b280fabac4bc2d09deb23e86454dc93b5443f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x28bbd37aaf51409e3638f9b8fc701cdc44c7c3292fecc1d62ad4b20210b87901"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1f32a15d688eadd3ea194454e021c6d00afc9e9cccc71ec91f227e80f042b002"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2edc518517bf9afcbc87353f09e227e081370933dc7cb5cfc2eb2a93fc7f0181"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x070ee271c1852402d40776aef704889e8a84b5819c2246d86884ed1a5d2016f3"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1de047b5a5849ca4ccf45c85d9fdcaefbc2f255e0401b1dcb660ce8e990c3a5f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1fe000b6cea269c07492c75eb1436627d9cc326ca919b085a0b36dd1502446e2"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x13dd422393c83775bed2ab695b3f3e9a4e8d8af14670c3a48b285bffcca7cec1"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x11f3cc797ed2aa28ac2edf15be93b2a099d6199a968101b64dc5dfb643040065"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x04bc15c1955a97713b39d987a3d854e050e42d21ec45b78fd02918175ae2a3af"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1555aa2256acb136b0a41c725add47f1cc6c8bbfb1213c351942a8343dc17aa5"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0ea1ab4df4bb5c407fd4c17d358b529825387d2d7d8937860645fe9862409e6e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1def5e882cc2d408429d67e2e3b71ff1e58174b1a5776ed237546f3b1e9f386f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x27fbb0b3c12e12adfa9ab4bf92753d34b144423d24227c4a1eb097ff0a07c371"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0a38819ad7b64e12c488e69370c7a978a5c97aea6c3a50626de8253c9db97309"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x293a03ec576df29826a95a74be5efc8cca48a13ecb02619943b921c6a0bea905"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x287163c4e2a65879d32f5ec547e5c6d98d2c969cf264a0d7d55eb5a0d170793e"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0ea23f32b1aba6e7d2127daaab1f4805de8617732bb8f52d33105521454162ef"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x065f49cf7619ddd7e22a0c46aa5027086f9383f7eaef9e227c780f616090ee19"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x08708036a050e2bbe45fa12682260983e9895ba90632af2488bcf41de69ded6b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2843d953517013b65dbedc3aced6851d1329cbf0de09d2c0832b6183f8c2d18c"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1e9905c5b536d708f6c44f8517c7c6d6bec313af20547b9c9c434074dbf03040"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0001ddeb714b7019a6dadac060c2d750b670e77c84a04cca76056f28f245affc"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x16214bbed01155787505c77393c0506a7d7b71506f3db749b2608275adec9d3a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x136cbe02e9eb10041a2803a012e546f7d81121fae19502e81380769bfeb1a8d0"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1ab2f06c7bc59198b7f0b4c5cc45d102b3aeff2e3bedaa7e83f9ec8a9b729941"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x12d4c4bab7b4f563811336fe52042fbcbc114b473b5a5246b82dd7d09d95165d"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1b28a2179a00c36efc1f83e7c24e04a26df39de731f3ace7c9cb322322cf31d3"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1dee72111e083a320a6fcb2b39934cc0ed29b90b44e15192b92a19670cd17a3b"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2a0df5296b32b357caf9f282b2130f46465bb36801c5624729eb3c623e03091a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x204f4adb876dad8ba2fbc2c0779dfc5b75e70e4d61da09133dc6920c00b890c6"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0e688dde977f582a42fbc656a7487f6481ebea1d91bbb2fb0107751154edfd71"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x00ca20b0a04fd91b9a72c568ad43a59b659874412581c42b6c7b97c9008560b6"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2a2f43665c00aef29ea68a8ec59cf24254e7c3983e53fdeecdf6a708a6745b21"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1f75b1a349640fc0aa5e898e2df792b681bc9739bab8cbae9cfb39f57aa83f68"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1dcc3c60b4049e197cadb892999514a1b43365322b3f28a3a10aaa18d465ae2a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2fa0e2fd1597f0e868e0eb6afac33528696b3eb7f07c64659bf2fb66cdf76bda"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0870055fc6a8744ee9f1e7d107633dc5659825fd5ef3d172e830f02e811646bb"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x295d9a9575eb1e6ef01848675a02bee43cac198ed8d7357c9cf83418be30073f"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1acea7e503c3c41a97dc984ace332114453fee4a0b73612960acd1c853851680"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x08e42c1b4325bedf37bc85a6584d696925cd47c46a5163d8eedf1f4d3f444705"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x08a547908591ba0862e42834f6b978ca2c73d01873d63e8582402d54ac679f48"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x24edb9b90ea1ee3b67235de2e7f5b8ad148ea7fb6f5986cd01170b18609cb736"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1aeb13905ae7552963c6e13fc7f03fe3cebcb596bad32172513705d3ef3c7023"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0aab603043287aebc52d5301a6db7e284565469a22fe18805628e2a89621e2c6"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2196965ed241c16380d765a6bf7c8292c7549a5d987fba5db9e39db3356bf14a"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x2b73e3bd1eb2166246848fa981e1a928557de9cc2da62810c5a7320b5881a828"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0053fd0370e23595c6b323e3c1935548fd2ca4b0e6b3eee7a50fa3a3fd955d43"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0693143fc92953c8a2d464d586177ebaa689bb394247da714cafa2a0f4a468f0"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x0f3d7bc305c949f1a7c1ef8b597e52fcdbcb8d56b90eb44af676e748431bc474"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x177f7e938d2fffef4f780d33461591b457ffc67ecb9f941a0801ca197b01bc01"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1644a446c7070125d203433c8127dc1084802b4f93f5c9523a1efe74ba7b3c36"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+        from_hex("0x0000000000000000000000000000000000000000000000000000000000000000"),
+    ],
+    [
+        from_hex("0x1da7ecab434fcd6f164f155390a0d4e91beeebd6911a62e5b7d96ba873544e56"),
+        from_hex("0x2be5b941063fa5ad759fe8d0f2a6ec5fa3291582fde4233c09b36ace994fae13"),
+        from_hex("0x290af499ee7f9bb7673cc0342ca86b9d96e8901fde3090f85df868a3cf37636f"),
+        from_hex("0x15c511eddc34ec7a2e1168a7ccb3fd0ac28c11924419ba2240bbdd4294e4a825"),
+        from_hex("0x2ec1ea616b24e0b2106a5462a1faae08c0f554a6f03d43c9ab1cbe7367b427ea"),
+        from_hex("0x2f7a3582dcb10b5d52b295b44836fbe8eaaaa3758da453d164b03e3abd88b041"),
+        from_hex("0x1aa5f28c5ede34fde0f4e48762e36c1c9c2f5f7d192467b90f0d9824ada3ec77"),
+        from_hex("0x1e9b345ab4d8cfc1883df00d3eba1ff99c7b511c13cace81cdb5086d498d8add"),
+        from_hex("0x1d490b62558ddf69749f3fb827920f536e2d477cc36bf90e1fe8d9be0b7802d2"),
+        from_hex("0x09719af255d663ed23557639848feea2e558d6efb41bb1439dd79c13a4310d88"),
+        from_hex("0x22033b3b2108c8ef2e03d6ed7c163f022a2b99e28ba4c70dd6dfa000f0304076"),
+        from_hex("0x1df634d68dd56c1a7d28a935283dbd12227598c8f26fcb7ed1d057df40f64458"),
+        from_hex("0x23fa4616b8c73d8581a363fbdf2f7e9999d72c1cd063afa1dcdbb0d517111368"),
+        from_hex("0x053c98a7aded1871f4594f071428a29a52e5575473e3933f64fd613592fa221f"),
+        from_hex("0x212539e3e4301d53423f2b03f5657c3149c251b497e6fe3b92dfd43e5d80b814"),
+        from_hex("0x2fd5f45edf29912fbe817f287fedac4c0bdadcf80821aa45e67c461085255e1b"),
+        from_hex("0x12c0ae13196595b935bf59fdd7bff39a49a4a86b19ff429aa0a02bb83a9d77dd"),
+        from_hex("0x15da8da23eaebb9f50e4162cfc64723ecfa125a7d50baa8b4bb1d6071d634b4d"),
+        from_hex("0x10464547372928ee26bb8848a8b0c6059ad37d9a40c92218c26b4ca674660cea"),
+        from_hex("0x297fc085a81d40e720838111e05dc215718415deb018e552abfea29a05a8560c"),
+        from_hex("0x1d0ee1a3598a18b7df96ef29101d925b372a2aaceb14c93a5327f79eb286e6e8"),
+        from_hex("0x1b1cb73fb3c51850c19f6d79df90d9650604a01e7eb5cdf4ce2866a582f31eee"),
+        from_hex("0x1198fb78b8cf58ead9f631bfe3a84963ff98f53e23dd0a112c9e89cb9146dcbb"),
+        from_hex("0x05adf4f94f8fbdfc39503d5c824113fba8aac8ccf74b3ab89cc67a7ead75ed48"),
+    ],
+    [
+        from_hex("0x12ec17b4f52e2d41110e74a5be893b8075d76fda678196861665fcf20f9e1192"),
+        from_hex("0x15349c5c7574db82921b17362402668de06ed63681b6b28cef79c065dee9d282"),
+        from_hex("0x16c2264d0c9a4d796389664a0a10d5d83d68b7d491ab37fc7e1ad6d08b68a11c"),
+        from_hex("0x0e034f6028b9ba93f7c6660b2f57ac6b2183ca24523c564cf222e9bc7d1a1d7d"),
+        from_hex("0x0aac065acd9d0a1f3b380ced90ddac0c76630d2bf43efff560c4cd6716c35d30"),
+        from_hex("0x0ee66cda50f661ed700ea975af38bde5d83efd068b95eb92875a292d55b80c64"),
+        from_hex("0x1047c6063b6ca583e9e8e5294a8ce1fd2eaffac5c90a7b5206325d074ca14ea6"),
+        from_hex("0x0fae288d50e494195a31c2f2934beff82b04aac58c4891f0c3d161b9c6717bb7"),
+        from_hex("0x12f9002884c8a41467a5e78cc9aa01b1510ae5bb8c3ddd73cb628c1ab157d12c"),
+        from_hex("0x15238199261de01598fa6236e692c2de71c6be5b97e618ad1ff43a00794380e1"),
+        from_hex("0x0c481f65ff4596863b95ed78124346fc1052ee9eb47b1a22bdf0a6dc7f3c5796"),
+        from_hex("0x1c995a606642c5bc01293619e8bf36e7229e2287a8454268d7464a1c1e828f5e"),
+        from_hex("0x0ad95423f38b29f8670c85cbe5efd17b9efa8f079664fb5fd3a4af7bf5132679"),
+        from_hex("0x2382ffacfaafa75c9caeba9e7c3128fd35b8a954a8f27b4640ca4568c3f7b722"),
+        from_hex("0x1efa121a646b773d82cb6f5b9f5c320d19e31c38ceee0eee0fcf06c3896b57a6"),
+        from_hex("0x172565665606557db31d9695fa4f96544242d7673e2efe38e4af2829dfb99f03"),
+        from_hex("0x0dd670d68751aae722a328454b0db14a1363750412332aef4e25b8fca110f130"),
+        from_hex("0x225083ea302ff3eceb762a1776e4b1394b31acb9f0c62157ecb9652fd2212201"),
+        from_hex("0x2a7bb696ac8714c322c9a4e5dad95333a7f06e8c283d0704085fbdcc686e0665"),
+        from_hex("0x07a59e731a04633534701422faa27b795a70cf386ba1afc48b34b41adc91f99f"),
+        from_hex("0x16be1c0b2852ab73837186dcea9e4f6bb65ce6be5c6c2876ab426e70db97cf50"),
+        from_hex("0x25c3f149ed4293c3814ba09eeedc29151ca18d8f9f0e6e22110e53ffb422dda1"),
+        from_hex("0x2590cb793fe4abcfcd2412f909443c3a9a186605607778828ebc2405b00a7195"),
+        from_hex("0x296b2d07268296908a780a686eed671c8ae06fb695e070ef53e07131e99b715e"),
+    ],
+    [
+        from_hex("0x01012e8008e3cd86a5fb1fed7045f7915b4726ebd30181a1ec9994cbe7767d6e"),
+        from_hex("0x25e5ae4974e62d8a8a371039de95d5f8343d55012ab1313a0ba061a4ee5dd4a0"),
+        from_hex("0x0f08123ffa8b1cc42fc7243afbdc0c1ad0c8f2033970e4c0d5a4e230fa1f41a5"),
+        from_hex("0x2c747c3248edb66a6580ffa128d2b8a4ddfc613d61708dd99235b8c4f88e2976"),
+        from_hex("0x12b1a91f3d7b763917a57b5b7b1e9a0731709a357c73a57f24921ed53f573a89"),
+        from_hex("0x244ed17f2215b23900a89048accf839b201cbc6af749f2addc2894c274b7fd4e"),
+        from_hex("0x1df813caddf31de8c9bdce4b8c85fe1c38480e6f5ea73bdce4803919f9402db3"),
+        from_hex("0x1ccde3baaac5f5631dec55f9c47de0cbe731726cf56def2a9802e1db45a0e638"),
+        from_hex("0x2c30caa6c0d2936fcecb2595f31bd01326b3d4ab30742c6af1e7830231f6c2f7"),
+        from_hex("0x0b2939d71e8610b614000028f279739f92785e614ee69bfc95c17e5d0f74d65b"),
+        from_hex("0x0dd947c185ba70ebee0bb33b570b93c33ed8e13843bda370a5e69350b19d28ba"),
+        from_hex("0x0f798f7f12cc0b71d55d0199a48a766d3791114001956b24f3c09f2cda8acafc"),
+        from_hex("0x09074d4ab369a457da2390e0f7467e39253e40c18cde10345bfb4139b4588d92"),
+        from_hex("0x11aa94d1bb0a6de5c962ab6d4d6480d0aa073d77ed53317346e153980e57da8f"),
+        from_hex("0x0932ff8d78be38e83ca0072b1b99e587b0b4732d47631a17ef5390c207a6da5e"),
+        from_hex("0x2917b35ff5361bf841c1bc0b790f2eb6ab9cc09a430c3eac3f75a584593534c8"),
+        from_hex("0x1706158df00ac51bdab0657213f55bb3827da1b76c46f2b627613b56d536d512"),
+        from_hex("0x2f8222d3a20cda1a849e3eadbc8f5039f9f5204923657dbccced4772bf8d34bc"),
+        from_hex("0x084e07658fad6a168bf2b3f1c14e9bb7b43afea402047c544f1e8693feabe656"),
+        from_hex("0x2f8192535386cb0a7b5aa5719cd73bfb5da25d92537420daa5fdc6255e38f7f3"),
+        from_hex("0x1895898e67ebd3107d1ad5946321100d6c3e579eb33ec93a7edb3f013b085b0d"),
+        from_hex("0x1635b9979152f830d19a37a81880d74b0ccbc221529bf49667ffb07366139d04"),
+        from_hex("0x1f6f624ac14c7e4d16a44fc69fd9403bba0a7e532359fcf8a2eb22971e7dfd66"),
+        from_hex("0x14052629b6eba0d428b20cb18d2d3e5cfd6e01cc570b7a73d0e2c9607c89777b"),
+    ],
+    [
+        from_hex("0x1cdba35572b6d6d4d596c342585d21869c10f66de580a9b5533c679addcaf368"),
+        from_hex("0x0bf3ee66455dcbabe3512edd16a055a6200fe25c39f5d4ce1370715d8a895fbb"),
+        from_hex("0x27fc86c4d8cc1976a4e41871350a4dd4f5476906657ca4b4c7bdd5f6244aa07d"),
+        from_hex("0x18e364f1df9ffd3b89f2d61412efe022ee16a0b83d70cb2a83bdd763a2d973f0"),
+        from_hex("0x0982fb970727b7e88ac81f80d14d340573b3718ae127d1e70118703c028a596a"),
+        from_hex("0x2ea4eeaa46ac1fba2a6d89532ae502abb814b45f82ef8b329c8e164c83f0892a"),
+        from_hex("0x20590de58046ba61be8df091609917154abfde714448983ef7b45f1451a04737"),
+        from_hex("0x1b39cd17dfbd7d7c7f1c93c2de8e204e98e6f28e86f1fa3e0e2d8fd2c77d4b05"),
+        from_hex("0x2ed710fb4c923295dc4bcb692ebaf632e72e154ad20c463ebba7b44839efe003"),
+        from_hex("0x02bc565177071f453779994637cb5c48528055cd1d90c72985560f878790afe2"),
+        from_hex("0x247914cada331b8412023fb4febb7a9df6ca5315921f240c228ca221d8e728bf"),
+        from_hex("0x1d917adaf54f74a698cd05ba7568b1205d0cd4d7b44d253225256386b1655f18"),
+        from_hex("0x21f20b2e26513ab1a8b04fec7494b812c40c5884f63866929bd97da6ab5c2adc"),
+        from_hex("0x126f2f439fc401e7f0aa39895f18e5eae362e1438fa6200f4e8171792d2f4259"),
+        from_hex("0x24fd676811a5058313c08b910848bad41a150bf930a5e43d0c48cd726392580d"),
+        from_hex("0x2a26c0198204c3146f52a18b84535a19d98ae1bc5c4a6460025e767f9d9363e6"),
+        from_hex("0x1e8c36feca9e9abbfe9a3bdb90cea22cd0e537d6ab5621812558b4c716e1bb1c"),
+        from_hex("0x1f4a2d95be23c9f062a21f90e4d8e76233718f051a5020ea5297ea8afabdc9ae"),
+        from_hex("0x16416201f1ed1ac0e30aec36a1f702568e155c4e241cf4ab1ba75a70c86cc342"),
+        from_hex("0x1906d95285448957b06b55c5aeaca0d73c74c44e64153932ce55b52876aa0600"),
+        from_hex("0x294cb7604b88a0a02ef8876a6b4991e05db21ddaf7fc01e1297ae487645422cb"),
+        from_hex("0x2b0b26afa4a3e6628e98e75f6f9fffaef5d5ccced4f3b4d585a95d5b53304df9"),
+        from_hex("0x1bc30ca076b47847ea32ede9df0db289b32e7cbc02cd6e9255ace6a159325abf"),
+        from_hex("0x1b78a875ad7aa45e5be64158f594c1dd6010d0e3fef02ba37be143c0dfeea377"),
+    ],
+];
+pub const OPT_RC24_FIRST: [Scalar; 24] = [
+    from_hex("0x151b52cf90c9bc1ae0b46ddb9d1d3eef8dc6047d6b1dcb587731855c8fe01f19"),
+    from_hex("0x22dc455eead7111d89f8d151a5338adfde0e8fa8f15a7f88752ab895ee6fb6a8"),
+    from_hex("0x0f580714f0acfbac65985c9a83d098ca16fcddc210121f5316bcac9727f86a2c"),
+    from_hex("0x1a4787a382af0e5226f494b5fbc6a8795a0a559a437a80bdc3da8016697bf0e3"),
+    from_hex("0x028110337000cf238bf96ae7207355e0e57f5ce5682a4b7c1f10048fc34ccbb2"),
+    from_hex("0x2d857f492193d2a2dfc4668ffac56653819261eccaf79f05755f7bb0d2e5bc60"),
+    from_hex("0x1663f0a9b6c0af5484c5f6d40f2b852c0faf0757ca756827c0f40b4e9b3d510e"),
+    from_hex("0x1814e6879d6b6f212698bbb7b8b52eb746207097874e14efad1bd2c22a8ee0fe"),
+    from_hex("0x28d16eef90f1fd41f1a9a0dc5d1cff7f44337591ba3b70f929a011ef23954304"),
+    from_hex("0x0c92f437699eb858ac60142047868606fb251b2b684a6e50d366e2b238401691"),
+    from_hex("0x0730cfa2ebc0e39c1672fd39b2038a1515775e52b8f7b6ec0fadd509b0be3944"),
+    from_hex("0x0cc5d60808175f2f8c281d168d28175d8885142f4da7a563367a599bee12e7f1"),
+    from_hex("0x033cebe127958583f796d1766e546bc64b0bf491d3ca9ac4013e62b786dcaed2"),
+    from_hex("0x0c2fdc776aa002d62fddaf2d6939790fd621964efdadb579180f538e3c86b5a3"),
+    from_hex("0x2b96bf7a5d9128590340d1dc119a5f37739a3999b5dc1ce7f41a7c2d404f7786"),
+    from_hex("0x1727ee5b48716c9760c7ae9423743964ca6548edec46bdcae2f8cd771adc31aa"),
+    from_hex("0x0af3e58c62c40ac3db856647bed16786bdf7ed27371e468a350d1ad1cfeec098"),
+    from_hex("0x1a8f89ee974ebfaaae7ff1bb39401231408ecf2e316dd4b44447970739b73359"),
+    from_hex("0x0adc5f3eed5ed5a3184892f819271d72a2e4ba34634ff236ffe561bac06e94bb"),
+    from_hex("0x3059c4b0c354702d04b2d7a951b9b347b29c4ddd61dfc45e219e55137898cb3f"),
+    from_hex("0x0ee801d5f880525100c7e3359bf5c916663c30f356cd4a5b5b198a517f1d6516"),
+    from_hex("0x0dd71c8bbfd59f4b2c5e11850d3639ee5a0d1a5a860cc936c507e6f3e3da00ef"),
+    from_hex("0x14db49dcd079b3312272c9345580f056eff678f1252fa1031bbf0f70d62886d4"),
+    from_hex("0x0c7af312177a85bc8a4ebcdedcc93452c7ef511a61743274648ea823453c0579"),
+];
+pub const OPT_RC24_PARTIAL: [Scalar; 56] = [
+    from_hex("0x05b4826c6e7adc35a9613c9d413a274a4aa9c7a19499d8f0d959cba335e7473e"),
+    from_hex("0x25c28bab8ee2e831389e6ef731c6bbab73b2b2383bc0d78979e78e34cdb8989e"),
+    from_hex("0x1d9c88f8fc003b3d506c9b341ba553c546109fd6ab3175bccf335edf234b7095"),
+    from_hex("0x2a7dbecfd4a90c241b23363fc5742259208cd607284de813796d1ab7ced107e0"),
+    from_hex("0x1743ab6c1147616afc259aa5253664c377a48fc5fbf6792dff5e6787b9d8344b"),
+    from_hex("0x02453d86a2285175168dd6f0e1ed1e902ac404df4cc083bac810cb26b4f9423f"),
+    from_hex("0x20f384dd37a0864816002c11630875b167056195f06264a391b02029e717511d"),
+    from_hex("0x17a8b6b3988ae03a7cd4a471d17086bddcf278a151054095a7bb02e931529a11"),
+    from_hex("0x2ea0f014bab3e1a51e8bf13b0d7ea4b8669629cd8d54996ef5d53eb4d63418d3"),
+    from_hex("0x13e8108d04488ae7abd90c2122a3b8161d1179b5f841455e704c54b918530f51"),
+    from_hex("0x004cb6714b827b986fe775e95936dfb9b2758cd52b387c0f64e156981d48964b"),
+    from_hex("0x1e4c89172ccb9fe4dd5aeadc03a84a934b00990a81a1d3d5fefb1a7c9e4c8d11"),
+    from_hex("0x2eb9af81edf11b9034fc719f500b00b6706cebee750a52fcee12aa89f2da13a9"),
+    from_hex("0x1c2af0a58ccb6b39357a7a3eedfca0dec6d0787c5bb66b0c7ef9def5099470cb"),
+    from_hex("0x05c34da4bd67337cefcd4cc1f7b724d196e468fda398b711d1a03556d46ec754"),
+    from_hex("0x07c2e22f636027df84a408c15bb99a3630f525188f41295bcfd886db1189bf2d"),
+    from_hex("0x22a44330918ce0fcff9ef9349a337196a1621bba5af99559b78489be31409171"),
+    from_hex("0x2422916b8f56f2fc842cd19ba65f158637ed22823ad368fd517f976b502f0087"),
+    from_hex("0x21e118bcc457c83671a0dc15415cebe8f80ae8701864599e88d708672e341033"),
+    from_hex("0x0428db7e9dd17e01357e35277d3ad7d7732d0d708a0417288625dd3fb3e4eef7"),
+    from_hex("0x1f37606a13ed6b42de35d35174f2b79d8ce43b22b591fcd122b008fe06d2ed4b"),
+    from_hex("0x18f23cbeba15e3c9e6b119a99adaca9840dcbca3cc2ed400cd7549f78f5ea8a2"),
+    from_hex("0x0176c1012ae480c4f5aaf7fc4df3f1e2a6b1767a26f61b5ec55b1a7f98276fd2"),
+    from_hex("0x19e3e785d2e11766fef113bd76a7738ee6ba01d5c4f0df56e4bff8866f8686a4"),
+    from_hex("0x1f0cc115b4f1efa76813bd757e846ff01e0aa408d79721f7c27a06444a484616"),
+    from_hex("0x088c51e015ad9b9012e674dfccc02d9129160305f5f6b70faf3cdf7bff3f2000"),
+    from_hex("0x2bb0c24c8628376d76b78ad76790e505ea7e2930eea05c036cb66776472462ba"),
+    from_hex("0x210dbdd489f7bf585e276840ad5605316da3844f04332a4d58c8e241f79718ca"),
+    from_hex("0x236e10358679d8567f98dd00aa59d62660e59632e4c8b578e67c65e1ce795644"),
+    from_hex("0x16fdad250c688eb04485a086bebe41c087a5c7084fac1323256d6360db09d8bb"),
+    from_hex("0x210a10bf30dc09c8ebc3bda25121923c7211fa033d4d4de34e65fa40041fdd1b"),
+    from_hex("0x012cf360947548cb3200658cc2c38150e4290d3b2098842deda8bcd5f872c14a"),
+    from_hex("0x2b818ffb1f35dfc8b65d5f903efe67ad2e394a3c86e9a25bc3aefde399c18992"),
+    from_hex("0x0ff71c6da16af051116707225b36fd2e44de83223572294ab1ef91128babad1d"),
+    from_hex("0x0ff984b97e08746d64b7070e5e50726dec5e28180227545362012a1b93a63dc5"),
+    from_hex("0x14f572f139ac96844609faec040766eb7b568f2fd9919f59da3511415e14bc64"),
+    from_hex("0x1669baa4071835739c3d4cff3a12a9fa9a982bfda4a81eb3fa89371dfb04442e"),
+    from_hex("0x22ab058cb3a03305c853d01fc7aed050c4a7f84824a39d5db766334095cdc1f1"),
+    from_hex("0x12bd2cf75e9407da1df0bea5d2c7b68374734d6064fc49b228ee6d3d5a5e84ea"),
+    from_hex("0x2b637cb946afd13a129dacf666fcff04a8f1587ad4bd26d07487e61375860de8"),
+    from_hex("0x2f370dcce2ff4afa6a2543103db40a6eff4cd92fa24bf1d4dbc3e90bdb4b1fb9"),
+    from_hex("0x2b2e7fe9fa35217f8e41c8047acc67e69f19740f56e3adec828936b6e12cb694"),
+    from_hex("0x2b65d8cba8126e63fd07b06f3c54329afc596c9a77b9691a14375d0ae0cbb691"),
+    from_hex("0x03e4681eac522ae2dbdc878cb5752d3e063e2a82160eea4997c3e2d8f0761bfb"),
+    from_hex("0x0222855b847282d8c4cba5b123dcb5140f045167066eca708dbc75de0352a01d"),
+    from_hex("0x0006b3e33d62cfbd97bdac0b7743992e5f9ce4d7207b2fda9b1f94a83a461a20"),
+    from_hex("0x1b4dbcb86fb04086e99e5320d7f89e6facc3b4dad8d64621d66f139b0b5c603e"),
+    from_hex("0x0d93812ac212535698d507ac47b99210e8d7e0a5c0869bbe41c17bd275ca7435"),
+    from_hex("0x2aef27cd49d788e676c58752f74e54dd6439f9952699ef972be4a8941c095915"),
+    from_hex("0x0063f7a8fed40756eb24d461cea34c2138c12dc96738c1a1a41a03cfe25948c1"),
+    from_hex("0x0f6b404c12d9fcde65143f53702b1269770e820d62842e4d2bf83287f7f2164b"),
+    from_hex("0x1c74f64d553b6707cfa527b6eb5ebe8b563c96191f64a91d9bf11d27b5361e7c"),
+    from_hex("0x0a9fa953e1bd4d0c29032133cca881b938f48d080eadbad97a8406ad954306ad"),
+    from_hex("0x23ce72d3e562614a72e707d71d09045f8e3c881557fbc8e203eca246d7d50d07"),
+    from_hex("0x0add03356962b6d91c64249133222946af8c3aeab43a1aa938592b33dfe7cc1d"),
+    from_hex("0x2032a3f19f7e93ac71deabb072f6463990ee4d18a5dd7ac1ad74b1c4d04d3a3d"),
+];
+
+/// Poseidon2 over BN254 with t = 24, d = 5, R_F = 8, R_P = 57.
+pub static POSEIDON2_BN256_T24_PARAMS: Poseidon2Params = Poseidon2Params {
+    t: 24,
+    d: 5,
+    sbox_chain: Cow::Borrowed(&[(0, 0), (1, 1), (2, 0)]),
+    rounds_f_beginning: 4,
+    rounds_p: 57,
+    rounds_f_end: 4,
+    rounds: 65,
+    mat_external: Cow::Borrowed(MAT_EXTERNAL24.as_flattened()),
+    mat_internal_diag_m_1: Cow::Borrowed(&MAT_DIAG24_M_1),
+    round_constants: Cow::Borrowed(RC24.as_flattened()),
+    opt_round_constants_first: Cow::Borrowed(&OPT_RC24_FIRST),
+    opt_round_constants_partial: Cow::Borrowed(&OPT_RC24_PARTIAL),
+};
+
+/// Every built-in instance, for the registry and for tests that cover them
+/// all.
+pub(crate) const ALL_INSTANCES: [&Poseidon2Params; 9] = [
+    &POSEIDON2_BN256_T2_PARAMS,
+    &POSEIDON2_BN256_PARAMS,
+    &POSEIDON2_BN256_T4_PARAMS,
+    &POSEIDON2_BN256_T8_PARAMS,
+    &POSEIDON2_BN256_T12_PARAMS,
+    &POSEIDON2_BN256_T16_PARAMS,
+    &POSEIDON2_BN256_T20_PARAMS,
+    &POSEIDON2_BN256_T24_PARAMS,
+    &POSEIDON2_BN256_GNARK_PARAMS,
+];
+
+#[cfg(test)]
+mod poseidon2_instance_bn256_tests {
+    use super::*;
+    use crate::poseidon2::{
+        grain_lfsr::generate_round_constants, poseidon2_gnark::gnark_round_constants,
+        poseidon2_params::Poseidon2ParamsBuilder,
+    };
+    use alloc::vec::Vec;
+
+    fn to_vecs<const T: usize>(rows: &[[Scalar; T]]) -> Vec<Vec<Scalar>> {
+        rows.iter().map(|r| r.to_vec()).collect()
+    }
+
+    fn assert_matches_runtime(s: &Poseidon2Params, params: &Poseidon2Params) {
+        assert_eq!(
+            (
+                s.t,
+                s.d,
+                s.rounds_f_beginning,
+                s.rounds_p,
+                s.rounds_f_end,
+                s.rounds
+            ),
+            (
+                params.t,
+                params.d,
+                params.rounds_f_beginning,
+                params.rounds_p,
+                params.rounds_f_end,
+                params.rounds
+            )
+        );
+        assert_eq!(s.sbox_chain, params.sbox_chain);
+        assert_eq!(s.mat_external, params.mat_external);
+        assert_eq!(s.mat_internal_diag_m_1, params.mat_internal_diag_m_1);
+        assert_eq!(s.round_constants, params.round_constants);
+        assert_eq!(
+            s.opt_round_constants_first,
+            params.opt_round_constants_first
+        );
+        assert_eq!(
+            s.opt_round_constants_partial,
+            params.opt_round_constants_partial
+        );
+    }
+
+    #[test]
+    fn static_params_match_runtime() {
+        let mat_internal = to_vecs(&MAT_INTERNAL3);
+        let params =
+            Poseidon2Params::new(3, 5, 8, 56, &MAT_DIAG3_M_1, &mat_internal, &to_vecs(&RC3));
+        assert_matches_runtime(&POSEIDON2_BN256_PARAMS, &params);
+        assert_eq!(POSEIDON2_BN256_PARAMS.mat_internal(), mat_internal);
+        assert_eq!(
+            POSEIDON2_BN256_PARAMS.mat_external(),
+            to_vecs(&MAT_EXTERNAL3)
+        );
+    }
+
+    #[test]
+    fn static_params_match_generated() {
+        fn check<const T: usize>(
+            s: &Poseidon2Params,
+            rounds_p: usize,
+            diag: &[Scalar; T],
+            mat_internal: &[[Scalar; T]; T],
+            mat_external: &[[Scalar; T]; T],
+        ) {
+            // sampling the diagonal again is slow in debug builds; the
+            // sampler itself is tested in `internal_matrix`
+            let params = Poseidon2ParamsBuilder::new(T, 5, 8, rounds_p)
+                .mat_internal_diag_m_1(diag)
+                .build()
+                .unwrap();
+            assert_matches_runtime(s, &params);
+            assert_eq!(s.mat_internal(), to_vecs(mat_internal));
+            assert_eq!(s.mat_external(), to_vecs(mat_external));
+        }
+
+        check(
+            &POSEIDON2_BN256_T2_PARAMS,
+            56,
+            &MAT_DIAG2_M_1,
+            &MAT_INTERNAL2,
+            &MAT_EXTERNAL2,
+        );
+        check(
+            &POSEIDON2_BN256_T4_PARAMS,
+            56,
+            &MAT_DIAG4_M_1,
+            &MAT_INTERNAL4,
+            &MAT_EXTERNAL4,
+        );
+        check(
+            &POSEIDON2_BN256_T8_PARAMS,
+            57,
+            &MAT_DIAG8_M_1,
+            &MAT_INTERNAL8,
+            &MAT_EXTERNAL8,
+        );
+        check(
+            &POSEIDON2_BN256_T12_PARAMS,
+            57,
+            &MAT_DIAG12_M_1,
+            &MAT_INTERNAL12,
+            &MAT_EXTERNAL12,
+        );
+        check(
+            &POSEIDON2_BN256_T16_PARAMS,
+            57,
+            &MAT_DIAG16_M_1,
+            &MAT_INTERNAL16,
+            &MAT_EXTERNAL16,
+        );
+        check(
+            &POSEIDON2_BN256_T20_PARAMS,
+            57,
+            &MAT_DIAG20_M_1,
+            &MAT_INTERNAL20,
+            &MAT_EXTERNAL20,
+        );
+        check(
+            &POSEIDON2_BN256_T24_PARAMS,
+            57,
+            &MAT_DIAG24_M_1,
+            &MAT_INTERNAL24,
+            &MAT_EXTERNAL24,
+        );
+        assert_eq!(to_vecs(&RC4), generate_round_constants(4, 8, 56));
+
+        let gnark = Poseidon2ParamsBuilder::new(2, 5, 6, 50)
+            .round_constants(&gnark_round_constants(2, 6, 50, 5))
+            .skip_security_check()
+            .build()
+            .unwrap();
+        assert_matches_runtime(&POSEIDON2_BN256_GNARK_PARAMS, &gnark);
+    }
 }
@@ -0,0 +1,137 @@
+//! Poseidon2 hash of Noir's `std::hash::poseidon2` and Barretenberg's
+//! `FieldSponge`: the t = 4 instance [`POSEIDON2_BN256_T4_PARAMS`] (the
+//! parameter set of Barretenberg), rate 3, and the input length times 2^64 as
+//! IV in the capacity lane.
+
+use super::poseidon2_fixed::Poseidon2Fixed;
+use super::poseidon2_instance_bn256::POSEIDON2_BN256_T4_PARAMS;
+use crate::fields::bn256::{FpBN256, ModMathInPlace, U256Field};
+
+const RATE: usize = 3;
+
+/// The sponge of Noir and Barretenberg. Inputs are cached until a full block
+/// of `RATE` elements is available; squeezing permutes the remaining cache
+/// and returns the first lane.
+#[derive(Clone, Debug)]
+pub struct NoirPoseidon2Sponge {
+    perm: Poseidon2Fixed<4>,
+    cache: [FpBN256; RATE],
+    cache_size: usize,
+    state: [FpBN256; 4],
+    squeeze_mode: bool,
+}
+
+impl NoirPoseidon2Sponge {
+    pub fn new(iv: FpBN256) -> Self {
+        let mut state = [FpBN256::ZERO; 4];
+        state[RATE] = iv;
+        NoirPoseidon2Sponge {
+            perm: Poseidon2Fixed::new(&POSEIDON2_BN256_T4_PARAMS),
+            cache: [FpBN256::ZERO; RATE],
+            cache_size: 0,
+            state,
+            squeeze_mode: false,
+        }
+    }
+
+    /// Panics after [`Self::squeeze`].
+    pub fn absorb(&mut self, input: &FpBN256) {
+        assert!(!self.squeeze_mode, "absorb after squeeze");
+        if self.cache_size == RATE {
+            self.perform_duplex();
+            self.cache[0] = *input;
+            self.cache_size = 1;
+        } else {
+            self.cache[self.cache_size] = *input;
+            self.cache_size += 1;
+        }
+    }
+
+    /// Returns the single output element; the sponge can only be squeezed once.
+    pub fn squeeze(&mut self) -> FpBN256 {
+        assert!(!self.squeeze_mode, "squeeze called twice");
+        self.perform_duplex();
+        self.squeeze_mode = true;
+        self.state[0]
+    }
+
+    fn perform_duplex(&mut self) {
+        for (s, c) in self.state.iter_mut().zip(&self.cache[..self.cache_size]) {
+            s.add_in_place(c);
+        }
+        self.perm.permute(&mut self.state);
+    }
+}
+
+/// Noir's `Poseidon2::hash(input, message_size)`: hashes the first
+/// `message_size` elements of `input`. If `message_size` is below
+/// `input.len()`, the input is treated as variable-length and a one is
+/// absorbed after it.
+pub fn hash(input: &[FpBN256], message_size: usize) -> FpBN256 {
+    assert!(message_size <= input.len());
+    let iv = FpBN256::new(&U256Field::from_u128((message_size as u128) << 64));
+    let mut sponge = NoirPoseidon2Sponge::new(iv);
+    for el in &input[..message_size] {
+        sponge.absorb(el);
+    }
+    if message_size != input.len() {
+        sponge.absorb(&FpBN256::ONE);
+    }
+    sponge.squeeze()
+}
+
+#[cfg(test)]
+mod poseidon2_noir_tests {
+    use super::*;
+    use crate::fields::utils::from_hex;
+    use crate::poseidon2::poseidon2::Poseidon2;
+
+    // Test vectors of Barretenberg's `poseidon2.test.cpp`, whose sponge Noir's
+    // `std::hash::poseidon2` mirrors
+    const A: FpBN256 = from_hex("9a807b615c4d3e2fa0b1c2d3e4f56789fedcba9876543210abcdef0123456789");
+
+    #[test]
+    fn permutation_consistency() {
+        let poseidon2 = Poseidon2::new(&POSEIDON2_BN256_T4_PARAMS);
+        assert_eq!(
+            poseidon2.permutation(&[A, A, A, A]),
+            [
+                from_hex("0x2bf1eaf87f7d27e8dc4056e9af975985bccc89077a21891d6c7b6ccce0631f95"),
+                from_hex("0x0c01fa1b8d0748becafbe452c0cb0231c38224ea824554c9362518eebdd5701f"),
+                from_hex("0x018555a8eb50cf07f64b019ebaf3af3c925c93e631f3ecd455db07bbb52bbdd3"),
+                from_hex("0x0cbea457c91c22c6c31fd89afd2541efc2edf31736b9f721e823b2165c90fd41"),
+            ]
+        );
+    }
+
+    #[test]
+    fn hash_consistency() {
+        assert_eq!(
+            hash(&[A, A, A, A], 4),
+            from_hex("0x2f43a0f83b51a6f5fc839dea0ecec74947637802a579fa9841930a25a0bcec11")
+        );
+    }
+
+    #[test]
+    fn variable_length() {
+        let input = [A, A, FpBN256::ZERO, FpBN256::ZERO];
+        let mut sponge = NoirPoseidon2Sponge::new(FpBN256::new(&U256Field::from_u128(2 << 64)));
+        sponge.absorb(&A);
+        sponge.absorb(&A);
+        sponge.absorb(&FpBN256::ONE);
+        assert_eq!(hash(&input, 2), sponge.squeeze());
+        // distinct from the fixed-length hash of the prefix
+        assert_ne!(hash(&input, 2), hash(&input[..2], 2));
+        // the empty input permutes [0, 0, 0, 0]
+        let poseidon2 = Poseidon2::new(&POSEIDON2_BN256_T4_PARAMS);
+        assert_eq!(hash(&[], 0), poseidon2.permutation(&[FpBN256::ZERO; 4])[0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn squeeze_twice() {
+        let mut sponge = NoirPoseidon2Sponge::new(FpBN256::ZERO);
+        sponge.squeeze();
+        sponge.squeeze();
+    }
+}
@@ -1,27 +1,277 @@
-use crate::fields::bn256::FpBN256;
-use core::ops::{AddAssign, MulAssign};
+use super::addition_chain::{MAX_SBOX_DEGREE, addition_chain};
+use super::grain_lfsr::generate_round_constants;
+use super::internal_matrix;
+use super::round_numbers::{self, InsufficientRounds, SecurityLevel};
+use crate::fields::bn256::{FpBN256, ModulusBN254, U256Field};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    format,
+    string::String,
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+use core::fmt;
+use core::num::NonZeroU16;
+use core::ops::{AddAssign, Deref, MulAssign};
+use crypto_bigint::{Encoding, modular::constant_mod::ResidueParams};
+use sha3::{Digest, Sha3_256};
 
 use crate::utils;
 
+/// Errors of fallible parameter construction and permutation calls.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Poseidon2Error {
+    /// The state width is not between 2 and `MAX_WIDTH`.
+    UnsupportedWidth(usize),
+    /// x^d is not a permutation of the field (gcd(d, p - 1) != 1), d < 3 or
+    /// d >= `MAX_SBOX_DEGREE`.
+    UnsupportedSboxDegree(usize),
+    /// The number of full rounds is odd.
+    OddFullRounds(usize),
+    /// `mat_internal_diag_m_1` does not have t entries.
+    InvalidDiagonalLength { expected: usize, got: usize },
+    /// The internal matrix is not `1 + diag(mat_internal_diag_m_1)`, the only
+    /// form the permutation implements.
+    InternalMatrixMismatch,
+    /// The internal diagonal differs from the fixed one of a width with a
+    /// fast internal layer (t = 2, 3).
+    FixedInternalDiagonal(usize),
+    /// The internal matrix is singular.
+    SingularInternalMatrix,
+    /// The external matrix is not t x t, or differs from the fixed matrix of
+    /// a width with a fast external layer.
+    ExternalMatrixMismatch,
+    /// The external matrix is singular.
+    SingularExternalMatrix,
+    /// The dense external matrix admits an infinitely long invariant
+    /// subspace trail, see [`internal_matrix::InternalMatrixReport`].
+    ExternalMatrixSubspaceTrail,
+    /// The number of round-constant rows is not `rounds_f + rounds_p`.
+    InvalidRoundCount { expected: usize, got: usize },
+    /// A round-constant row does not have t entries.
+    InvalidRoundConstantsLength {
+        round: usize,
+        expected: usize,
+        got: usize,
+    },
+    /// A partial round has a non-zero constant outside lane 0.
+    NonZeroPartialRoundConstant { round: usize },
+    /// The rounds are below the bounds of the requested security level.
+    InsufficientRounds(InsufficientRounds),
+    /// A state does not have t elements.
+    InvalidStateLength { expected: usize, got: usize },
+    /// The parameters are for a different width than the permutation type.
+    WidthMismatch { expected: usize, got: usize },
+}
+
+impl fmt::Display for Poseidon2Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Poseidon2Error::UnsupportedWidth(t) => write!(f, "unsupported state width t = {}", t),
+            Poseidon2Error::UnsupportedSboxDegree(d) => {
+                write!(f, "unsupported sbox degree d = {}", d)
+            }
+            Poseidon2Error::OddFullRounds(rounds_f) => {
+                write!(f, "number of full rounds R_F = {} is odd", rounds_f)
+            }
+            Poseidon2Error::InvalidDiagonalLength { expected, got } => write!(
+                f,
+                "internal diagonal has {} entries, expected {}",
+                got, expected
+            ),
+            Poseidon2Error::InternalMatrixMismatch => {
+                write!(f, "internal matrix is not 1 + diag(mat_internal_diag_m_1)")
+            }
+            Poseidon2Error::FixedInternalDiagonal(t) => write!(
+                f,
+                "internal diagonal of t = {} is fixed by its fast internal layer",
+                t
+            ),
+            Poseidon2Error::SingularInternalMatrix => write!(f, "internal matrix is singular"),
+            Poseidon2Error::ExternalMatrixMismatch => {
+                write!(f, "external matrix does not fit the state width")
+            }
+            Poseidon2Error::SingularExternalMatrix => write!(f, "external matrix is singular"),
+            Poseidon2Error::ExternalMatrixSubspaceTrail => {
+                write!(f, "external matrix admits an invariant subspace trail")
+            }
+            Poseidon2Error::InvalidRoundCount { expected, got } => {
+                write!(f, "{} rows of round constants, expected {}", got, expected)
+            }
+            Poseidon2Error::InvalidRoundConstantsLength {
+                round,
+                expected,
+                got,
+            } => write!(
+                f,
+                "round {} has {} round constants, expected {}",
+                round, got, expected
+            ),
+            Poseidon2Error::NonZeroPartialRoundConstant { round } => write!(
+                f,
+                "partial round {} has a non-zero constant outside lane 0",
+                round
+            ),
+            Poseidon2Error::InsufficientRounds(err) => write!(f, "{}", err),
+            Poseidon2Error::InvalidStateLength { expected, got } => {
+                write!(f, "state has {} elements, expected {}", got, expected)
+            }
+            Poseidon2Error::WidthMismatch { expected, got } => {
+                write!(f, "parameters have width {}, expected {}", got, expected)
+            }
+        }
+    }
+}
+
+impl From<InsufficientRounds> for Poseidon2Error {
+    fn from(err: InsufficientRounds) -> Self {
+        Poseidon2Error::InsufficientRounds(err)
+    }
+}
+
+/// Largest supported state width.
+pub const MAX_WIDTH: usize = 24;
+
+/// State widths supported by the permutation, 2 to `MAX_WIDTH`.
+pub(crate) const fn is_supported_width(t: usize) -> bool {
+    t >= 2 && t <= MAX_WIDTH
+}
+
+/// Widths whose external layer has a fast path (t = 2, 3 and multiples of 4);
+/// all others multiply by the dense `mat_external`.
+pub(crate) const fn has_fast_external_layer(t: usize) -> bool {
+    t == 2 || t == 3 || t.is_multiple_of(4)
+}
+
+/// Widths whose internal layer has a fast path (t = 2, 3), which hard-codes
+/// the diagonal [`fixed_mat_internal_diag_m_1`].
+pub(crate) const fn has_fast_internal_layer(t: usize) -> bool {
+    t == 2 || t == 3
+}
+
+/// Internal diagonal of the widths with a fast internal layer: `[1, 2]` for
+/// t = 2 and `[1, 1, 2]` for t = 3.
+pub(crate) fn fixed_mat_internal_diag_m_1(t: usize) -> Option<Vec<FpBN256>> {
+    let two = FpBN256::ONE.add(&FpBN256::ONE);
+    match t {
+        2 => Some(vec![FpBN256::ONE, two]),
+        3 => Some(vec![FpBN256::ONE, FpBN256::ONE, two]),
+        _ => None,
+    }
+}
+
+/// Sbox degrees d for which x^d permutes the field, i.e. gcd(d, p - 1) = 1.
+/// This rules out d = 3 for BN254.
+pub(crate) fn is_supported_sbox_degree(d: usize) -> bool {
+    if !(3..MAX_SBOX_DEGREE).contains(&d) {
+        return false;
+    }
+    let (_, rem) = p_minus_1_div_rem(d);
+
+    let (mut a, mut b) = (d, rem);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a == 1
+}
+
+// (p - 1) / d and (p - 1) mod d for 0 < d < `MAX_SBOX_DEGREE`
+fn p_minus_1_div_rem(d: usize) -> (U256Field, usize) {
+    let p_minus_1 = ModulusBN254::MODULUS.wrapping_sub(&U256Field::ONE);
+    let divisor = NonZeroU16::new(d as u16).expect("d is non-zero").into();
+    let (quo, rem) = p_minus_1.div_rem_limb(divisor);
+    (quo, rem.0 as usize)
+}
+
+// The constant tables are borrowed for the `static` instances, which need no
+// initialization at runtime, and owned for parameters built with `new`.
 #[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        into = "super::poseidon2_params_serde::Poseidon2ParamsRepr",
+        try_from = "super::poseidon2_params_serde::Poseidon2ParamsRepr"
+    )
+)]
 pub struct Poseidon2Params {
     pub(crate) t: usize, // statesize
     pub(crate) d: usize, // sbox degree
+    // addition chain for x^d, see `addition_chain`
+    pub(crate) sbox_chain: Cow<'static, [(usize, usize)]>,
     pub(crate) rounds_f_beginning: usize,
     pub(crate) rounds_p: usize,
     #[allow(dead_code)]
     pub(crate) rounds_f_end: usize,
     pub(crate) rounds: usize,
-    pub(crate) mat_internal_diag_m_1: Vec<FpBN256>,
-    pub(crate) _mat_internal: Vec<Vec<FpBN256>>,
-    pub(crate) round_constants: Vec<Vec<FpBN256>>,
+    // t x t, row-major
+    pub(crate) mat_external: Cow<'static, [FpBN256]>,
+    pub(crate) mat_internal_diag_m_1: Cow<'static, [FpBN256]>,
+    // `rounds` rows of `t` constants, stored contiguously
+    pub(crate) round_constants: Cow<'static, [FpBN256]>,
+    // equivalent constants of the partial rounds: a full vector added before
+    // the first partial round, then one scalar per partial round (but the last)
+    pub(crate) opt_round_constants_first: Cow<'static, [FpBN256]>,
+    pub(crate) opt_round_constants_partial: Cow<'static, [FpBN256]>,
 }
 
-impl Poseidon2Params {
-    #[allow(clippy::too_many_arguments)]
+/// Parameters held by a permutation: either a `static` instance such as
+/// [`super::poseidon2_instance_bn256::POSEIDON2_BN256_PARAMS`], or shared
+/// parameters built at runtime.
+#[derive(Clone, Debug)]
+pub enum Poseidon2ParamsRef {
+    Static(&'static Poseidon2Params),
+    Shared(Arc<Poseidon2Params>),
+}
+
+impl Deref for Poseidon2ParamsRef {
+    type Target = Poseidon2Params;
+
+    fn deref(&self) -> &Poseidon2Params {
+        match self {
+            Poseidon2ParamsRef::Static(params) => params,
+            Poseidon2ParamsRef::Shared(params) => params,
+        }
+    }
+}
+
+impl From<&'static Poseidon2Params> for Poseidon2ParamsRef {
+    fn from(params: &'static Poseidon2Params) -> Self {
+        Poseidon2ParamsRef::Static(params)
+    }
+}
+
+impl From<Arc<Poseidon2Params>> for Poseidon2ParamsRef {
+    fn from(params: Arc<Poseidon2Params>) -> Self {
+        Poseidon2ParamsRef::Shared(params)
+    }
+}
+
+impl From<&Arc<Poseidon2Params>> for Poseidon2ParamsRef {
+    fn from(params: &Arc<Poseidon2Params>) -> Self {
+        Poseidon2ParamsRef::Shared(Arc::clone(params))
+    }
+}
 
+impl Poseidon2Params {
     pub const INIT_SHAKE: &'static str = "Poseidon2";
 
+    /// Security level the round numbers are checked against unless the
+    /// check is skipped explicitly.
+    pub const DEFAULT_SECURITY_LEVEL: SecurityLevel = SecurityLevel::Bits128;
+
+    /// Panicking version of [`Self::try_new`].
+    ///
+    /// # Panics
+    ///
+    /// On every error of [`Self::try_new`], in particular if the round
+    /// numbers are below [`Self::DEFAULT_SECURITY_LEVEL`] or x^d does not
+    /// permute the field (e.g. d = 3). Use the builder's
+    /// [`skip_security_check`](Poseidon2ParamsBuilder::skip_security_check)
+    /// for instances below the default level.
+    #[track_caller]
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         t: usize,
         d: usize,
@@ -31,25 +281,310 @@ impl Poseidon2Params {
         mat_internal: &[Vec<FpBN256>],
         round_constants: &[Vec<FpBN256>],
     ) -> Self {
-        assert!(d == 3 || d == 5 || d == 7 || d == 11);
-        assert_eq!(rounds_f % 2, 0);
+        // a `match` rather than `unwrap_or_else`, so the panic points at the
+        // caller
+        match Self::try_new(
+            t,
+            d,
+            rounds_f,
+            rounds_p,
+            mat_internal_diag_m_1,
+            mat_internal,
+            round_constants,
+        ) {
+            Ok(params) => params,
+            Err(err) => panic!("invalid Poseidon2 parameters: {}", err),
+        }
+    }
+
+    /// Validates the instance and precomputes the equivalent round constants.
+    ///
+    /// The round numbers must meet [`Self::DEFAULT_SECURITY_LEVEL`]; build
+    /// instances below it with [`Poseidon2ParamsBuilder::skip_security_check`].
+    /// `mat_internal` must be `1 + diag(mat_internal_diag_m_1)` and invertible,
+    /// where t = 2, 3 only allow the diagonal of their fast internal layer,
+    /// `round_constants` must have `rounds_f + rounds_p` rows of t constants,
+    /// where partial rounds only use lane 0. The dense
+    /// [default external matrix](Self::default_mat_external) must pass the
+    /// invariant subspace check.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        t: usize,
+        d: usize,
+        rounds_f: usize,
+        rounds_p: usize,
+        mat_internal_diag_m_1: &[FpBN256],
+        mat_internal: &[Vec<FpBN256>],
+        round_constants: &[Vec<FpBN256>],
+    ) -> Result<Self, Poseidon2Error> {
+        Self::try_new_with_security_level(
+            t,
+            d,
+            rounds_f,
+            rounds_p,
+            mat_internal_diag_m_1,
+            mat_internal,
+            round_constants,
+            Some(Self::DEFAULT_SECURITY_LEVEL),
+        )
+    }
+
+    // `try_new`, checking the round numbers against `security_level` (if
+    // any) after all other checks
+    #[allow(clippy::too_many_arguments)]
+    fn try_new_with_security_level(
+        t: usize,
+        d: usize,
+        rounds_f: usize,
+        rounds_p: usize,
+        mat_internal_diag_m_1: &[FpBN256],
+        mat_internal: &[Vec<FpBN256>],
+        round_constants: &[Vec<FpBN256>],
+        security_level: Option<SecurityLevel>,
+    ) -> Result<Self, Poseidon2Error> {
+        Self::check_shape(t, d, rounds_f)?;
+        if mat_internal_diag_m_1.len() != t {
+            return Err(Poseidon2Error::InvalidDiagonalLength {
+                expected: t,
+                got: mat_internal_diag_m_1.len(),
+            });
+        }
+        // the permutation only uses the diagonal, which defines the matrix
+        if mat_internal != Self::mat_internal_from_diag_m_1(mat_internal_diag_m_1) {
+            return Err(Poseidon2Error::InternalMatrixMismatch);
+        }
+        if has_fast_internal_layer(t)
+            && fixed_mat_internal_diag_m_1(t).as_deref() != Some(mat_internal_diag_m_1)
+        {
+            return Err(Poseidon2Error::FixedInternalDiagonal(t));
+        }
+        if !internal_matrix::is_invertible(mat_internal) {
+            return Err(Poseidon2Error::SingularInternalMatrix);
+        }
+
         let r = rounds_f / 2;
         let rounds = rounds_f + rounds_p;
+        if round_constants.len() != rounds {
+            return Err(Poseidon2Error::InvalidRoundCount {
+                expected: rounds,
+                got: round_constants.len(),
+            });
+        }
+        for (round, rc) in round_constants.iter().enumerate() {
+            if rc.len() != t {
+                return Err(Poseidon2Error::InvalidRoundConstantsLength {
+                    round,
+                    expected: t,
+                    got: rc.len(),
+                });
+            }
+            let partial = round >= r && round < r + rounds_p;
+            if partial && rc[1..].iter().any(|c| *c != FpBN256::ZERO) {
+                return Err(Poseidon2Error::NonZeroPartialRoundConstant { round });
+            }
+        }
+
+        if let Some(security_level) = security_level {
+            round_numbers::check_round_numbers(
+                &ModulusBN254::MODULUS,
+                t,
+                d,
+                rounds_f,
+                rounds_p,
+                security_level,
+            )?;
+        }
 
-        Poseidon2Params {
+        let mat_external = Self::default_mat_external(t);
+        Self::check_dense_mat_external(t, &mat_external)?;
+
+        let (opt_round_constants_first, opt_round_constants_partial) = if rounds_p > 0 {
+            let opt = Self::equivalent_round_constants(round_constants, mat_internal, r, rounds_p);
+            let partial = opt[1..rounds_p].iter().map(|c| c[0]).collect();
+            (opt[0].to_owned(), partial)
+        } else {
+            (Vec::new(), Vec::new())
+        };
+
+        Ok(Poseidon2Params {
             t,
             d,
             rounds_f_beginning: r,
             rounds_p,
             rounds_f_end: r,
             rounds,
-            mat_internal_diag_m_1: mat_internal_diag_m_1.to_owned(),
-            _mat_internal: mat_internal.to_owned(),
-            round_constants: round_constants.to_owned(),
+            sbox_chain: Cow::Owned(addition_chain(d)),
+            mat_external: Cow::Owned(mat_external.concat()),
+            mat_internal_diag_m_1: Cow::Owned(mat_internal_diag_m_1.to_owned()),
+            round_constants: Cow::Owned(round_constants.concat()),
+            opt_round_constants_first: Cow::Owned(opt_round_constants_first),
+            opt_round_constants_partial: Cow::Owned(opt_round_constants_partial),
+        })
+    }
+
+    // The widths without a fast external layer multiply by a dense matrix,
+    // which, like the MDS matrices of the Poseidon reference script, must not
+    // admit an infinitely long subspace trail.
+    fn check_dense_mat_external(
+        t: usize,
+        mat_external: &[Vec<FpBN256>],
+    ) -> Result<(), Poseidon2Error> {
+        if !has_fast_external_layer(t) && !internal_matrix::is_invariant_subspace_free(mat_external)
+        {
+            return Err(Poseidon2Error::ExternalMatrixSubspaceTrail);
+        }
+        Ok(())
+    }
+
+    /// Starts a [`Poseidon2ParamsBuilder`].
+    pub fn builder(t: usize, d: usize, rounds_f: usize, rounds_p: usize) -> Poseidon2ParamsBuilder {
+        Poseidon2ParamsBuilder::new(t, d, rounds_f, rounds_p)
+    }
+
+    fn check_shape(t: usize, d: usize, rounds_f: usize) -> Result<(), Poseidon2Error> {
+        if !is_supported_width(t) {
+            return Err(Poseidon2Error::UnsupportedWidth(t));
+        }
+        if !is_supported_sbox_degree(d) {
+            return Err(Poseidon2Error::UnsupportedSboxDegree(d));
+        }
+        if !rounds_f.is_multiple_of(2) {
+            return Err(Poseidon2Error::OddFullRounds(rounds_f));
+        }
+        Ok(())
+    }
+
+    /// Checks `R_F` and `R_P` against the statistical, interpolation and
+    /// Gröbner-basis bounds (including the security margin) for `security_level`.
+    pub fn check_security_level(
+        &self,
+        security_level: SecurityLevel,
+    ) -> Result<(), InsufficientRounds> {
+        round_numbers::check_round_numbers(
+            &ModulusBN254::MODULUS,
+            self.t,
+            self.d,
+            self.rounds_f_beginning + self.rounds_f_end,
+            self.rounds_p,
+            security_level,
+        )
+    }
+
+    /// Identifier of the instance shape, `poseidon2-bn254-t{t}-d{d}-rf{R_F}-rp{R_P}`.
+    /// Instances with the same shape but different constants share it, see
+    /// [`Self::fingerprint`].
+    pub fn id(&self) -> String {
+        format!(
+            "poseidon2-bn254-t{}-d{}-rf{}-rp{}",
+            self.t,
+            self.d,
+            self.rounds_f_beginning + self.rounds_f_end,
+            self.rounds_p
+        )
+    }
+
+    /// SHA3-256 over the domain tag `Poseidon2Params`, t, d, the round
+    /// numbers (as big-endian `u64`s) and all defining constants (the
+    /// external matrix, the internal diagonal and the round constants, as
+    /// 32-byte big-endian integers). Derived tables are not included, as
+    /// they are determined by these.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        let mut hasher = Sha3_256::new();
+        hasher.update(b"Poseidon2Params");
+        for n in [
+            self.t,
+            self.d,
+            self.rounds_f_beginning,
+            self.rounds_p,
+            self.rounds_f_end,
+        ] {
+            hasher.update((n as u64).to_be_bytes());
+        }
+        for el in self
+            .mat_external
+            .iter()
+            .chain(self.mat_internal_diag_m_1.iter())
+            .chain(self.round_constants.iter())
+        {
+            hasher.update(el.retrieve().to_be_bytes());
         }
+        hasher.finalize().into()
     }
 
-    // Unused
+    /// Round constants of round `r`.
+    #[inline]
+    pub fn round_constants(&self, r: usize) -> &[FpBN256] {
+        &self.round_constants[r * self.t..(r + 1) * self.t]
+    }
+
+    /// Exponent `e = d^-1 mod (p - 1)` of the inverse sbox `x^e`.
+    pub fn sbox_inverse_exponent(&self) -> U256Field {
+        // p - 1 = q d + r, then e = (1 + k (p - 1)) / d = k q + (1 + k r) / d
+        // for the k < d with 1 + k r = 0 mod d
+        let d = self.d;
+        let (quo, rem) = p_minus_1_div_rem(d);
+        let k = (0..d)
+            .find(|k| (1 + k * rem).is_multiple_of(d))
+            .expect("d is coprime to p - 1");
+        quo.wrapping_mul(&U256Field::from_u64(k as u64))
+            .wrapping_add(&U256Field::from_u64(((1 + k * rem) / d) as u64))
+    }
+
+    /// The internal matrix `1 + diag(mat_internal_diag_m_1)`.
+    pub fn mat_internal(&self) -> Vec<Vec<FpBN256>> {
+        Self::mat_internal_from_diag_m_1(&self.mat_internal_diag_m_1)
+    }
+
+    /// The external matrix.
+    pub fn mat_external(&self) -> Vec<Vec<FpBN256>> {
+        self.mat_external
+            .chunks_exact(self.t)
+            .map(|row| row.to_vec())
+            .collect()
+    }
+
+    /// External matrix used for width `t`: `circ(2, 1)` and `circ(2, 1, 1)`
+    /// for t = 2, 3, `circ(2 M4, M4, ..., M4)` for multiples of 4 as in the
+    /// paper, and otherwise the Cauchy matrix `1 / (x_i + y_j)` with
+    /// `x_i = i`, `y_j = t + j`. The latter is MDS, as all `x_i + y_j` are
+    /// distinct and non-zero, and construction rejects it (as any dense
+    /// external matrix) if it admits an infinitely long invariant subspace
+    /// trail. Unlike the Poseidon reference script it is fixed rather than
+    /// sampled over Grain-generated `x`, `y`.
+    pub fn default_mat_external(t: usize) -> Vec<Vec<FpBN256>> {
+        let el = |x: u64| FpBN256::new(&U256Field::from_u64(x));
+        match t {
+            2 | 3 => (0..t)
+                .map(|i| (0..t).map(|j| el(1 + (i == j) as u64)).collect())
+                .collect(),
+            _ if t.is_multiple_of(4) => {
+                const M4: [[u64; 4]; 4] = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]];
+                let block_diag = if t == 4 { 1 } else { 2 };
+                (0..t)
+                    .map(|i| {
+                        (0..t)
+                            .map(|j| {
+                                let scale = if i / 4 == j / 4 { block_diag } else { 1 };
+                                el(scale * M4[i % 4][j % 4])
+                            })
+                            .collect()
+                    })
+                    .collect()
+            }
+            _ => (0..t)
+                .map(|i| {
+                    (0..t)
+                        .map(|j| utils::invert_unwrap(&el((i + t + j) as u64)))
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// Moves the partial round constants through the internal linear layer,
+    /// so that all but one scalar per partial round are added up front.
+    /// `opt[0]` is added before the first partial sbox, `opt[i][0]` after the
+    /// sbox of partial round `i - 1`; `opt[rounds_p]` is zero.
     pub fn equivalent_round_constants(
         round_constants: &[Vec<FpBN256>],
         mat_internal: &[Vec<FpBN256>],
@@ -75,6 +610,17 @@ impl Poseidon2Params {
         opt
     }
 
+    /// Internal matrix `1 + diag(mat_internal_diag_m_1)`, i.e. all-ones plus the
+    /// given diagonal.
+    pub fn mat_internal_from_diag_m_1(mat_internal_diag_m_1: &[FpBN256]) -> Vec<Vec<FpBN256>> {
+        let t = mat_internal_diag_m_1.len();
+        let mut mat = vec![vec![FpBN256::ONE; t]; t];
+        for (i, row) in mat.iter_mut().enumerate() {
+            row[i].add_assign(&mat_internal_diag_m_1[i]);
+        }
+        mat
+    }
+
     pub fn mat_vec_mul(mat: &[Vec<FpBN256>], input: &[FpBN256]) -> Vec<FpBN256> {
         let t = mat.len();
         debug_assert!(t == input.len());
@@ -89,3 +635,368 @@ impl Poseidon2Params {
         out
     }
 }
+
+/// Builder for [`Poseidon2Params`].
+///
+/// Round constants default to the Grain LFSR output and the internal diagonal
+/// to the one of the reference implementation (`[1, 2]` for t = 2,
+/// `[1, 1, 2]` for t = 3, sampled with the Grain LFSR for t >= 4). The
+/// external matrix defaults to [`Poseidon2Params::default_mat_external`].
+/// The round numbers are checked against
+/// [`Poseidon2Params::DEFAULT_SECURITY_LEVEL`] unless configured otherwise.
+#[derive(Clone, Debug)]
+pub struct Poseidon2ParamsBuilder {
+    t: usize,
+    d: usize,
+    rounds_f: usize,
+    rounds_p: usize,
+    mat_internal_diag_m_1: Option<Vec<FpBN256>>,
+    mat_external: Option<Vec<Vec<FpBN256>>>,
+    round_constants: Option<Vec<Vec<FpBN256>>>,
+    security_level: Option<SecurityLevel>,
+}
+
+impl Poseidon2ParamsBuilder {
+    pub fn new(t: usize, d: usize, rounds_f: usize, rounds_p: usize) -> Self {
+        Poseidon2ParamsBuilder {
+            t,
+            d,
+            rounds_f,
+            rounds_p,
+            mat_internal_diag_m_1: None,
+            mat_external: None,
+            round_constants: None,
+            security_level: Some(Poseidon2Params::DEFAULT_SECURITY_LEVEL),
+        }
+    }
+
+    /// Internal diagonal, only configurable for widths without a fast
+    /// internal layer (t >= 4).
+    pub fn mat_internal_diag_m_1(mut self, mat_internal_diag_m_1: &[FpBN256]) -> Self {
+        self.mat_internal_diag_m_1 = Some(mat_internal_diag_m_1.to_owned());
+        self
+    }
+
+    /// Dense external matrix, only configurable for widths without a fast
+    /// external layer.
+    pub fn mat_external(mut self, mat_external: &[Vec<FpBN256>]) -> Self {
+        self.mat_external = Some(mat_external.to_owned());
+        self
+    }
+
+    pub fn round_constants(mut self, round_constants: &[Vec<FpBN256>]) -> Self {
+        self.round_constants = Some(round_constants.to_owned());
+        self
+    }
+
+    /// Require the round numbers to meet `security_level` instead of the
+    /// default level.
+    pub fn security_level(mut self, security_level: SecurityLevel) -> Self {
+        self.security_level = Some(security_level);
+        self
+    }
+
+    /// Accept round numbers below every security level, e.g. to reproduce
+    /// an instance of another library.
+    pub fn skip_security_check(mut self) -> Self {
+        self.security_level = None;
+        self
+    }
+
+    pub fn build(self) -> Result<Poseidon2Params, Poseidon2Error> {
+        let (t, d, rounds_f, rounds_p) = (self.t, self.d, self.rounds_f, self.rounds_p);
+        Poseidon2Params::check_shape(t, d, rounds_f)?;
+        if let Some(security_level) = self.security_level {
+            round_numbers::check_round_numbers(
+                &ModulusBN254::MODULUS,
+                t,
+                d,
+                rounds_f,
+                rounds_p,
+                security_level,
+            )?;
+        }
+
+        let mat_internal_diag_m_1 = self.mat_internal_diag_m_1.unwrap_or_else(|| {
+            fixed_mat_internal_diag_m_1(t).unwrap_or_else(|| {
+                internal_matrix::generate_mat_internal_diag_m_1(t, rounds_f, rounds_p)
+            })
+        });
+        let round_constants = self
+            .round_constants
+            .unwrap_or_else(|| generate_round_constants(t, rounds_f, rounds_p));
+        if mat_internal_diag_m_1.len() != t {
+            return Err(Poseidon2Error::InvalidDiagonalLength {
+                expected: t,
+                got: mat_internal_diag_m_1.len(),
+            });
+        }
+        let mat_internal = Poseidon2Params::mat_internal_from_diag_m_1(&mat_internal_diag_m_1);
+
+        // the round numbers were checked above, before generating constants
+        let mut params = Poseidon2Params::try_new_with_security_level(
+            t,
+            d,
+            rounds_f,
+            rounds_p,
+            &mat_internal_diag_m_1,
+            &mat_internal,
+            &round_constants,
+            None,
+        )?;
+        if let Some(mat_external) = self.mat_external {
+            if mat_external.len() != t || mat_external.iter().any(|row| row.len() != t) {
+                return Err(Poseidon2Error::ExternalMatrixMismatch);
+            }
+            if has_fast_external_layer(t) && mat_external != params.mat_external() {
+                return Err(Poseidon2Error::ExternalMatrixMismatch);
+            }
+            if !internal_matrix::is_invertible(&mat_external) {
+                return Err(Poseidon2Error::SingularExternalMatrix);
+            }
+            Poseidon2Params::check_dense_mat_external(t, &mat_external)?;
+            params.mat_external = Cow::Owned(mat_external.concat());
+        }
+        Ok(params)
+    }
+}
+
+#[cfg(test)]
+mod poseidon2_params_tests {
+    use super::*;
+    use crate::poseidon2::poseidon2_instance_bn256::{MAT_DIAG3_M_1, POSEIDON2_BN256_PARAMS, RC3};
+
+    fn rc3() -> Vec<Vec<FpBN256>> {
+        RC3.iter().map(|r| r.to_vec()).collect()
+    }
+
+    #[test]
+    fn try_new_validates() {
+        let diag = MAT_DIAG3_M_1.to_vec();
+        let mat = Poseidon2Params::mat_internal_from_diag_m_1(&diag);
+        let rc = rc3();
+        let try_new = |t,
+                       d,
+                       rounds_f,
+                       rounds_p,
+                       diag: &[FpBN256],
+                       mat: &[Vec<FpBN256>],
+                       rc: &[Vec<FpBN256>]| {
+            Poseidon2Params::try_new(t, d, rounds_f, rounds_p, diag, mat, rc).map(|_| ())
+        };
+
+        assert_eq!(try_new(3, 5, 8, 56, &diag, &mat, &rc), Ok(()));
+        assert_eq!(
+            try_new(25, 5, 8, 56, &diag, &mat, &rc),
+            Err(Poseidon2Error::UnsupportedWidth(25))
+        );
+        assert_eq!(
+            try_new(3, 4, 8, 56, &diag, &mat, &rc),
+            Err(Poseidon2Error::UnsupportedSboxDegree(4))
+        );
+        // 3 and 13 divide p - 1, so x^3 and x^13 are not permutations
+        assert_eq!(
+            try_new(3, 3, 8, 56, &diag, &mat, &rc),
+            Err(Poseidon2Error::UnsupportedSboxDegree(3))
+        );
+        assert_eq!(
+            try_new(3, 13, 8, 56, &diag, &mat, &rc),
+            Err(Poseidon2Error::UnsupportedSboxDegree(13))
+        );
+        assert_eq!(try_new(3, 11, 8, 56, &diag, &mat, &rc), Ok(()));
+        assert_eq!(
+            try_new(3, 5, 7, 57, &diag, &mat, &rc),
+            Err(Poseidon2Error::OddFullRounds(7))
+        );
+        assert_eq!(
+            try_new(3, 5, 8, 56, &diag[..2], &mat, &rc),
+            Err(Poseidon2Error::InvalidDiagonalLength {
+                expected: 3,
+                got: 2
+            })
+        );
+        let other = Poseidon2Params::mat_internal_from_diag_m_1(&[FpBN256::ONE; 3]);
+        assert_eq!(
+            try_new(3, 5, 8, 56, &diag, &other, &rc),
+            Err(Poseidon2Error::InternalMatrixMismatch)
+        );
+        // the fast internal layer of t = 3 hard-codes its diagonal
+        let two = FpBN256::ONE.add(&FpBN256::ONE);
+        let custom = [FpBN256::ONE, two, two.add(&two)];
+        let custom_mat = Poseidon2Params::mat_internal_from_diag_m_1(&custom);
+        assert_eq!(
+            try_new(3, 5, 8, 56, &custom, &custom_mat, &rc),
+            Err(Poseidon2Error::FixedInternalDiagonal(3))
+        );
+        // 1 + diag(-1, -1, -1, -1) is invertible, but has a zero leading entry
+        let rc4 = generate_round_constants(4, 8, 56);
+        let zero_diag = [FpBN256::ONE.neg(); 4];
+        let zero_diag_mat = Poseidon2Params::mat_internal_from_diag_m_1(&zero_diag);
+        assert_eq!(
+            try_new(4, 5, 8, 56, &zero_diag, &zero_diag_mat, &rc4),
+            Ok(())
+        );
+        // 1 + diag(0, 0, 0, 1) has three equal rows
+        let singular = [FpBN256::ZERO, FpBN256::ZERO, FpBN256::ZERO, FpBN256::ONE];
+        let singular_mat = Poseidon2Params::mat_internal_from_diag_m_1(&singular);
+        assert_eq!(
+            try_new(4, 5, 8, 56, &singular, &singular_mat, &rc4),
+            Err(Poseidon2Error::SingularInternalMatrix)
+        );
+        assert_eq!(
+            try_new(3, 5, 8, 55, &diag, &mat, &rc),
+            Err(Poseidon2Error::InvalidRoundCount {
+                expected: 63,
+                got: 64
+            })
+        );
+        let mut short = rc.clone();
+        short[10].pop();
+        assert_eq!(
+            try_new(3, 5, 8, 56, &diag, &mat, &short),
+            Err(Poseidon2Error::InvalidRoundConstantsLength {
+                round: 10,
+                expected: 3,
+                got: 2
+            })
+        );
+        let mut dense = rc.clone();
+        dense[4][2] = FpBN256::ONE;
+        assert_eq!(
+            try_new(3, 5, 8, 56, &diag, &mat, &dense),
+            Err(Poseidon2Error::NonZeroPartialRoundConstant { round: 4 })
+        );
+        let mut fewer = rc.clone();
+        fewer.truncate(48);
+        assert!(matches!(
+            try_new(3, 5, 8, 40, &diag, &mat, &fewer),
+            Err(Poseidon2Error::InsufficientRounds(_))
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid Poseidon2 parameters")]
+    fn new_panics_on_invalid_params() {
+        let diag = MAT_DIAG3_M_1.to_vec();
+        let mat = Poseidon2Params::mat_internal_from_diag_m_1(&diag);
+        Poseidon2Params::new(3, 3, 8, 56, &diag, &mat, &rc3());
+    }
+
+    #[test]
+    fn default_mat_external() {
+        for t in 2..=MAX_WIDTH {
+            let mat = Poseidon2Params::default_mat_external(t);
+            assert!(internal_matrix::is_invertible(&mat), "t = {}", t);
+            assert_eq!(Poseidon2Params::check_dense_mat_external(t, &mat), Ok(()));
+        }
+    }
+
+    #[test]
+    fn builder() {
+        let params = Poseidon2Params::builder(3, 5, 8, 56)
+            .security_level(SecurityLevel::Bits128)
+            .build()
+            .unwrap();
+        assert_eq!(
+            params.round_constants,
+            POSEIDON2_BN256_PARAMS.round_constants
+        );
+        assert_eq!(
+            params.mat_internal_diag_m_1,
+            POSEIDON2_BN256_PARAMS.mat_internal_diag_m_1
+        );
+        assert_eq!(
+            params.opt_round_constants_partial,
+            POSEIDON2_BN256_PARAMS.opt_round_constants_partial
+        );
+
+        let explicit = Poseidon2Params::builder(3, 5, 8, 56)
+            .mat_internal_diag_m_1(&MAT_DIAG3_M_1)
+            .round_constants(&rc3())
+            .build()
+            .unwrap();
+        assert_eq!(explicit.round_constants, params.round_constants);
+
+        // 128-bit security is required by default
+        let err = Poseidon2Params::builder(3, 5, 8, 40).build().unwrap_err();
+        assert!(matches!(err, Poseidon2Error::InsufficientRounds(_)));
+        let err = Poseidon2Params::builder(3, 5, 8, 56)
+            .security_level(SecurityLevel::Bits256)
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, Poseidon2Error::InsufficientRounds(_)));
+        let insecure = Poseidon2Params::builder(3, 5, 8, 40)
+            .skip_security_check()
+            .build()
+            .unwrap();
+        assert_eq!(insecure.rounds_p, 40);
+
+        // the external matrix is only configurable without a fast path
+        let cauchy5 = Poseidon2Params::default_mat_external(5);
+        let mut swapped = cauchy5.clone();
+        swapped.swap(0, 1);
+        let params = Poseidon2Params::builder(5, 5, 8, 56)
+            .mat_external(&swapped)
+            .build()
+            .unwrap();
+        assert_eq!(params.mat_external(), swapped);
+        let mut singular = cauchy5.clone();
+        singular[1] = singular[0].clone();
+        assert_eq!(
+            Poseidon2Params::builder(5, 5, 8, 56)
+                .mat_external(&singular)
+                .build()
+                .unwrap_err(),
+            Poseidon2Error::SingularExternalMatrix
+        );
+        assert_eq!(
+            Poseidon2Params::builder(5, 5, 8, 56)
+                .mat_external(&cauchy5[1..])
+                .build()
+                .unwrap_err(),
+            Poseidon2Error::ExternalMatrixMismatch
+        );
+        // 2 I is invertible, but never mixes lane 0 into the others
+        let two = FpBN256::ONE.add(&FpBN256::ONE);
+        let diagonal: Vec<Vec<FpBN256>> = (0..5)
+            .map(|i| {
+                (0..5)
+                    .map(|j| if i == j { two } else { FpBN256::ZERO })
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            Poseidon2Params::builder(5, 5, 8, 56)
+                .mat_external(&diagonal)
+                .build()
+                .unwrap_err(),
+            Poseidon2Error::ExternalMatrixSubspaceTrail
+        );
+        assert_eq!(
+            Poseidon2Params::builder(4, 5, 8, 56)
+                .mat_external(&Poseidon2Params::default_mat_external(4)[..])
+                .build()
+                .map(|_| ()),
+            Ok(())
+        );
+        let mut m4 = Poseidon2Params::default_mat_external(4);
+        m4.swap(0, 1);
+        assert_eq!(
+            Poseidon2Params::builder(4, 5, 8, 56)
+                .mat_external(&m4)
+                .build()
+                .unwrap_err(),
+            Poseidon2Error::ExternalMatrixMismatch
+        );
+        assert_eq!(
+            Poseidon2Params::builder(3, 5, 8, 56)
+                .round_constants(&rc3()[1..])
+                .build()
+                .unwrap_err(),
+            Poseidon2Error::InvalidRoundCount {
+                expected: 64,
+                got: 63
+            }
+        );
+    }
+}
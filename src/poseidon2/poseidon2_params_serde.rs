@@ -0,0 +1,137 @@
+//! Serde encoding of [`Poseidon2Params`]: the defining parameters and tables
+//! with hex-encoded field elements. The derived tables are recomputed and
+//! the instance is validated with [`Poseidon2Params::builder`] on load,
+//! including the default security check.
+//!
+//! ```json
+//! {
+//!   "t": 3,
+//!   "d": 5,
+//!   "rounds_f": 8,
+//!   "rounds_p": 56,
+//!   "mat_internal_diag_m_1": ["0x01...", ...],
+//!   "mat_external": [["0x02...", ...], ...],
+//!   "round_constants": [["0x1d06...", ...], ...]
+//! }
+//! ```
+//!
+//! `mat_external` may be omitted, which selects
+//! [`Poseidon2Params::default_mat_external`].
+
+use super::poseidon2_params::{Poseidon2Error, Poseidon2Params};
+use crate::fields::{bn256::FpBN256, serde_hex};
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct Poseidon2ParamsRepr {
+    t: usize,
+    d: usize,
+    rounds_f: usize,
+    rounds_p: usize,
+    #[serde(with = "serde_hex::vec")]
+    mat_internal_diag_m_1: Vec<FpBN256>,
+    #[serde(default, with = "serde_hex::matrix")]
+    mat_external: Vec<Vec<FpBN256>>,
+    #[serde(with = "serde_hex::matrix")]
+    round_constants: Vec<Vec<FpBN256>>,
+}
+
+impl From<Poseidon2Params> for Poseidon2ParamsRepr {
+    fn from(params: Poseidon2Params) -> Self {
+        Poseidon2ParamsRepr {
+            t: params.t,
+            d: params.d,
+            rounds_f: params.rounds_f_beginning + params.rounds_f_end,
+            rounds_p: params.rounds_p,
+            mat_internal_diag_m_1: params.mat_internal_diag_m_1.to_vec(),
+            mat_external: params.mat_external(),
+            round_constants: params
+                .round_constants
+                .chunks_exact(params.t)
+                .map(|row| row.to_vec())
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<Poseidon2ParamsRepr> for Poseidon2Params {
+    type Error = Poseidon2Error;
+
+    fn try_from(repr: Poseidon2ParamsRepr) -> Result<Self, Poseidon2Error> {
+        let mut builder = Poseidon2Params::builder(repr.t, repr.d, repr.rounds_f, repr.rounds_p)
+            .mat_internal_diag_m_1(&repr.mat_internal_diag_m_1)
+            .round_constants(&repr.round_constants);
+        if !repr.mat_external.is_empty() {
+            builder = builder.mat_external(&repr.mat_external);
+        }
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod poseidon2_params_serde_tests {
+    use super::*;
+    use crate::fields::utils::to_hex;
+    use crate::poseidon2::poseidon2_instance_bn256::POSEIDON2_BN256_PARAMS;
+    use alloc::string::ToString;
+
+    fn assert_same(a: &Poseidon2Params, b: &Poseidon2Params) {
+        assert_eq!(
+            (a.t, a.d, a.rounds, a.rounds_p),
+            (b.t, b.d, b.rounds, b.rounds_p)
+        );
+        assert_eq!(a.mat_internal_diag_m_1, b.mat_internal_diag_m_1);
+        assert_eq!(a.mat_external, b.mat_external);
+        assert_eq!(a.round_constants, b.round_constants);
+        assert_eq!(a.opt_round_constants_first, b.opt_round_constants_first);
+        assert_eq!(a.opt_round_constants_partial, b.opt_round_constants_partial);
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let json = serde_json::to_string(&POSEIDON2_BN256_PARAMS).unwrap();
+        assert!(json.contains(&to_hex(&POSEIDON2_BN256_PARAMS.round_constants[0])));
+        let params: Poseidon2Params = serde_json::from_str(&json).unwrap();
+        assert_same(&params, &POSEIDON2_BN256_PARAMS);
+
+        // a width with a dense external matrix
+        let cauchy = Poseidon2Params::default_mat_external(5);
+        let mut swapped = cauchy.clone();
+        swapped.swap(0, 1);
+        let t5 = Poseidon2Params::builder(5, 5, 8, 56)
+            .mat_external(&swapped)
+            .build()
+            .unwrap();
+        let json = serde_json::to_string(&t5).unwrap();
+        assert_same(&serde_json::from_str(&json).unwrap(), &t5);
+    }
+
+    #[test]
+    fn validated_on_load() {
+        let mut value = serde_json::to_value(&POSEIDON2_BN256_PARAMS).unwrap();
+        value.as_object_mut().unwrap().remove("mat_external");
+        let params: Poseidon2Params = serde_json::from_value(value.clone()).unwrap();
+        assert_same(&params, &POSEIDON2_BN256_PARAMS);
+
+        let mut bad = value.clone();
+        bad["rounds_p"] = 60.into();
+        let err = serde_json::from_value::<Poseidon2Params>(bad).unwrap_err();
+        let expected = Poseidon2Error::InvalidRoundCount {
+            expected: 68,
+            got: 64,
+        };
+        assert!(err.to_string().contains(&expected.to_string()));
+
+        let mut bad = value.clone();
+        bad["round_constants"][0][0] = "0xzz".into();
+        assert!(serde_json::from_value::<Poseidon2Params>(bad).is_err());
+
+        // a constant plus p would load to the same parameters
+        let mut bad = value;
+        bad["mat_internal_diag_m_1"][0] =
+            "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000002".into();
+        let err = serde_json::from_value::<Poseidon2Params>(bad).unwrap_err();
+        assert!(err.to_string().contains("non-canonical"));
+    }
+}
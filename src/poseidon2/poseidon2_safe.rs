@@ -0,0 +1,308 @@
+use super::poseidon2::Poseidon2;
+use super::poseidon2_params::Poseidon2ParamsRef;
+use crate::fields::bn256::{FpBN256, ModMathInPlace, U256Field};
+use alloc::{borrow::ToOwned, vec, vec::Vec};
+use core::fmt;
+use sha3::{Digest, Sha3_256};
+
+/// A single call in a SAFE IO pattern.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpongeOp {
+    Absorb(u32),
+    Squeeze(u32),
+}
+
+impl SpongeOp {
+    const ABSORB_FLAG: u32 = 1 << 31;
+
+    fn len(&self) -> u32 {
+        match self {
+            SpongeOp::Absorb(n) | SpongeOp::Squeeze(n) => *n,
+        }
+    }
+
+    /// Length of a call of `n` elements as a pattern entry. Lengths beyond
+    /// `u32` map to `u32::MAX`, which no valid pattern contains, so such a
+    /// call is rejected instead of aliasing a shorter entry.
+    fn call_len(n: usize) -> u32 {
+        u32::try_from(n).unwrap_or(u32::MAX)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SafeError {
+    /// The IO pattern is empty or contains a call of length 0 or >= 2^31.
+    InvalidIoPattern,
+    /// A call does not match the next entry of the declared IO pattern.
+    UnexpectedCall {
+        expected: Option<SpongeOp>,
+        got: SpongeOp,
+    },
+    /// `finish` was called before the whole IO pattern was consumed.
+    IoPatternNotFinished,
+}
+
+impl fmt::Display for SafeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SafeError::InvalidIoPattern => write!(f, "invalid IO pattern"),
+            SafeError::UnexpectedCall { expected, got } => {
+                write!(f, "unexpected call {:?}, expected {:?}", got, expected)
+            }
+            SafeError::IoPatternNotFinished => write!(f, "IO pattern not finished"),
+        }
+    }
+}
+
+/// Sponge following the SAFE (Sponge API for Field Elements) specification.
+///
+/// The caller declares the IO pattern up front. A 128-bit tag derived from the
+/// pattern and a domain separator is written into the capacity element, and
+/// every call is checked against the pattern. On any deviation the state is
+/// erased and the sponge refuses further use.
+#[derive(Clone, Debug)]
+pub struct SafeSponge {
+    perm: Poseidon2,
+    rate: usize,
+    state: Vec<FpBN256>,
+    absorb_pos: usize,
+    squeeze_pos: usize,
+    io_pattern: Vec<SpongeOp>,
+    io_count: usize,
+    aborted: bool,
+}
+
+impl SafeSponge {
+    /// Starts a new sponge with rate `t - 1` and a single capacity element.
+    pub fn start(
+        params: impl Into<Poseidon2ParamsRef>,
+        io_pattern: &[SpongeOp],
+        domain_separator: &[u8],
+    ) -> Result<Self, SafeError> {
+        let perm = Poseidon2::new(params);
+        let t = perm.get_t();
+        let rate = t - 1;
+        let mut state = vec![FpBN256::ZERO; t];
+        state[rate] = Self::tag(io_pattern, domain_separator)?;
+
+        Ok(SafeSponge {
+            perm,
+            rate,
+            state,
+            absorb_pos: 0,
+            squeeze_pos: 0,
+            io_pattern: io_pattern.to_owned(),
+            io_count: 0,
+            aborted: false,
+        })
+    }
+
+    /// Computes the SAFE tag: consecutive calls of the same kind are aggregated,
+    /// encoded as 32-bit big-endian words (absorb calls with the MSB set), the
+    /// domain separator is appended and the first 128 bits of the SHA3-256 digest
+    /// are interpreted as a big-endian integer.
+    pub fn tag(io_pattern: &[SpongeOp], domain_separator: &[u8]) -> Result<FpBN256, SafeError> {
+        if io_pattern.is_empty()
+            || io_pattern
+                .iter()
+                .any(|op| op.len() == 0 || op.len() >= SpongeOp::ABSORB_FLAG)
+        {
+            return Err(SafeError::InvalidIoPattern);
+        }
+
+        let mut runs: Vec<(bool, u64)> = Vec::with_capacity(io_pattern.len());
+        for op in io_pattern {
+            let absorb = matches!(op, SpongeOp::Absorb(_));
+            match runs.last_mut() {
+                Some((a, len)) if *a == absorb => *len += op.len() as u64,
+                _ => runs.push((absorb, op.len() as u64)),
+            }
+        }
+
+        let mut hasher = Sha3_256::new();
+        for (absorb, len) in runs {
+            if len >= SpongeOp::ABSORB_FLAG as u64 {
+                return Err(SafeError::InvalidIoPattern);
+            }
+            let word = if absorb {
+                SpongeOp::ABSORB_FLAG | len as u32
+            } else {
+                len as u32
+            };
+            hasher.update(word.to_be_bytes());
+        }
+        hasher.update(domain_separator);
+        let digest = hasher.finalize();
+
+        let mut bytes = [0u8; 32];
+        bytes[16..].copy_from_slice(&digest[..16]);
+        Ok(FpBN256::new(&U256Field::from_be_slice(&bytes)))
+    }
+
+    /// Absorbs `input`; must match the next `Absorb(input.len())` of the pattern.
+    pub fn absorb(&mut self, input: &[FpBN256]) -> Result<(), SafeError> {
+        self.next_op(SpongeOp::Absorb(SpongeOp::call_len(input.len())))?;
+        for el in input {
+            if self.absorb_pos == self.rate {
+                self.permute();
+                self.absorb_pos = 0;
+            }
+            self.state[self.absorb_pos].add_in_place(el);
+            self.absorb_pos += 1;
+        }
+        self.squeeze_pos = self.rate;
+        Ok(())
+    }
+
+    /// Squeezes `n` elements; must match the next `Squeeze(n)` of the pattern.
+    pub fn squeeze(&mut self, n: usize) -> Result<Vec<FpBN256>, SafeError> {
+        self.next_op(SpongeOp::Squeeze(SpongeOp::call_len(n)))?;
+        let mut out = Vec::with_capacity(n);
+        for _ in 0..n {
+            if self.squeeze_pos == self.rate {
+                self.permute();
+                self.squeeze_pos = 0;
+                self.absorb_pos = 0;
+            }
+            out.push(self.state[self.squeeze_pos]);
+            self.squeeze_pos += 1;
+        }
+        Ok(out)
+    }
+
+    /// Checks that the whole IO pattern was consumed and erases the state.
+    pub fn finish(mut self) -> Result<(), SafeError> {
+        let done = !self.aborted && self.io_count == self.io_pattern.len();
+        self.erase();
+        if done {
+            Ok(())
+        } else {
+            Err(SafeError::IoPatternNotFinished)
+        }
+    }
+
+    fn next_op(&mut self, got: SpongeOp) -> Result<(), SafeError> {
+        let expected = if self.aborted {
+            None
+        } else {
+            self.io_pattern.get(self.io_count).copied()
+        };
+        if expected != Some(got) {
+            self.aborted = true;
+            self.erase();
+            return Err(SafeError::UnexpectedCall { expected, got });
+        }
+        self.io_count += 1;
+        Ok(())
+    }
+
+    fn erase(&mut self) {
+        self.state.iter_mut().for_each(|s| *s = FpBN256::ZERO);
+    }
+
+    fn permute(&mut self) {
+        self.state = self.perm.permutation(&self.state);
+    }
+}
+
+#[cfg(test)]
+mod poseidon2_safe_tests {
+    use super::*;
+    use crate::{
+        fields::utils::from_hex, poseidon2::poseidon2_instance_bn256::POSEIDON2_BN256_PARAMS,
+    };
+    use core::ops::AddAssign;
+
+    type Scalar = FpBN256;
+
+    const PATTERN: [SpongeOp; 5] = [
+        SpongeOp::Absorb(2),
+        SpongeOp::Absorb(1),
+        SpongeOp::Squeeze(1),
+        SpongeOp::Absorb(2),
+        SpongeOp::Squeeze(2),
+    ];
+
+    fn scalar(i: u64) -> Scalar {
+        Scalar::new(&U256Field::from_u64(i))
+    }
+
+    #[test]
+    fn tag_kat() {
+        // aggregated to ABSORB(3), SQUEEZE(1), ABSORB(2), SQUEEZE(2)
+        let tag = SafeSponge::tag(&PATTERN, b"test").unwrap();
+        assert_eq!(tag, from_hex("0xdbe9987cfce2f9ba133462418b5f2227"));
+        assert_ne!(tag, SafeSponge::tag(&PATTERN, b"other").unwrap());
+        assert_eq!(
+            SafeSponge::tag(&[SpongeOp::Absorb(0)], b""),
+            Err(SafeError::InvalidIoPattern)
+        );
+        assert_eq!(SafeSponge::tag(&[], b""), Err(SafeError::InvalidIoPattern));
+    }
+
+    #[test]
+    fn follows_io_pattern() {
+        let poseidon2 = Poseidon2::new(&POSEIDON2_BN256_PARAMS);
+        let tag = SafeSponge::tag(&PATTERN, b"test").unwrap();
+
+        let mut sponge = SafeSponge::start(&POSEIDON2_BN256_PARAMS, &PATTERN, b"test").unwrap();
+        sponge.absorb(&[scalar(1), scalar(2)]).unwrap();
+        sponge.absorb(&[scalar(3)]).unwrap();
+        let out1 = sponge.squeeze(1).unwrap();
+        sponge.absorb(&[scalar(4), scalar(5)]).unwrap();
+        let out2 = sponge.squeeze(2).unwrap();
+        sponge.finish().unwrap();
+
+        let mut state = poseidon2.permutation(&[scalar(1), scalar(2), tag]);
+        state[0].add_assign(&scalar(3));
+        state = poseidon2.permutation(&state);
+        assert_eq!(out1, vec![state[0]]);
+        state[0].add_assign(&scalar(4));
+        state[1].add_assign(&scalar(5));
+        state = poseidon2.permutation(&state);
+        assert_eq!(out2, state[..2].to_vec());
+    }
+
+    #[test]
+    fn rejects_deviations() {
+        let mut sponge = SafeSponge::start(&POSEIDON2_BN256_PARAMS, &PATTERN, b"test").unwrap();
+        assert_eq!(
+            sponge.squeeze(1),
+            Err(SafeError::UnexpectedCall {
+                expected: Some(SpongeOp::Absorb(2)),
+                got: SpongeOp::Squeeze(1)
+            })
+        );
+        // aborted sponges stay unusable
+        assert!(sponge.absorb(&[scalar(1), scalar(2)]).is_err());
+
+        let mut sponge = SafeSponge::start(&POSEIDON2_BN256_PARAMS, &PATTERN, b"test").unwrap();
+        assert!(sponge.absorb(&[scalar(1)]).is_err());
+
+        let mut sponge = SafeSponge::start(&POSEIDON2_BN256_PARAMS, &PATTERN, b"test").unwrap();
+        sponge.absorb(&[scalar(1), scalar(2)]).unwrap();
+        assert_eq!(sponge.finish(), Err(SafeError::IoPatternNotFinished));
+    }
+
+    #[test]
+    fn call_len_does_not_truncate() {
+        assert_eq!(SpongeOp::call_len(3), 3);
+        assert_eq!(SpongeOp::call_len(u32::MAX as usize), u32::MAX);
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert_eq!(SpongeOp::call_len(1 << 32), u32::MAX);
+            assert_eq!(SpongeOp::call_len((1 << 32) + 2), u32::MAX);
+        }
+
+        // an overlong squeeze fails the pattern check instead of aliasing
+        let mut sponge =
+            SafeSponge::start(&POSEIDON2_BN256_PARAMS, &[SpongeOp::Squeeze(2)], b"test").unwrap();
+        assert_eq!(
+            sponge.next_op(SpongeOp::Squeeze(SpongeOp::call_len(usize::MAX))),
+            Err(SafeError::UnexpectedCall {
+                expected: Some(SpongeOp::Squeeze(2)),
+                got: SpongeOp::Squeeze(u32::MAX)
+            })
+        );
+    }
+}
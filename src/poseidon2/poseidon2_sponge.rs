@@ -0,0 +1,157 @@
+use super::poseidon2::Poseidon2;
+use super::poseidon2_params::Poseidon2ParamsRef;
+use crate::fields::bn256::{FpBN256, ModMathInPlace};
+use alloc::{vec, vec::Vec};
+
+/// Sponge construction on top of the Poseidon2 permutation.
+///
+/// The first `rate` lanes of the state are absorbed into and squeezed from, the
+/// remaining `t - rate` lanes form the capacity. On finalization the input is
+/// padded with a single one followed by zeros up to the next block boundary
+/// (10*-padding), so inputs of different lengths never collide.
+#[derive(Clone, Debug)]
+pub struct Poseidon2Sponge {
+    perm: Poseidon2,
+    rate: usize,
+    state: Vec<FpBN256>,
+    pos: usize,
+    squeezing: bool,
+}
+
+impl Poseidon2Sponge {
+    /// Creates a sponge with the given rate; the capacity is `t - rate`.
+    /// For the t=3 `POSEIDON2_BN256_PARAMS` use rate 2 / capacity 1.
+    pub fn new(params: impl Into<Poseidon2ParamsRef>, rate: usize) -> Self {
+        let perm = Poseidon2::new(params);
+        let t = perm.get_t();
+        assert!(rate > 0 && rate < t, "rate must be in 1..t");
+        Poseidon2Sponge {
+            perm,
+            rate,
+            state: vec![FpBN256::ZERO; t],
+            pos: 0,
+            squeezing: false,
+        }
+    }
+
+    pub fn get_rate(&self) -> usize {
+        self.rate
+    }
+
+    pub fn get_capacity(&self) -> usize {
+        self.state.len() - self.rate
+    }
+
+    /// Absorbs `input` into the sponge. Panics if called after squeezing started.
+    pub fn absorb(&mut self, input: &[FpBN256]) {
+        assert!(!self.squeezing, "absorb after squeeze");
+        for el in input {
+            if self.pos == self.rate {
+                self.permute();
+                self.pos = 0;
+            }
+            self.state[self.pos].add_in_place(el);
+            self.pos += 1;
+        }
+    }
+
+    /// Applies the padding and switches the sponge into squeezing mode.
+    /// Calling it more than once has no effect.
+    pub fn finalize(&mut self) {
+        if self.squeezing {
+            return;
+        }
+        if self.pos == self.rate {
+            self.permute();
+            self.pos = 0;
+        }
+        self.state[self.pos].add_in_place(&FpBN256::ONE);
+        self.permute();
+        self.pos = 0;
+        self.squeezing = true;
+    }
+
+    /// Squeezes `n` field elements, finalizing the sponge first if needed.
+    pub fn squeeze(&mut self, n: usize) -> Vec<FpBN256> {
+        self.finalize();
+        let mut out = Vec::with_capacity(n);
+        for _ in 0..n {
+            if self.pos == self.rate {
+                self.permute();
+                self.pos = 0;
+            }
+            out.push(self.state[self.pos]);
+            self.pos += 1;
+        }
+        out
+    }
+
+    fn permute(&mut self) {
+        self.state = self.perm.permutation(&self.state);
+    }
+}
+
+#[cfg(test)]
+mod poseidon2_sponge_tests {
+    use super::*;
+    use crate::{
+        fields::bn256::U256Field, poseidon2::poseidon2_instance_bn256::POSEIDON2_BN256_PARAMS,
+    };
+    use core::ops::AddAssign;
+
+    type Scalar = FpBN256;
+
+    fn scalars(n: usize) -> Vec<Scalar> {
+        (0..n)
+            .map(|i| Scalar::new(&U256Field::from_u64(i as u64 + 1)))
+            .collect()
+    }
+
+    fn hash(input: &[Scalar], n: usize) -> Vec<Scalar> {
+        let mut sponge = Poseidon2Sponge::new(&POSEIDON2_BN256_PARAMS, 2);
+        sponge.absorb(input);
+        sponge.squeeze(n)
+    }
+
+    #[test]
+    fn padding_is_injective() {
+        let a = scalars(1);
+        let mut a0 = a.clone();
+        a0.push(Scalar::ZERO);
+        assert_ne!(hash(&a, 1), hash(&a0, 1));
+        assert_ne!(hash(&[], 1), hash(&[Scalar::ZERO], 1));
+        // full block vs. the same block followed by the padding element
+        let b = scalars(2);
+        let mut b1 = b.clone();
+        b1.push(Scalar::ONE);
+        assert_ne!(hash(&b, 1), hash(&b1, 1));
+    }
+
+    #[test]
+    fn incremental_absorb() {
+        let input = scalars(7);
+        let mut sponge = Poseidon2Sponge::new(&POSEIDON2_BN256_PARAMS, 2);
+        sponge.absorb(&input[..3]);
+        sponge.absorb(&[]);
+        sponge.absorb(&input[3..]);
+        let mut out = sponge.squeeze(1);
+        out.extend(sponge.squeeze(4));
+        assert_eq!(out, hash(&input, 5));
+    }
+
+    #[test]
+    fn matches_manual_permutation() {
+        let poseidon2 = Poseidon2::new(&POSEIDON2_BN256_PARAMS);
+        let input = scalars(2);
+
+        let mut state = poseidon2.permutation(&[input[0], input[1], Scalar::ZERO]);
+        state[0].add_assign(&Scalar::ONE);
+        state = poseidon2.permutation(&state);
+        let first = state[..2].to_vec();
+        state = poseidon2.permutation(&state);
+
+        let out = hash(&input, 3);
+        assert_eq!(out[..2], first[..]);
+        assert_eq!(out[2], state[0]);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::fields::bn256::FpBN256;
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum RoundType {
+    Full,
+    Partial,
+}
+
+/// Intermediate states of one round. Partial rounds add the constant and
+/// apply the sbox to lane 0 only.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RoundTrace {
+    pub round_type: RoundType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::fields::serde_hex::vec"))]
+    pub state_before: Vec<FpBN256>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::fields::serde_hex::vec"))]
+    pub after_add_rc: Vec<FpBN256>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::fields::serde_hex::vec"))]
+    pub after_sbox: Vec<FpBN256>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::fields::serde_hex::vec"))]
+    pub after_linear_layer: Vec<FpBN256>,
+}
+
+/// Every intermediate state of a permutation call, in the round structure of
+/// the paper: the round constants of each round are added as a full vector,
+/// not in the equivalent form the fast permutation uses. The states after
+/// each round agree with both.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Poseidon2Trace {
+    #[cfg_attr(feature = "serde", serde(with = "crate::fields::serde_hex::vec"))]
+    pub input: Vec<FpBN256>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::fields::serde_hex::vec"))]
+    pub after_initial_linear_layer: Vec<FpBN256>,
+    pub rounds: Vec<RoundTrace>,
+}
+
+impl Poseidon2Trace {
+    /// Output of the permutation.
+    pub fn output(&self) -> &[FpBN256] {
+        self.rounds
+            .last()
+            .map_or(&self.after_initial_linear_layer, |round| {
+                &round.after_linear_layer
+            })
+    }
+}
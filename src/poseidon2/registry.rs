@@ -0,0 +1,202 @@
+//! Registry of parameter sets by identifier, so that both sides of a proof or
+//! a Merkle commitment can name the instance in use and check its
+//! [fingerprint](Poseidon2Params::fingerprint).
+
+use super::poseidon2_instance_bn256::ALL_INSTANCES;
+use super::poseidon2_params::{Poseidon2Params, Poseidon2ParamsRef};
+use alloc::{collections::BTreeMap, string::String, sync::Arc};
+use core::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RegistryError {
+    /// No instance is registered under the identifier.
+    UnknownId(String),
+    /// A different instance is already registered under the identifier.
+    Conflict(String),
+    /// The registered instance does not have the expected fingerprint.
+    FingerprintMismatch(String),
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryError::UnknownId(id) => write!(f, "unknown Poseidon2 instance {}", id),
+            RegistryError::Conflict(id) => {
+                write!(f, "a different instance is registered as {}", id)
+            }
+            RegistryError::FingerprintMismatch(id) => {
+                write!(f, "instance {} does not have the expected fingerprint", id)
+            }
+        }
+    }
+}
+
+/// Maps identifiers to parameter sets. [`Poseidon2Registry::new`] contains the
+/// instances of [`super::poseidon2_instance_bn256`] under their
+/// [`Poseidon2Params::id`]; more can be added with [`Self::register`].
+///
+/// Entries are [`Poseidon2ParamsRef`]s rather than `Arc<Poseidon2Params>`,
+/// so that the built-in `static` instances are shared without an allocation
+/// or a copy; runtime instances are registered as `Arc`s and returned as
+/// [`Poseidon2ParamsRef::Shared`]. Either kind dereferences to
+/// `Poseidon2Params` and can be passed to `Poseidon2::new`.
+#[derive(Clone, Debug)]
+pub struct Poseidon2Registry {
+    instances: BTreeMap<String, Poseidon2ParamsRef>,
+}
+
+impl Default for Poseidon2Registry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Poseidon2Registry {
+    /// A registry of the built-in instances. Their tables are `static`s, so
+    /// this only formats their identifiers.
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        for params in ALL_INSTANCES {
+            let id = params.id();
+            assert!(
+                !registry.instances.contains_key(&id),
+                "duplicate built-in instance {}",
+                id
+            );
+            registry.instances.insert(id, params.into());
+        }
+        registry
+    }
+
+    /// A registry without the built-in instances.
+    pub fn empty() -> Self {
+        Poseidon2Registry {
+            instances: BTreeMap::new(),
+        }
+    }
+
+    /// Registers `params` under `id`. Registering the same instance again is
+    /// a no-op; a different instance (by fingerprint) under a taken `id` is
+    /// an error.
+    pub fn register(
+        &mut self,
+        id: impl Into<String>,
+        params: Arc<Poseidon2Params>,
+    ) -> Result<(), RegistryError> {
+        let id = id.into();
+        if let Some(existing) = self.instances.get(&id) {
+            if existing.fingerprint() != params.fingerprint() {
+                return Err(RegistryError::Conflict(id));
+            }
+            return Ok(());
+        }
+        self.instances.insert(id, params.into());
+        Ok(())
+    }
+
+    pub fn get(&self, id: &str) -> Result<Poseidon2ParamsRef, RegistryError> {
+        self.instances
+            .get(id)
+            .cloned()
+            .ok_or_else(|| RegistryError::UnknownId(id.into()))
+    }
+
+    /// Like [`Self::get`], but also checks the fingerprint carried alongside
+    /// the identifier.
+    pub fn get_checked(
+        &self,
+        id: &str,
+        fingerprint: &[u8; 32],
+    ) -> Result<Poseidon2ParamsRef, RegistryError> {
+        let params = self.get(id)?;
+        if params.fingerprint() != *fingerprint {
+            return Err(RegistryError::FingerprintMismatch(id.into()));
+        }
+        Ok(params)
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.instances.keys().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod registry_tests {
+    use super::*;
+    use crate::fields::bn256::FpBN256;
+    use crate::poseidon2::poseidon2_instance_bn256::{MAT_DIAG3_M_1, POSEIDON2_BN256_PARAMS, RC3};
+    use alloc::vec::Vec;
+
+    fn runtime_t3() -> Poseidon2Params {
+        let rc: Vec<Vec<FpBN256>> = RC3.iter().map(|r| r.to_vec()).collect();
+        Poseidon2Params::builder(3, 5, 8, 56)
+            .mat_internal_diag_m_1(&MAT_DIAG3_M_1)
+            .round_constants(&rc)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn fingerprint() {
+        assert_eq!(
+            POSEIDON2_BN256_PARAMS.id(),
+            "poseidon2-bn254-t3-d5-rf8-rp56"
+        );
+        // pinned, so that the encoding does not change unnoticed
+        assert_eq!(POSEIDON2_BN256_PARAMS.fingerprint(), FINGERPRINT_T3);
+        assert_eq!(
+            runtime_t3().fingerprint(),
+            POSEIDON2_BN256_PARAMS.fingerprint()
+        );
+
+        let mut rc: Vec<Vec<FpBN256>> = RC3.iter().map(|r| r.to_vec()).collect();
+        rc[63][2] = FpBN256::ONE;
+        let other = Poseidon2Params::builder(3, 5, 8, 56)
+            .mat_internal_diag_m_1(&MAT_DIAG3_M_1)
+            .round_constants(&rc)
+            .build()
+            .unwrap();
+        assert_eq!(other.id(), POSEIDON2_BN256_PARAMS.id());
+        assert_ne!(other.fingerprint(), POSEIDON2_BN256_PARAMS.fingerprint());
+    }
+
+    #[test]
+    fn registry() {
+        let mut registry = Poseidon2Registry::new();
+        assert_eq!(registry.ids().count(), 9);
+        let id = "poseidon2-bn254-t3-d5-rf8-rp56";
+        let t3 = registry.get(id).unwrap();
+        assert!(matches!(t3, Poseidon2ParamsRef::Static(_)));
+        assert!(registry.get_checked(id, &FINGERPRINT_T3).is_ok());
+        assert_eq!(
+            registry.get_checked(id, &[0; 32]).unwrap_err(),
+            RegistryError::FingerprintMismatch(id.into())
+        );
+        assert_eq!(
+            registry.get("poseidon2-bn254-t3").unwrap_err(),
+            RegistryError::UnknownId("poseidon2-bn254-t3".into())
+        );
+
+        // same instance again is fine, different constants are not
+        registry.register(id, Arc::new(runtime_t3())).unwrap();
+        let other = Arc::new(Poseidon2Params::builder(3, 5, 8, 56).build().unwrap());
+        registry.register("custom", Arc::clone(&other)).unwrap();
+        assert!(matches!(
+            registry.get("custom").unwrap(),
+            Poseidon2ParamsRef::Shared(_)
+        ));
+        let t2 = Arc::new(Poseidon2Params::builder(2, 5, 8, 56).build().unwrap());
+        assert_eq!(
+            registry.register("custom", t2).unwrap_err(),
+            RegistryError::Conflict("custom".into())
+        );
+        assert!(Poseidon2Registry::empty().get(id).is_err());
+    }
+
+    // computed independently with Python's hashlib
+    const FINGERPRINT_T3: [u8; 32] = [
+        0xdf, 0x0f, 0x55, 0xbd, 0xa8, 0x19, 0xfb, 0x66, 0x96, 0x55, 0x26, 0xf3, 0x14, 0xcd, 0x90,
+        0x43, 0x86, 0x67, 0x55, 0x0c, 0x6e, 0x4c, 0x6d, 0x10, 0x67, 0x1b, 0xfe, 0x03, 0x72, 0x0a,
+        0x7b, 0xdc,
+    ];
+}
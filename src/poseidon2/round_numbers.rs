@@ -0,0 +1,233 @@
+use crate::fields::bn256::U256Field;
+use core::fmt;
+use libm::{ceil, exp2, floor, log2};
+
+/// Target security level of a Poseidon2 instance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SecurityLevel {
+    Bits80,
+    Bits128,
+    Bits256,
+}
+
+impl SecurityLevel {
+    pub fn bits(&self) -> usize {
+        match self {
+            SecurityLevel::Bits80 => 80,
+            SecurityLevel::Bits128 => 128,
+            SecurityLevel::Bits256 => 256,
+        }
+    }
+}
+
+/// Returned when an instance has fewer rounds than required for a security level.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InsufficientRounds {
+    pub security_level: SecurityLevel,
+    pub rounds_f: usize,
+    pub rounds_p: usize,
+    pub min_rounds_f: usize,
+    pub min_rounds_p: usize,
+}
+
+impl fmt::Display for InsufficientRounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "R_F = {}, R_P = {} is below the {}-bit security bounds (minimal R_F = {}, R_P = {})",
+            self.rounds_f,
+            self.rounds_p,
+            self.security_level.bits(),
+            self.min_rounds_f,
+            self.min_rounds_p
+        )
+    }
+}
+
+// Security margin of the Poseidon2 paper: two extra full rounds, 7.5% extra
+// partial rounds.
+const MARGIN_F: usize = 2;
+const MARGIN_P: f64 = 1.075;
+
+/// Field description used by the bounds.
+#[derive(Clone, Copy, Debug)]
+struct Field {
+    size: usize, // bit length of the modulus
+    log2_p: f64,
+}
+
+impl Field {
+    fn new(modulus: &U256Field) -> Self {
+        let word_bits = exp2(crypto_bigint::Word::BITS as f64);
+        let p = modulus
+            .as_words()
+            .iter()
+            .rev()
+            .fold(0f64, |acc, w| acc * word_bits + *w as f64);
+        Field {
+            size: modulus.bits(),
+            log2_p: log2(p),
+        }
+    }
+}
+
+fn log2_binomial(n: usize, k: usize) -> f64 {
+    let k = k.min(n - k);
+    (1..=k)
+        .map(|i| log2((n - k + i) as f64) - log2(i as f64))
+        .sum()
+}
+
+/// Statistical, interpolation and Gröbner-basis bounds of the Poseidon and
+/// Poseidon2 papers (plus the Gröbner attack of ePrint 2023/537), without
+/// security margin. Mirrors `sat_inequiv_alpha` of the reference sage script.
+fn sat_inequiv(
+    field: &Field,
+    t: usize,
+    rounds_f: usize,
+    rounds_p: usize,
+    d: usize,
+    m: usize,
+) -> bool {
+    let (tf, rp, m_f) = (t as f64, rounds_p as f64, m as f64);
+    let log_d_2 = 1.0 / log2(d as f64); // log_d(2)
+
+    let r_f_1 = if m_f <= floor(field.log2_p - (d as f64 - 1.0) / 2.0) * (tf + 1.0) {
+        6.0
+    } else {
+        10.0
+    };
+    let r_f_2 = 1.0 + ceil(log_d_2 * m.min(field.size) as f64) + ceil(log2(tf) * log_d_2) - rp;
+    let r_f_3 = log_d_2 * m_f.min(field.log2_p) - rp;
+    let r_f_4 = tf - 1.0 + log_d_2 * (m_f / (tf + 1.0)).min(field.log2_p / 2.0) - rp;
+    let r_f_5 = (tf - 2.0 + m_f / (2.0 * log2(d as f64)) - rp) / (tf - 1.0);
+    let r_f_max = [r_f_1, r_f_2, r_f_3, r_f_4, r_f_5]
+        .iter()
+        .map(|r| ceil(*r))
+        .fold(f64::MIN, f64::max);
+    if (rounds_f as f64) < r_f_max {
+        return false;
+    }
+
+    let r_temp = t / 3;
+    let over = (rounds_f - 1) * t + rounds_p + r_temp + r_temp * (rounds_f / 2) + rounds_p + d;
+    let under = r_temp * (rounds_f / 2) + rounds_p + d;
+    let cost_gb4 = ceil(2.0 * log2_binomial(over, under));
+    cost_gb4 >= m_f
+}
+
+/// Minimal secure `(R_F, R_P)` including the security margin, minimizing the
+/// number of sboxes `t * R_F + R_P` as the reference sage script does.
+pub fn calc_round_numbers(
+    modulus: &U256Field,
+    t: usize,
+    d: usize,
+    security_level: SecurityLevel,
+) -> (usize, usize) {
+    assert!(t >= 2 && d >= 3);
+    let field = Field::new(modulus);
+    let m = security_level.bits();
+
+    let mut best: Option<(usize, usize, usize)> = None; // (cost, R_F, R_P)
+    for rounds_p in 1..500 {
+        if let Some(rounds_f) = (4..100)
+            .step_by(2)
+            .find(|&rf| sat_inequiv(&field, t, rf, rounds_p, d, m))
+        {
+            let rounds_f = rounds_f + MARGIN_F;
+            let rounds_p = ceil(rounds_p as f64 * MARGIN_P) as usize;
+            let cost = t * rounds_f + rounds_p;
+            if best.is_none_or(|(c, rf, _)| cost < c || (cost == c && rounds_f < rf)) {
+                best = Some((cost, rounds_f, rounds_p));
+            }
+        }
+    }
+    let (_, rounds_f, rounds_p) = best.expect("no secure round numbers found");
+    (rounds_f, rounds_p)
+}
+
+/// Checks that `(rounds_f, rounds_p)` meet the bounds for `security_level`
+/// including the security margin.
+pub fn check_round_numbers(
+    modulus: &U256Field,
+    t: usize,
+    d: usize,
+    rounds_f: usize,
+    rounds_p: usize,
+    security_level: SecurityLevel,
+) -> Result<(), InsufficientRounds> {
+    let field = Field::new(modulus);
+    // strip the margin: the largest R_P whose margin-inflated value fits
+    let base_p = (1..=rounds_p)
+        .rev()
+        .find(|&rp| ceil(rp as f64 * MARGIN_P) as usize <= rounds_p)
+        .unwrap_or(0);
+    let secure = rounds_f.is_multiple_of(2)
+        && rounds_f >= 4 + MARGIN_F
+        && base_p >= 1
+        && sat_inequiv(
+            &field,
+            t,
+            rounds_f - MARGIN_F,
+            base_p,
+            d,
+            security_level.bits(),
+        );
+
+    if secure {
+        Ok(())
+    } else {
+        let (min_rounds_f, min_rounds_p) = calc_round_numbers(modulus, t, d, security_level);
+        Err(InsufficientRounds {
+            security_level,
+            rounds_f,
+            rounds_p,
+            min_rounds_f,
+            min_rounds_p,
+        })
+    }
+}
+
+#[cfg(test)]
+mod round_numbers_tests {
+    use super::*;
+    use crate::fields::bn256::ModulusBN254;
+    use crate::poseidon2::poseidon2_instance_bn256::{ALL_INSTANCES, POSEIDON2_BN256_GNARK_PARAMS};
+    use crypto_bigint::modular::constant_mod::ResidueParams;
+
+    #[test]
+    fn bn254_round_numbers() {
+        let p = ModulusBN254::MODULUS;
+        for (t, rounds_p) in [(2, 56), (3, 56), (4, 56), (8, 57), (16, 57), (24, 57)] {
+            assert_eq!(
+                calc_round_numbers(&p, t, 5, SecurityLevel::Bits128),
+                (8, rounds_p)
+            );
+        }
+        assert_eq!(calc_round_numbers(&p, 2, 5, SecurityLevel::Bits80), (8, 34));
+    }
+
+    #[test]
+    fn shipped_instances_are_secure() {
+        for params in ALL_INSTANCES {
+            let check = params.check_security_level(SecurityLevel::Bits128);
+            // gnark-crypto's R_F = 6 is below the bound, see its instance
+            if core::ptr::eq(params, &POSEIDON2_BN256_GNARK_PARAMS) {
+                assert!(check.is_err());
+            } else {
+                assert_eq!(check, Ok(()));
+            }
+        }
+    }
+
+    #[test]
+    fn check_bounds() {
+        let p = ModulusBN254::MODULUS;
+        assert!(check_round_numbers(&p, 3, 5, 8, 56, SecurityLevel::Bits128).is_ok());
+        assert!(check_round_numbers(&p, 3, 5, 10, 60, SecurityLevel::Bits128).is_ok());
+        let err = check_round_numbers(&p, 3, 5, 8, 40, SecurityLevel::Bits128).unwrap_err();
+        assert_eq!((err.min_rounds_f, err.min_rounds_p), (8, 56));
+        assert!(check_round_numbers(&p, 3, 5, 6, 56, SecurityLevel::Bits128).is_err());
+        assert!(check_round_numbers(&p, 3, 5, 8, 56, SecurityLevel::Bits256).is_err());
+    }
+}
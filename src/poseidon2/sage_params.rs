@@ -0,0 +1,483 @@
+//! Parser for the Rust output of the HorizenLabs `poseidon2_rust_params.sage`
+//! script, as in
+//!
+//! ```text
+//! pub static ref MAT_DIAG3_M_1: Vec<Scalar> = vec![ from_hex("0x..."), ... ];
+//! pub static ref MAT_INTERNAL3: Vec<Vec<Scalar>> = vec![ vec![ from_hex("0x..."), ... ], ... ];
+//! pub static ref RC3: Vec<Vec<Scalar>> = vec![ vec![ from_hex("0x..."), ... ], ... ];
+//! pub static ref POSEIDON2_BN256_PARAMS: Arc<Poseidon2Params> = Arc::new(Poseidon2Params::new(
+//!     3, 5, 8, 56, &MAT_DIAG3_M_1, &MAT_INTERNAL3, &RC3
+//! ));
+//! ```
+//!
+//! The tables are looked up by the names passed to `Poseidon2Params::new`,
+//! and may also be written as (nested) arrays `[...]`. The result is
+//! validated with [`Poseidon2Params::try_new`].
+//!
+//! Without such a call, the plain-text output of the script's `print`s is
+//! parsed instead:
+//!
+//! ```text
+//! Params: n=255, t=3, alpha=5, M=128, R_F=8, R_P=56
+//! ...
+//! Round constants for GF(p):
+//! ['0x...', '0x...', ...]
+//! ```
+//!
+//! The round constants are either `R_F * t + R_P` values, one per sbox as the
+//! script generates them, or `(R_F + R_P) * t` values with zeros in the
+//! partial rounds. The internal diagonal is not part of that output; it is
+//! derived as the script does, see
+//! [`Poseidon2ParamsBuilder`](super::poseidon2_params::Poseidon2ParamsBuilder). An optional
+//! `MDS matrix:` list of rows is used as the external matrix.
+
+use super::poseidon2_params::{Poseidon2Error, Poseidon2Params};
+use crate::fields::{bn256::FpBN256, utils::try_from_hex};
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SageParseError {
+    /// Neither a `Poseidon2Params::new(...)` call with seven arguments nor a
+    /// `Params:` line was found.
+    MissingConstructor,
+    /// An integer argument of the constructor is not a number.
+    InvalidNumber(String),
+    /// No table with this name is defined.
+    MissingTable(String),
+    /// The table is not a vector (or matrix) of hex field elements.
+    InvalidTable(String),
+    /// The parsed instance is invalid.
+    Params(Poseidon2Error),
+}
+
+impl fmt::Display for SageParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SageParseError::MissingConstructor => {
+                write!(f, "no Poseidon2Params::new(...) call or Params: line found")
+            }
+            SageParseError::InvalidNumber(s) => write!(f, "invalid number {:?}", s),
+            SageParseError::MissingTable(name) => write!(f, "table {} is not defined", name),
+            SageParseError::InvalidTable(name) => write!(f, "table {} is malformed", name),
+            SageParseError::Params(err) => write!(f, "invalid Poseidon2 parameters: {}", err),
+        }
+    }
+}
+
+impl From<Poseidon2Error> for SageParseError {
+    fn from(err: Poseidon2Error) -> Self {
+        SageParseError::Params(err)
+    }
+}
+
+// A (nested) table of field elements
+enum Table {
+    Element(FpBN256),
+    List(Vec<Table>),
+}
+
+/// Parses and validates the first instance defined in `s`.
+pub fn parse_sage_params(s: &str) -> Result<Poseidon2Params, SageParseError> {
+    match constructor_args(s) {
+        Some(args) => parse_rust(s, &args),
+        None => parse_plain(s),
+    }
+}
+
+fn parse_rust(s: &str, args: &[&str]) -> Result<Poseidon2Params, SageParseError> {
+    let number = |arg: &str| {
+        arg.parse::<usize>()
+            .map_err(|_| SageParseError::InvalidNumber(arg.into()))
+    };
+    let (t, d, rounds_f, rounds_p) = (
+        number(args[0])?,
+        number(args[1])?,
+        number(args[2])?,
+        number(args[3])?,
+    );
+    let table_name = |arg: &'_ str| String::from(arg.trim_start_matches('&').trim());
+
+    let diag_name = table_name(args[4]);
+    let mat_internal_diag_m_1 = match table(s, &diag_name)? {
+        Table::List(els) => elements(els).ok_or(SageParseError::InvalidTable(diag_name))?,
+        Table::Element(_) => return Err(SageParseError::InvalidTable(diag_name)),
+    };
+    let mat_internal_name = table_name(args[5]);
+    let mat_internal = matrix(table(s, &mat_internal_name)?)
+        .ok_or(SageParseError::InvalidTable(mat_internal_name))?;
+    let rc_name = table_name(args[6]);
+    let round_constants =
+        matrix(table(s, &rc_name)?).ok_or(SageParseError::InvalidTable(rc_name))?;
+
+    Ok(Poseidon2Params::try_new(
+        t,
+        d,
+        rounds_f,
+        rounds_p,
+        &mat_internal_diag_m_1,
+        &mat_internal,
+        &round_constants,
+    )?)
+}
+
+fn parse_plain(s: &str) -> Result<Poseidon2Params, SageParseError> {
+    const PARAMS: &str = "Params:";
+    const ROUND_CONSTANTS: &str = "Round constants for GF(p):";
+    const MDS: &str = "MDS matrix:";
+
+    let line = s
+        .lines()
+        .find_map(|line| line.trim().strip_prefix(PARAMS))
+        .ok_or(SageParseError::MissingConstructor)?;
+    let param = |key: &str| {
+        let value = line
+            .split(',')
+            .filter_map(|kv| kv.split_once('='))
+            .find(|(k, _)| k.trim() == key)
+            .map(|(_, v)| v.trim())
+            .ok_or_else(|| SageParseError::InvalidNumber(key.into()))?;
+        value
+            .parse::<usize>()
+            .map_err(|_| SageParseError::InvalidNumber(value.into()))
+    };
+    let (t, d, rounds_f, rounds_p) = (param("t")?, param("alpha")?, param("R_F")?, param("R_P")?);
+
+    let rc_name = String::from(ROUND_CONSTANTS);
+    let rc_start = s
+        .find(ROUND_CONSTANTS)
+        .ok_or_else(|| SageParseError::MissingTable(rc_name.clone()))?;
+    let flat = match list(&s[rc_start + ROUND_CONSTANTS.len()..], &rc_name)? {
+        Table::List(els) => elements(els).ok_or(SageParseError::InvalidTable(rc_name.clone()))?,
+        Table::Element(_) => return Err(SageParseError::InvalidTable(rc_name)),
+    };
+    let round_constants = round_constant_rows(&flat, t, rounds_f, rounds_p)
+        .ok_or(SageParseError::InvalidTable(rc_name))?;
+
+    let mut builder =
+        Poseidon2Params::builder(t, d, rounds_f, rounds_p).round_constants(&round_constants);
+    if let Some(start) = s.find(MDS) {
+        let name = String::from(MDS);
+        let mat_external = matrix(list(&s[start + MDS.len()..], &name)?)
+            .ok_or(SageParseError::InvalidTable(name))?;
+        builder = builder.mat_external(&mat_external);
+    }
+    Ok(builder.build()?)
+}
+
+// Rows of round constants from the flat list of the script, which has one
+// constant per sbox (or, padded, t per round)
+fn round_constant_rows(
+    flat: &[FpBN256],
+    t: usize,
+    rounds_f: usize,
+    rounds_p: usize,
+) -> Option<Vec<Vec<FpBN256>>> {
+    let rounds = rounds_f + rounds_p;
+    if flat.len() == rounds * t {
+        return Some(flat.chunks_exact(t).map(|row| row.to_vec()).collect());
+    }
+    if flat.len() != rounds_f * t + rounds_p {
+        return None;
+    }
+    let r = rounds_f / 2;
+    let mut rest = flat;
+    let mut rows = Vec::with_capacity(rounds);
+    for round in 0..rounds {
+        let n = if round >= r && round < r + rounds_p {
+            1
+        } else {
+            t
+        };
+        let mut row = rest[..n].to_vec();
+        row.resize(t, FpBN256::ZERO);
+        rows.push(row);
+        rest = &rest[n..];
+    }
+    Some(rows)
+}
+
+// The seven comma-separated arguments of `Poseidon2Params::new(...)`
+fn constructor_args(s: &str) -> Option<Vec<&str>> {
+    const CALL: &str = "Poseidon2Params::new(";
+    let start = s.find(CALL)? + CALL.len();
+    let end = start + s[start..].find(')')?;
+    let args: Vec<&str> = s[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|arg| !arg.is_empty())
+        .collect();
+    (args.len() == 7).then_some(args)
+}
+
+// The bracketed expression following `name:` or `name =`
+fn table(s: &str, name: &str) -> Result<Table, SageParseError> {
+    let missing = || SageParseError::MissingTable(name.into());
+    let invalid = || SageParseError::InvalidTable(name.into());
+
+    let def = s
+        .match_indices(name)
+        .filter(|&(i, _)| !s[..i].ends_with(|c: char| c.is_ascii_alphanumeric() || c == '_'))
+        .map(|(i, _)| i + name.len())
+        .find(|&i| {
+            let rest = s[i..].trim_start();
+            rest.starts_with(':') || rest.starts_with('=')
+        })
+        .ok_or_else(missing)?;
+    let body = &s[def..];
+    list(&body[body.find('=').ok_or_else(invalid)? + 1..], name)
+}
+
+// The first bracketed expression in `body`, with elements quoted in `"` or
+// `'`
+fn list(body: &str, name: &str) -> Result<Table, SageParseError> {
+    let invalid = || SageParseError::InvalidTable(name.into());
+    let open = body.find('[').ok_or_else(invalid)?;
+
+    let mut stack: Vec<Vec<Table>> = Vec::new();
+    let mut rest = &body[open..];
+    loop {
+        let i = rest.find(['[', ']', '"', '\'']).ok_or_else(invalid)?;
+        match rest.as_bytes()[i] {
+            b'[' => {
+                stack.push(Vec::new());
+                rest = &rest[i + 1..];
+            }
+            b']' => {
+                let list = Table::List(stack.pop().ok_or_else(invalid)?);
+                match stack.last_mut() {
+                    Some(parent) => parent.push(list),
+                    None => return Ok(list),
+                }
+                rest = &rest[i + 1..];
+            }
+            quote => {
+                let len = rest[i + 1..].find(quote as char).ok_or_else(invalid)?;
+                let el = try_from_hex(&rest[i + 1..i + 1 + len]).ok_or_else(invalid)?;
+                stack
+                    .last_mut()
+                    .ok_or_else(invalid)?
+                    .push(Table::Element(el));
+                rest = &rest[i + len + 2..];
+            }
+        }
+    }
+}
+
+fn elements(list: Vec<Table>) -> Option<Vec<FpBN256>> {
+    list.into_iter()
+        .map(|el| match el {
+            Table::Element(el) => Some(el),
+            Table::List(_) => None,
+        })
+        .collect()
+}
+
+fn matrix(table: Table) -> Option<Vec<Vec<FpBN256>>> {
+    match table {
+        Table::List(rows) => rows
+            .into_iter()
+            .map(|row| match row {
+                Table::List(els) => elements(els),
+                Table::Element(_) => None,
+            })
+            .collect(),
+        Table::Element(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod sage_params_tests {
+    use super::*;
+    use crate::fields::utils::to_hex;
+    use crate::poseidon2::poseidon2_instance_bn256::{
+        MAT_DIAG3_M_1, MAT_INTERNAL3, POSEIDON2_BN256_PARAMS, RC3,
+    };
+    use alloc::{format, string::ToString};
+    use core::fmt::Write;
+
+    // the layout of the sage script's output
+    fn sage_output(rounds_p: usize) -> String {
+        let vector = |els: &[FpBN256]| {
+            els.iter()
+                .map(|el| format!("from_hex(\"{}\"),\n", to_hex(el)))
+                .collect::<String>()
+        };
+        let mut s = String::from("lazy_static! {\n");
+        writeln!(s, "pub static ref MAT_DIAG3_M_1: Vec<Scalar> = vec![").unwrap();
+        s.push_str(&vector(&MAT_DIAG3_M_1));
+        s.push_str("];\npub static ref MAT_INTERNAL3: Vec<Vec<Scalar>> = vec![\n");
+        for row in &MAT_INTERNAL3 {
+            writeln!(s, "vec![{}],", vector(row)).unwrap();
+        }
+        s.push_str("];\npub static ref RC3: Vec<Vec<Scalar>> = vec![\n");
+        for row in &RC3 {
+            writeln!(s, "vec![{}],", vector(row)).unwrap();
+        }
+        s.push_str("];\n");
+        writeln!(
+            s,
+            "pub static ref POSEIDON2_BN256_PARAMS: Arc<Poseidon2Params<Scalar>> = \
+             Arc::new(Poseidon2Params::new(3, 5, 8, {}, &MAT_DIAG3_M_1, &MAT_INTERNAL3, &RC3));\n}}",
+            rounds_p
+        )
+        .unwrap();
+        s
+    }
+
+    #[test]
+    fn parse() {
+        let params = parse_sage_params(&sage_output(56)).unwrap();
+        assert_eq!(
+            params.round_constants,
+            POSEIDON2_BN256_PARAMS.round_constants
+        );
+        assert_eq!(
+            params.mat_internal_diag_m_1,
+            POSEIDON2_BN256_PARAMS.mat_internal_diag_m_1
+        );
+        assert_eq!(
+            params.opt_round_constants_partial,
+            POSEIDON2_BN256_PARAMS.opt_round_constants_partial
+        );
+
+        // the const-array layout of this crate
+        let s = "const DIAG: [Scalar; 2] = [from_hex(\"0x1\"), from_hex(\"0x2\")];\n\
+                 const M: [[Scalar; 2]; 2] = [[from_hex(\"0x2\"), from_hex(\"0x1\")], \
+                 [from_hex(\"0x1\"), from_hex(\"0x3\")]];\n"
+            .to_string();
+        let rc = (0..64)
+            .map(|r| {
+                if (4..60).contains(&r) {
+                    "[\"0x5\", \"0x0\"]"
+                } else {
+                    "[\"0x5\", \"0x6\"]"
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let s = format!(
+            "{}const RC: [[Scalar; 2]; 64] = [{}];\nPoseidon2Params::new(2, 5, 8, 56, &DIAG, &M, &RC)",
+            s, rc
+        );
+        assert_eq!(parse_sage_params(&s).unwrap().t, 2);
+    }
+
+    // the plain-text layout of the sage script's `print`s, with one round
+    // constant per sbox
+    fn sage_plain_output() -> String {
+        let flat = RC3
+            .iter()
+            .enumerate()
+            .flat_map(|(round, row)| {
+                let partial = (4..60).contains(&round);
+                row.iter().take(if partial { 1 } else { 3 })
+            })
+            .map(|el| format!("'{}'", to_hex(el)))
+            .collect::<Vec<_>>();
+        format!(
+            "Params: n=255, t=3, alpha=5, M=128, R_F=8, R_P=56\n\
+             Modulus = 21888242871839275222246405745257275088548364400416034343698204186575808495617\n\
+             Number of round constants: 80\n\
+             Round constants for GF(p):\n\
+             [{}]\n",
+            flat.join(", ")
+        )
+    }
+
+    #[test]
+    fn parse_plain() {
+        let params = parse_sage_params(&sage_plain_output()).unwrap();
+        assert_eq!(
+            params.round_constants,
+            POSEIDON2_BN256_PARAMS.round_constants
+        );
+        assert_eq!(
+            params.mat_internal_diag_m_1,
+            POSEIDON2_BN256_PARAMS.mat_internal_diag_m_1
+        );
+
+        // padded round constants and the external matrix
+        let padded = RC3
+            .iter()
+            .flatten()
+            .map(|el| format!("'{}'", to_hex(el)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let mds = |m: &[Vec<FpBN256>]| {
+            m.iter()
+                .map(|row| {
+                    let row = row
+                        .iter()
+                        .map(|el| format!("'{}'", to_hex(el)))
+                        .collect::<Vec<_>>();
+                    format!("[{}]", row.join(", "))
+                })
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        let mat_external = Poseidon2Params::default_mat_external(3);
+        let s = format!(
+            "Params: n=255, t=3, alpha=5, M=128, R_F=8, R_P=56\n\
+             Round constants for GF(p):\n[{}]\nMDS matrix:\n [{}]\n",
+            padded,
+            mds(&mat_external)
+        );
+        assert_eq!(
+            parse_sage_params(&s).unwrap().round_constants,
+            POSEIDON2_BN256_PARAMS.round_constants
+        );
+
+        let mut swapped = mat_external;
+        swapped.swap(0, 2);
+        let s = format!(
+            "Params: n=255, t=3, alpha=5, M=128, R_F=8, R_P=56\n\
+             Round constants for GF(p):\n[{}]\nMDS matrix:\n [{}]\n",
+            padded,
+            mds(&swapped)
+        );
+        assert_eq!(
+            parse_sage_params(&s).unwrap_err(),
+            SageParseError::Params(Poseidon2Error::ExternalMatrixMismatch)
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            parse_sage_params("").unwrap_err(),
+            SageParseError::MissingConstructor
+        );
+        assert_eq!(
+            parse_sage_params(&sage_output(55)).unwrap_err(),
+            SageParseError::Params(Poseidon2Error::InvalidRoundCount {
+                expected: 63,
+                got: 64
+            })
+        );
+        let s = sage_output(56).replace("MAT_INTERNAL3:", "OTHER_MAT_INTERNAL3:");
+        assert_eq!(
+            parse_sage_params(&s).unwrap_err(),
+            SageParseError::MissingTable("MAT_INTERNAL3".into())
+        );
+        let s = sage_output(56).replacen("0x0000", "0xzz00", 1);
+        assert_eq!(
+            parse_sage_params(&s).unwrap_err(),
+            SageParseError::InvalidTable("MAT_DIAG3_M_1".into())
+        );
+
+        let plain = sage_plain_output();
+        assert_eq!(
+            parse_sage_params(&plain.replace("R_P=56", "R_P=55")).unwrap_err(),
+            SageParseError::InvalidTable("Round constants for GF(p):".into())
+        );
+        assert_eq!(
+            parse_sage_params(&plain.replace("R_P=56", "R_P=5x")).unwrap_err(),
+            SageParseError::InvalidNumber("5x".into())
+        );
+        assert_eq!(
+            parse_sage_params(&plain.replace("Round constants", "Constants")).unwrap_err(),
+            SageParseError::MissingTable("Round constants for GF(p):".into())
+        );
+    }
+}
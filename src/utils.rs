@@ -1,7 +1,8 @@
 use crate::fields::bn256::FpBN256;
+use alloc::{borrow::ToOwned, vec, vec::Vec};
 use core::ops::{MulAssign, SubAssign};
 
-// gaussian elimination
+// gauss-jordan elimination with row pivoting, panics if `mat` is singular
 pub fn mat_inverse(mat: &[Vec<FpBN256>]) -> Vec<Vec<FpBN256>> {
     let n = mat.len();
     assert!(mat[0].len() == n);
@@ -12,67 +13,32 @@ pub fn mat_inverse(mat: &[Vec<FpBN256>]) -> Vec<Vec<FpBN256>> {
         invi[i] = FpBN256::ONE;
     }
 
-    // upper triangle
-    for row in 0..n {
-        for j in 0..row {
-            let el = m[row][j];
-            for col in 0..n {
-                if col < j {
-                    m[row][col] = FpBN256::ZERO;
-                } else {
-                    let mut tmp = m[j][col];
-                    tmp.mul_assign(&el);
-                    m[row][col].sub_assign(&tmp);
-                }
-                if col > row {
-                    inv[row][col] = FpBN256::ZERO;
-                } else {
-                    let mut tmp = inv[j][col];
-                    tmp.mul_assign(&el);
-                    inv[row][col].sub_assign(&tmp);
-                }
-            }
-        }
-        let el_inv = invert_unwrap(&m[row][row]);
-        for col in 0..n {
-            match col.cmp(&row) {
-                std::cmp::Ordering::Less => inv[row][col].mul_assign(&el_inv),
-                std::cmp::Ordering::Equal => {
-                    m[row][col] = FpBN256::ONE;
-                    inv[row][col].mul_assign(&el_inv)
-                }
-                std::cmp::Ordering::Greater => m[row][col].mul_assign(&el_inv),
-            }
-        }
-    }
+    for col in 0..n {
+        let pivot = (col..n)
+            .find(|&row| m[row][col] != FpBN256::ZERO)
+            .expect("matrix is not invertible");
+        m.swap(col, pivot);
+        inv.swap(col, pivot);
+
+        let el_inv = invert_unwrap(&m[col][col]);
+        m[col].iter_mut().for_each(|v| v.mul_assign(&el_inv));
+        inv[col].iter_mut().for_each(|v| v.mul_assign(&el_inv));
 
-    // back substitution
-    for row in (0..n).rev() {
-        for j in (row + 1..n).rev() {
-            let el = m[row][j];
-            for col in 0..n {
-                #[cfg(debug_assertions)]
-                {
-                    if col >= j {
-                        m[row][col] = FpBN256::ZERO;
-                    }
-                }
-                let mut tmp = inv[j][col];
+        let (m_pivot, inv_pivot) = (m[col].clone(), inv[col].clone());
+        for row in (0..n).filter(|&row| row != col) {
+            let el = m[row][col];
+            if el == FpBN256::ZERO {
+                continue;
+            }
+            for (v, p) in m[row].iter_mut().zip(&m_pivot) {
+                let mut tmp = *p;
                 tmp.mul_assign(&el);
-                inv[row][col].sub_assign(&tmp);
+                v.sub_assign(&tmp);
             }
-        }
-    }
-
-    #[cfg(debug_assertions)]
-    {
-        for (row, mrow) in m.iter().enumerate() {
-            for (col, v) in mrow.iter().enumerate() {
-                if row == col {
-                    debug_assert!(*v == FpBN256::ONE);
-                } else {
-                    debug_assert!(*v == FpBN256::ZERO);
-                }
+            for (v, p) in inv[row].iter_mut().zip(&inv_pivot) {
+                let mut tmp = *p;
+                tmp.mul_assign(&el);
+                v.sub_assign(&tmp);
             }
         }
     }
@@ -96,7 +62,7 @@ pub fn mat_transpose(mat: &[Vec<FpBN256>]) -> Vec<Vec<FpBN256>> {
 /// Multiplicative inverse, panics if not found.
 pub fn invert_unwrap(x: &FpBN256) -> FpBN256 {
     let (inv, ok) = x.invert();
-    if <crypto_bigint::CtChoice as Into<bool>>::into(ok) == false {
+    if !<crypto_bigint::CtChoice as Into<bool>>::into(ok) {
         panic!("inversion failed");
     }
     inv
@@ -0,0 +1,68 @@
+// Prints the gnark-crypto vectors checked in src/poseidon2/poseidon2_gnark.rs.
+//
+//	go mod init gnark_vectors
+//	go get github.com/consensys/gnark-crypto@v0.14.0
+//	go run . > output.txt
+//
+// Written against the v0.14 API of ecc/bn254/fr/poseidon2.
+package main
+
+import (
+	"fmt"
+
+	"github.com/consensys/gnark-crypto/ecc/bn254/fr"
+	"github.com/consensys/gnark-crypto/ecc/bn254/fr/poseidon2"
+)
+
+func printElement(name string, e *fr.Element) {
+	b := e.Bytes()
+	fmt.Printf("%s = 0x%x\n", name, b[:])
+}
+
+func main() {
+	params := poseidon2.NewParameters(2, 6, 50)
+	printElement("RC_FIRST", &params.RoundKeys[0][0])
+	printElement("RC_LAST", &params.RoundKeys[len(params.RoundKeys)-1][1])
+
+	perm := poseidon2.NewPermutation(2, 6, 50)
+	state := make([]fr.Element, 2)
+	state[1].SetOne()
+	if err := perm.Permutation(state); err != nil {
+		panic(err)
+	}
+	printElement("PERM_0_1[0]", &state[0])
+	printElement("PERM_0_1[1]", &state[1])
+
+	// two blocks, 1 and 2
+	blocks := make([]byte, 64)
+	blocks[31] = 1
+	blocks[63] = 2
+	h := poseidon2.NewMerkleDamgardHasher()
+	if _, err := h.Write(blocks); err != nil {
+		panic(err)
+	}
+	fmt.Printf("DIGEST_1_2 = 0x%x\n", h.Sum(nil))
+
+	// a write shorter than a block
+	h.Reset()
+	if _, err := h.Write([]byte{0x01, 0x02}); err != nil {
+		panic(err)
+	}
+	fmt.Printf("DIGEST_SHORT = 0x%x\n", h.Sum(nil))
+
+	// a longer write with a partial last block, expected to fail; the state
+	// afterwards shows whether the full block before it was absorbed
+	h.Reset()
+	ragged := append(blocks[:32:32], 7)
+	_, err := h.Write(ragged)
+	fmt.Printf("ragged write error: %v\n", err)
+	fmt.Printf("DIGEST_AFTER_RAGGED = 0x%x\n", h.Sum(nil))
+
+	// a valid block followed by a non-canonical one (the modulus); the state
+	// afterwards shows whether gnark rolls back the failed write
+	h.Reset()
+	modulus := fr.Modulus().FillBytes(make([]byte, 32))
+	_, err = h.Write(append(blocks[:32:32], modulus...))
+	fmt.Printf("non-canonical write error: %v\n", err)
+	fmt.Printf("DIGEST_AFTER_NON_CANONICAL = 0x%x\n", h.Sum(nil))
+}